@@ -0,0 +1,82 @@
+//! Benchmark of `draw_connected_scatter`
+//!
+//! This compares drawing a connected scatter plot in one pass with
+//! `WxBackend::draw_connected_scatter`, with the two plotters passes it
+//! replaces: a `LineSeries`, then a series of `Circle` elements.
+//!
+//! Run with `cargo bench --bench connected_scatter`.
+
+use std::process;
+use std::time::{Duration, Instant};
+
+use plotters::prelude::*;
+use plotters_wxdragon::{MarkerShape, WxBackend};
+use wxdragon as wx;
+
+const WIDTH: i32 = 800;
+const HEIGHT: i32 = 600;
+const POINTS: i32 = 10_000;
+
+fn main() {
+    let _ = wx::main(|_| {
+        let two_passes = bench("two passes", |dc| {
+            let root = WxBackend::new(dc).into_drawing_area();
+            root.draw(&PathElement::new(points().collect::<Vec<_>>(), BLUE))
+                .expect("draw path");
+            for point in points() {
+                root.draw(&Circle::new(point, 3, RED.filled()))
+                    .expect("draw circle");
+            }
+            root.present().expect("present");
+        });
+        let one_pass = bench("one pass", |dc| {
+            let mut backend = WxBackend::new(dc);
+            backend
+                .draw_connected_scatter(
+                    &points().collect::<Vec<_>>(),
+                    &BLUE,
+                    MarkerShape::Circle,
+                    3,
+                    &RED.filled(),
+                )
+                .expect("draw_connected_scatter");
+            backend.present().expect("present");
+        });
+        println!(
+            "speedup: {:.1}x",
+            two_passes.as_secs_f64() / one_pass.as_secs_f64()
+        );
+        process::exit(0);
+    });
+}
+
+/// Draws on a new in-memory device context and reports the elapsed time.
+fn bench<F>(name: &str, draw_fn: F) -> Duration
+where
+    F: FnOnce(&wx::MemoryDC),
+{
+    let mut bitmap =
+        wx::Bitmap::new(WIDTH, HEIGHT).expect("failed to create bitmap");
+    let mut dc = wx::MemoryDC::new();
+    dc.select_object(&mut bitmap);
+
+    let start = Instant::now();
+    draw_fn(&dc);
+    dc.select_object(&mut wx::Bitmap::null_bitmap());
+    let elapsed = start.elapsed();
+
+    println!("{name}: {POINTS} points in {elapsed:?}");
+    elapsed
+}
+
+/// A noisy sine wave across the canvas.
+fn points() -> impl Iterator<Item = (i32, i32)> {
+    (0..POINTS).map(|i| {
+        let x = i * WIDTH / POINTS;
+        let noise = (i * 7919 % 41 - 20) as f64;
+        let y = HEIGHT as f64 / 2.0
+            + HEIGHT as f64 / 3.0 * (i as f64 / 500.0).sin()
+            + noise;
+        (x, y as i32)
+    })
+}
@@ -0,0 +1,67 @@
+//! Benchmark of `draw_pixel`
+//!
+//! This compares drawing a full canvas of pixels through the backend, which
+//! batches them in an off-screen buffer, with drawing each pixel directly on
+//! the device context with one pen change and one point per pixel.
+//!
+//! Run with `cargo bench --bench draw_pixel`.
+
+use std::process;
+use std::time::{Duration, Instant};
+
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+use wxdragon::{self as wx, DeviceContext};
+
+const WIDTH: i32 = 800;
+const HEIGHT: i32 = 600;
+
+fn main() {
+    let _ = wx::main(|_| {
+        let direct = bench("direct", |dc| {
+            for (x, y, (r, g, b)) in pixels() {
+                dc.set_pen(wx::Colour::rgb(r, g, b), 1, wx::PenStyle::Solid);
+                dc.draw_point(x, y);
+            }
+        });
+        let batched = bench("batched", |dc| {
+            let mut backend = WxBackend::new(dc);
+            for (x, y, rgb) in pixels() {
+                let color = RGBColor(rgb.0, rgb.1, rgb.2).to_backend_color();
+                backend.draw_pixel((x, y), color).expect("draw_pixel");
+            }
+            backend.present().expect("present");
+        });
+        println!(
+            "speedup: {:.1}x",
+            direct.as_secs_f64() / batched.as_secs_f64()
+        );
+        process::exit(0);
+    });
+}
+
+/// Draws on a new in-memory device context and reports the elapsed time.
+fn bench<F>(name: &str, draw_fn: F) -> Duration
+where
+    F: FnOnce(&wx::MemoryDC),
+{
+    let mut bitmap =
+        wx::Bitmap::new(WIDTH, HEIGHT).expect("failed to create bitmap");
+    let mut dc = wx::MemoryDC::new();
+    dc.select_object(&mut bitmap);
+
+    let start = Instant::now();
+    draw_fn(&dc);
+    dc.select_object(&mut wx::Bitmap::null_bitmap());
+    let elapsed = start.elapsed();
+
+    println!("{name}: {} pixels in {elapsed:?}", WIDTH * HEIGHT);
+    elapsed
+}
+
+/// All pixels of the canvas, with a color gradient.
+fn pixels() -> impl Iterator<Item = (i32, i32, (u8, u8, u8))> {
+    (0..HEIGHT).flat_map(|y| {
+        (0..WIDTH).map(move |x| (x, y, (x as u8, y as u8, (x ^ y) as u8)))
+    })
+}
@@ -0,0 +1,83 @@
+//! Benchmark of `draw_text`
+//!
+//! This compares drawing 1000 labels through the backend, which creates each
+//! font once, with creating a new font for each label as the backend used to
+//! do, twice per label: once to estimate the size of the label, and once to
+//! draw it.
+//!
+//! Run with `cargo bench --bench draw_text`.
+
+use std::process;
+use std::time::{Duration, Instant};
+
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+use wxdragon::{self as wx, DeviceContext};
+
+const WIDTH: i32 = 800;
+const HEIGHT: i32 = 600;
+const LABELS: i32 = 1000;
+
+fn main() {
+    let _ = wx::main(|_| {
+        let uncached = bench("uncached", |dc| {
+            for (x, y, label) in labels() {
+                for _ in 0..2 {
+                    let font = wx::Font::builder()
+                        .with_point_size(9)
+                        .with_family(wx::FontFamily::Swiss)
+                        .with_style(wx::FontStyle::Normal)
+                        .with_weight(wx::FontWeight::Normal)
+                        .with_underline(false)
+                        .with_face_name("")
+                        .build()
+                        .expect("failed to create font");
+                    dc.set_font(&font);
+                }
+                dc.draw_text(&label, x, y);
+            }
+        });
+        let cached = bench("cached", |dc| {
+            let mut backend = WxBackend::new_with_dpi(dc, 96.0);
+            let style = TextStyle::from(("sans-serif", 15).into_font());
+            for (x, y, label) in labels() {
+                backend
+                    .draw_text(&label, &style, (x, y))
+                    .expect("draw_text");
+            }
+            backend.present().expect("present");
+        });
+        println!(
+            "speedup: {:.1}x",
+            uncached.as_secs_f64() / cached.as_secs_f64()
+        );
+        process::exit(0);
+    });
+}
+
+/// Draws on a new in-memory device context and reports the elapsed time.
+fn bench<F>(name: &str, draw_fn: F) -> Duration
+where
+    F: FnOnce(&wx::MemoryDC),
+{
+    let mut bitmap =
+        wx::Bitmap::new(WIDTH, HEIGHT).expect("failed to create bitmap");
+    let mut dc = wx::MemoryDC::new();
+    dc.select_object(&mut bitmap);
+
+    let start = Instant::now();
+    draw_fn(&dc);
+    dc.select_object(&mut wx::Bitmap::null_bitmap());
+    let elapsed = start.elapsed();
+
+    println!("{name}: {LABELS} labels in {elapsed:?}");
+    elapsed
+}
+
+/// Tick-like labels on a grid covering the canvas.
+fn labels() -> impl Iterator<Item = (i32, i32, String)> {
+    (0..LABELS).map(|i| {
+        let (x, y) = (i % 20 * 40, i / 20 * 12);
+        (x, y, format!("{:.1}", i as f64 / 10.0))
+    })
+}
@@ -0,0 +1,70 @@
+//! Benchmark of pen and brush changes
+//!
+//! This compares drawing a long line series through the backend, which sets
+//! the pen of the device context only when it changes, with setting the pen
+//! on the device context before each segment.
+//!
+//! Run with `cargo bench --bench pen_cache`.
+
+use std::process;
+use std::time::{Duration, Instant};
+
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+use wxdragon::{self as wx, DeviceContext};
+
+const WIDTH: i32 = 800;
+const HEIGHT: i32 = 600;
+const SEGMENTS: i32 = 100_000;
+
+fn main() {
+    let _ = wx::main(|_| {
+        let direct = bench("direct", |dc| {
+            let color = wx::Colour::new(0, 0, 255, 255);
+            for (from, to) in segments() {
+                dc.set_pen(color, 1, wx::PenStyle::Solid);
+                dc.draw_line(from.0, from.1, to.0, to.1);
+            }
+        });
+        let cached = bench("cached", |dc| {
+            let mut backend = WxBackend::new(dc);
+            for (from, to) in segments() {
+                backend.draw_line(from, to, &BLUE).expect("draw_line");
+            }
+            backend.present().expect("present");
+        });
+        println!(
+            "speedup: {:.1}x",
+            direct.as_secs_f64() / cached.as_secs_f64()
+        );
+        process::exit(0);
+    });
+}
+
+/// Draws on a new in-memory device context and reports the elapsed time.
+///
+/// The direct run sets the pen once per segment, the cached run once in
+/// total.
+fn bench<F>(name: &str, draw_fn: F) -> Duration
+where
+    F: FnOnce(&wx::MemoryDC),
+{
+    let mut bitmap =
+        wx::Bitmap::new(WIDTH, HEIGHT).expect("failed to create bitmap");
+    let mut dc = wx::MemoryDC::new();
+    dc.select_object(&mut bitmap);
+
+    let start = Instant::now();
+    draw_fn(&dc);
+    dc.select_object(&mut wx::Bitmap::null_bitmap());
+    let elapsed = start.elapsed();
+
+    println!("{name}: {SEGMENTS} segments in {elapsed:?}");
+    elapsed
+}
+
+/// Segments of a zigzag line series across the canvas.
+fn segments() -> impl Iterator<Item = ((i32, i32), (i32, i32))> {
+    let point = |i: i32| (i * WIDTH / SEGMENTS, HEIGHT / 2 + (i % 50) * 4);
+    (0..SEGMENTS).map(move |i| (point(i), point(i + 1)))
+}
@@ -0,0 +1,77 @@
+//! Example of an animated plot.
+//!
+//! This example draws a sine wave moving to the right in an
+//! `AnimatedPlotPanel`, redrawn 30 times per second. Click the button to
+//! pause and resume the animation.
+//!
+//! The panel draws each frame with a backend bound to a `WxBackendState`, so
+//! the fonts of the caption and the labels are created for the first frame
+//! only.
+
+use std::time::Duration;
+
+use plotters::prelude::*;
+use plotters_wxdragon::{AnimatedPlotPanel, WxBackend};
+use wxdragon::{self as wx, WxWidget};
+
+fn draw(
+    backend: WxBackend<'_, wx::AutoBufferedPaintDC>,
+    frame: u64,
+    elapsed: Duration,
+) -> anyhow::Result<()> {
+    let root = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&root)
+        .caption(format!("Frame {frame}"), ("sans-serif", 30))
+        .margin(10)
+        .x_label_area_size(30)
+        .y_label_area_size(40)
+        .build_cartesian_2d(0.0..10.0, -1.2..1.2)?;
+    chart.configure_mesh().draw()?;
+
+    // one period every two seconds
+    let phase = elapsed.as_secs_f64() * std::f64::consts::PI;
+    chart.draw_series(LineSeries::new(
+        (0..=500)
+            .map(|i| i as f64 / 50.0)
+            .map(|x| (x, (x * 2.0 - phase).sin())),
+        &RED,
+    ))?;
+
+    root.present()?;
+    Ok(())
+}
+
+fn main() {
+    let _ = wxdragon::main(|_| {
+        let frame = wx::Frame::builder()
+            .with_title("Plotters example animation")
+            .with_size(wx::Size::new(800, 600))
+            .with_position(wx::Point::new(100, 100))
+            .build();
+
+        let pause_button =
+            wx::Button::builder(&frame).with_label("Pause").build();
+        let plot_panel = AnimatedPlotPanel::new(
+            &frame,
+            Duration::from_millis(1000 / 30),
+            draw,
+        );
+
+        let sizer = wx::BoxSizer::builder(wx::Orientation::Vertical).build();
+        sizer.add(&pause_button, 0, wx::SizerFlag::All, 5);
+        sizer.add(&*plot_panel, 1, wx::SizerFlag::Expand, 0);
+        frame.set_sizer(sizer, true);
+
+        pause_button.on_click(move |_event| {
+            if plot_panel.is_running() {
+                plot_panel.stop();
+                pause_button.set_label("Resume");
+            } else {
+                plot_panel.start();
+                pause_button.set_label("Pause");
+            }
+        });
+
+        frame.show(true);
+    });
+}
@@ -0,0 +1,117 @@
+//! Example of shading a region of interest behind a time series.
+//!
+//! This example draws a random walk, with a translucent band marking a region
+//! of interest, e.g. a recession period.
+//!
+//! The band uses `WxBackend::draw_band`, which is not part of the plotters
+//! API. To call it from a chart, the band is a custom element `Band` that
+//! implements `Drawable` for `WxBackend`: plotters maps its two corners to
+//! pixels, and hands the backend to the element. The band is drawn before
+//! the series, so that the series is drawn over it.
+
+use std::ops::Range;
+
+use plotters::element::{Drawable, PointCollection};
+use plotters::prelude::*;
+use plotters_backend::{BackendCoord, DrawingErrorKind};
+use plotters_wxdragon::WxBackend;
+use wxdragon::{self as wx, DeviceContext, WindowEvents, WxWidget};
+
+/// A plotters element shading a vertical band between two corners
+struct Band {
+    corners: [(f64, f64); 2],
+    color: RGBAColor,
+}
+
+impl Band {
+    /// A band over `x_range`, spanning the full `y_range` of the chart
+    fn new(x_range: Range<f64>, y_range: Range<f64>, color: RGBAColor) -> Self {
+        let corners =
+            [(x_range.start, y_range.start), (x_range.end, y_range.end)];
+        Band { corners, color }
+    }
+}
+
+impl<'a> PointCollection<'a, (f64, f64)> for &'a Band {
+    type Point = &'a (f64, f64);
+    type IntoIter = &'a [(f64, f64)];
+
+    fn point_iter(self) -> Self::IntoIter {
+        &self.corners
+    }
+}
+
+impl<'context, C> Drawable<WxBackend<'context, C>> for Band
+where
+    C: DeviceContext,
+{
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut pos: I,
+        backend: &mut WxBackend<'context, C>,
+        _parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<plotters_wxdragon::Error>> {
+        let (Some((x0, y0)), Some((x1, y1))) = (pos.next(), pos.next()) else {
+            return Ok(());
+        };
+        let color = self.color.to_backend_color();
+        backend.draw_band(x0, x1, (y0, y1), color)
+    }
+}
+
+/// Pseudo-random walk, so that the example is reproducible
+fn data() -> Vec<(f64, f64)> {
+    let mut seed = 12345u32;
+    let mut value = 0.0;
+    (0..200)
+        .map(|i| {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            value += (seed >> 8) as f64 / (1u32 << 24) as f64 - 0.5;
+            (i as f64, value)
+        })
+        .collect()
+}
+
+fn draw(dc: &wx::AutoBufferedPaintDC) -> anyhow::Result<()> {
+    let root = WxBackend::new(dc).into_drawing_area();
+    let (x_range, y_range) = (0f64..200.0, -10f64..10.0);
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Region of interest", ("sans-serif", 30))
+        .margin(10)
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(x_range, y_range.clone())?;
+    chart.configure_mesh().draw()?;
+
+    chart.draw_series(std::iter::once(Band::new(
+        80.0..120.0,
+        y_range,
+        RED.mix(0.2),
+    )))?;
+    chart.draw_series(LineSeries::new(data(), &BLUE))?;
+
+    root.present()?;
+    Ok(())
+}
+
+fn main() {
+    let _ = wxdragon::main(|_| {
+        let frame = wx::Frame::builder()
+            .with_title("Plotters example band")
+            .with_size(wx::Size::new(800, 600))
+            .with_position(wx::Point::new(100, 100))
+            .build();
+
+        let panel = wx::PanelBuilder::new(&frame).build();
+        panel.set_background_style(wx::BackgroundStyle::Paint);
+        panel.on_paint(move |_event| {
+            let dc = wx::AutoBufferedPaintDC::new(&panel);
+            draw(&dc).expect("draw");
+        });
+        panel.on_size(move |_event| {
+            panel.refresh(true, None);
+        });
+
+        frame.show(true);
+    });
+}
@@ -0,0 +1,135 @@
+//! Example of updating several settings of a plot with a single redraw.
+//!
+//! This example draws a sine wave in a `PlotPanel`. The toolbar "Next" button
+//! changes its frequency, its amplitude and its color. Each setter refreshes
+//! the panel, as it would when called alone, but the button calls them
+//! between `freeze` and `thaw`, so the plot is redrawn once. The number of
+//! frames drawn is shown in the caption.
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use plotters::prelude::*;
+use plotters_wxdragon::{PlotPanel, WxBackend};
+use wxdragon::{self as wx, WxWidget};
+
+/// Settings of the plot
+struct Settings {
+    frequency: f64,
+    amplitude: f64,
+    color: RGBColor,
+}
+
+/// Colors the wave cycles through
+const COLORS: [RGBColor; 3] = [RED, BLUE, GREEN];
+
+/// The plot panel and its settings
+struct Plot {
+    panel: PlotPanel,
+    settings: Rc<RefCell<Settings>>,
+}
+
+impl Plot {
+    fn set_frequency(&self, frequency: f64) {
+        self.settings.borrow_mut().frequency = frequency;
+        self.panel.refresh();
+    }
+
+    fn set_amplitude(&self, amplitude: f64) {
+        self.settings.borrow_mut().amplitude = amplitude;
+        self.panel.refresh();
+    }
+
+    fn set_color(&self, color: RGBColor) {
+        self.settings.borrow_mut().color = color;
+        self.panel.refresh();
+    }
+}
+
+fn draw(
+    backend: WxBackend<'_, wx::AutoBufferedPaintDC>,
+    settings: &Settings,
+    frame: u64,
+) -> anyhow::Result<()> {
+    let root = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&root)
+        .caption(format!("frame {frame}"), ("sans-serif", 30))
+        .margin(10)
+        .x_label_area_size(30)
+        .y_label_area_size(40)
+        .build_cartesian_2d(0.0..10.0, -1.0..1.0)?;
+    chart.configure_mesh().draw()?;
+    chart.draw_series(LineSeries::new(
+        (0..=1000)
+            .map(|i| i as f64 / 100.0)
+            .map(|x| (x, settings.amplitude * (settings.frequency * x).sin())),
+        &settings.color,
+    ))?;
+    root.present()?;
+    Ok(())
+}
+
+const ID_TOOL_NEXT: wx::Id = wx::ID_HIGHEST + 1;
+
+fn main() {
+    let _ = wxdragon::main(|_| {
+        let frame = wx::Frame::builder()
+            .with_title("Plotters example batch_update")
+            .with_size(wx::Size::new(800, 600))
+            .with_position(wx::Point::new(100, 100))
+            .build();
+
+        add_toolbar(&frame);
+
+        let settings = Rc::new(RefCell::new(Settings {
+            frequency: 1.0,
+            amplitude: 0.5,
+            color: COLORS[0],
+        }));
+        let paint_settings = settings.clone();
+        let frames = Cell::new(0);
+        let panel = PlotPanel::new(&frame, move |backend| {
+            frames.set(frames.get() + 1);
+            draw(backend, &paint_settings.borrow(), frames.get())
+        });
+        panel.on_error(|e| eprintln!("failed to draw plot: {e}"));
+        // Initial paint
+        panel.refresh();
+        let plot = Plot { panel, settings };
+
+        // toolbar events: change all settings, and redraw once
+        let step = Cell::new(0);
+        frame.on_menu(move |event| match event.get_id() {
+            ID_TOOL_NEXT => {
+                step.set(step.get() + 1);
+                let step = step.get();
+                plot.panel.freeze();
+                plot.set_frequency(1.0 + (step % 4) as f64);
+                plot.set_amplitude(0.25 * (1 + step % 4) as f64);
+                plot.set_color(COLORS[step % COLORS.len()]);
+                plot.panel.thaw();
+            }
+            _ => {
+                event.skip(true);
+            }
+        });
+
+        frame.show(true);
+    });
+}
+
+/// Creates the toolbar with the next tool
+fn add_toolbar(frame: &wx::Frame) {
+    use wx::ArtClient::Toolbar;
+    use wx::ArtId::GoForward;
+    if let Some(toolbar) = frame
+        .create_tool_bar(Some(wx::ToolBarStyle::Default), wx::ID_ANY as i32)
+    {
+        if let Some(new_icon) =
+            wx::ArtProvider::get_bitmap(GoForward, Toolbar, None)
+        {
+            toolbar.add_tool(ID_TOOL_NEXT, "Next", &new_icon, "Next settings");
+        }
+        toolbar.realize();
+    }
+}
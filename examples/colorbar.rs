@@ -0,0 +1,134 @@
+//! Example of a heatmap with a colorbar.
+//!
+//! This example draws a heatmap of a 2D function as a grid of filled
+//! rectangles, and a colorbar mapping its colors to values in a side area.
+//!
+//! The colorbar uses `WxBackend::draw_colorbar`, which is not part of the
+//! plotters API. To call it from a drawing area, the colorbar is a custom
+//! element `Colorbar` that implements `Drawable` for `WxBackend`: plotters
+//! maps its corners to device pixels, and hands the backend to the element.
+//! The heatmap and the colorbar share the same colormap.
+
+use plotters::element::{Drawable, PointCollection};
+use plotters::prelude::*;
+use plotters_backend::{BackendCoord, DrawingErrorKind};
+use plotters_wxdragon::{LegendOrientation, WxBackend};
+use wxdragon::{self as wx, DeviceContext, WindowEvents, WxWidget};
+
+/// Range of the values of the heatmap
+const VALUE_RANGE: std::ops::Range<f64> = -1.0..1.0;
+
+/// Width of the area of the colorbar, right of the heatmap
+const COLORBAR_AREA_WIDTH: u32 = 100;
+
+/// Blue for the lowest values to red for the highest values
+fn colormap(value: f64) -> HSLColor {
+    let ratio =
+        (value - VALUE_RANGE.start) / (VALUE_RANGE.end - VALUE_RANGE.start);
+    HSLColor(2.0 / 3.0 * (1.0 - ratio.clamp(0.0, 1.0)), 0.8, 0.5)
+}
+
+/// Function shown on the heatmap
+fn f(x: f64, y: f64) -> f64 {
+    (x * y).sin()
+}
+
+/// A plotters element drawing a vertical colorbar between two corners
+struct Colorbar {
+    corners: [BackendCoord; 2],
+}
+
+impl<'a> PointCollection<'a, BackendCoord> for &'a Colorbar {
+    type Point = &'a BackendCoord;
+    type IntoIter = &'a [BackendCoord];
+
+    fn point_iter(self) -> Self::IntoIter {
+        &self.corners
+    }
+}
+
+impl<'context, C> Drawable<WxBackend<'context, C>> for Colorbar
+where
+    C: DeviceContext,
+{
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut pos: I,
+        backend: &mut WxBackend<'context, C>,
+        _parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<plotters_wxdragon::Error>> {
+        let (Some(upper_left), Some(bottom_right)) = (pos.next(), pos.next())
+        else {
+            return Ok(());
+        };
+        let label_style = TextStyle::from(("sans-serif", 15).into_font());
+        backend.draw_colorbar(
+            (upper_left, bottom_right),
+            |value| colormap(value).to_backend_color(),
+            VALUE_RANGE,
+            LegendOrientation::Vertical,
+            &label_style,
+        )
+    }
+}
+
+fn draw(dc: &wx::AutoBufferedPaintDC) -> anyhow::Result<()> {
+    let root = WxBackend::new(dc).into_drawing_area();
+    let (width, _) = root.dim_in_pixel();
+    let (heatmap_area, colorbar_area) =
+        root.split_horizontally(width.saturating_sub(COLORBAR_AREA_WIDTH));
+
+    let mut chart = ChartBuilder::on(&heatmap_area)
+        .caption("sin(x y)", ("sans-serif", 30))
+        .margin(10)
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(-3f64..3f64, -3f64..3f64)?;
+    chart.configure_mesh().disable_mesh().draw()?;
+    let n = 60;
+    let step = 6.0 / n as f64;
+    chart.draw_series((0..n).flat_map(|i| (0..n).map(move |j| (i, j))).map(
+        |(i, j)| {
+            let x = -3.0 + i as f64 * step;
+            let y = -3.0 + j as f64 * step;
+            let value = f(x + step / 2.0, y + step / 2.0);
+            Rectangle::new(
+                [(x, y), (x + step, y + step)],
+                colormap(value).filled(),
+            )
+        },
+    ))?;
+
+    // align the colorbar with the plotting area, both areas start at the
+    // top of the window
+    let (_, y_pixels) = chart.plotting_area().get_pixel_range();
+    let (top, bottom) = (y_pixels.start, y_pixels.end - 1);
+    colorbar_area.draw(&Colorbar {
+        corners: [(10, top), (30, bottom)],
+    })?;
+
+    root.present()?;
+    Ok(())
+}
+
+fn main() {
+    let _ = wxdragon::main(|_| {
+        let frame = wx::Frame::builder()
+            .with_title("Plotters example colorbar")
+            .with_size(wx::Size::new(800, 600))
+            .with_position(wx::Point::new(100, 100))
+            .build();
+
+        let panel = wx::PanelBuilder::new(&frame).build();
+        panel.set_background_style(wx::BackgroundStyle::Paint);
+        panel.on_paint(move |_event| {
+            let dc = wx::AutoBufferedPaintDC::new(&panel);
+            draw(&dc).expect("draw");
+        });
+        panel.on_size(move |_event| {
+            panel.refresh(true, None);
+        });
+
+        frame.show(true);
+    });
+}
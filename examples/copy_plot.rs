@@ -0,0 +1,79 @@
+//! Example of copying a plot to the clipboard.
+//!
+//! This example draws the same $y=x^2$ plot as the `x2` example, with a
+//! toolbar "Copy" button that copies the plot, at the size of the panel, to
+//! the clipboard with `copy_plot_to_clipboard`. The same drawing function
+//! draws on the panel and off-screen.
+
+use plotters::prelude::*;
+use plotters_wxdragon::{PlotPanel, WxBackend, copy_plot_to_clipboard};
+use wxdragon::{self as wx, DeviceContext, WxWidget};
+
+fn draw<C: DeviceContext>(backend: WxBackend<'_, C>) -> anyhow::Result<()> {
+    let root = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&root)
+        .caption("y=x^2", ("sans-serif", 50).into_font())
+        .margin(5)
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(-1f32..1f32, -0.1f32..1f32)?;
+    chart.configure_mesh().draw()?;
+    chart.draw_series(LineSeries::new(
+        (-50..=50).map(|x| x as f32 / 50.0).map(|x| (x, x * x)),
+        &RED,
+    ))?;
+    root.present()?;
+    Ok(())
+}
+
+const ID_TOOL_COPY: wx::Id = wx::ID_HIGHEST + 1;
+
+fn main() {
+    let _ = wxdragon::main(|_| {
+        let frame = wx::Frame::builder()
+            .with_title("Plotters example copy_plot")
+            .with_size(wx::Size::new(800, 600))
+            .with_position(wx::Point::new(100, 100))
+            .build();
+
+        add_toolbar(&frame);
+
+        let plot_panel = PlotPanel::new(&frame, draw);
+
+        // toolbar events: copy the plot at the size of the panel
+        let menu_panel = plot_panel.clone();
+        frame.on_menu(move |event| match event.get_id() {
+            ID_TOOL_COPY => {
+                let size = menu_panel.get_size();
+                let width = size.width.max(1) as u32;
+                let height = size.height.max(1) as u32;
+                if let Err(e) = copy_plot_to_clipboard(width, height, draw) {
+                    eprintln!("failed to copy the plot: {e}");
+                }
+            }
+            _ => {
+                event.skip(true);
+            }
+        });
+
+        // Initial paint
+        plot_panel.refresh();
+
+        frame.show(true);
+    });
+}
+
+/// Creates the toolbar with the copy tool
+fn add_toolbar(frame: &wx::Frame) {
+    use wx::ArtClient::Toolbar;
+    use wx::ArtId::Copy;
+    if let Some(toolbar) = frame
+        .create_tool_bar(Some(wx::ToolBarStyle::Default), wx::ID_ANY as i32)
+    {
+        if let Some(new_icon) = wx::ArtProvider::get_bitmap(Copy, Toolbar, None)
+        {
+            toolbar.add_tool(ID_TOOL_COPY, "Copy", &new_icon, "Copy the plot");
+        }
+        toolbar.realize();
+    }
+}
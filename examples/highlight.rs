@@ -0,0 +1,116 @@
+//! Example of highlighting the data points inside a selected region.
+//!
+//! This example draws a scatter plot and a rectangular selection, then
+//! highlights the points inside the selection with translucent circles.
+//!
+//! The highlight uses `WxBackend::highlight_points`, which is not part of the
+//! plotters API. To call it from a chart, the points are wrapped in a custom
+//! element `Highlight` that implements `Drawable` for `WxBackend`: plotters
+//! maps the data coordinates to pixels, and hands the backend to the element.
+
+use plotters::element::{Drawable, PointCollection};
+use plotters::prelude::*;
+use plotters_backend::{BackendCoord, DrawingErrorKind};
+use plotters_wxdragon::WxBackend;
+use wxdragon::{self as wx, DeviceContext, WindowEvents, WxWidget};
+
+/// Selected region, in data coordinates
+const SELECTION: ((f64, f64), (f64, f64)) = ((0.2, 0.3), (0.6, 0.8));
+
+/// A plotters element highlighting data points
+struct Highlight {
+    points: Vec<(f64, f64)>,
+    radius: u32,
+    color: RGBAColor,
+}
+
+impl<'a> PointCollection<'a, (f64, f64)> for &'a Highlight {
+    type Point = &'a (f64, f64);
+    type IntoIter = &'a [(f64, f64)];
+
+    fn point_iter(self) -> Self::IntoIter {
+        &self.points
+    }
+}
+
+impl<'context, C> Drawable<WxBackend<'context, C>> for Highlight
+where
+    C: DeviceContext,
+{
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        pos: I,
+        backend: &mut WxBackend<'context, C>,
+        _parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<plotters_wxdragon::Error>> {
+        let color = self.color.to_backend_color();
+        backend.highlight_points(pos, self.radius, color)
+    }
+}
+
+/// Pseudo-random points, so that the example is reproducible
+fn data() -> Vec<(f64, f64)> {
+    let mut seed = 12345u32;
+    let mut next = move || {
+        seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+        (seed >> 8) as f64 / (1u32 << 24) as f64
+    };
+    (0..100).map(|_| (next(), next())).collect()
+}
+
+fn draw(dc: &wx::AutoBufferedPaintDC) -> anyhow::Result<()> {
+    let root = WxBackend::new(dc).into_drawing_area();
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Selected points", ("sans-serif", 30))
+        .margin(10)
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(0f64..1f64, 0f64..1f64)?;
+    chart.configure_mesh().draw()?;
+
+    let points = data();
+    chart.draw_series(
+        points.iter().map(|&p| Circle::new(p, 3, BLUE.filled())),
+    )?;
+
+    let ((x0, y0), (x1, y1)) = SELECTION;
+    chart.draw_series(std::iter::once(Rectangle::new(
+        [(x0, y0), (x1, y1)],
+        BLACK,
+    )))?;
+
+    let selected = points
+        .into_iter()
+        .filter(|&(x, y)| x0 <= x && x <= x1 && y0 <= y && y <= y1)
+        .collect();
+    chart.draw_series(std::iter::once(Highlight {
+        points: selected,
+        radius: 8,
+        color: RED.mix(0.4),
+    }))?;
+
+    root.present()?;
+    Ok(())
+}
+
+fn main() {
+    let _ = wxdragon::main(|_| {
+        let frame = wx::Frame::builder()
+            .with_title("Plotters example highlight")
+            .with_size(wx::Size::new(800, 600))
+            .with_position(wx::Point::new(100, 100))
+            .build();
+
+        let panel = wx::PanelBuilder::new(&frame).build();
+        panel.set_background_style(wx::BackgroundStyle::Paint);
+        panel.on_paint(move |_event| {
+            let dc = wx::AutoBufferedPaintDC::new(&panel);
+            draw(&dc).expect("draw");
+        });
+        panel.on_size(move |_event| {
+            panel.refresh(true, None);
+        });
+
+        frame.show(true);
+    });
+}
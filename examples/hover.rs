@@ -0,0 +1,96 @@
+//! Example of highlighting the series under the mouse cursor.
+//!
+//! This example draws several overlapping series, and draws the series
+//! nearest to the mouse cursor with a thicker line.
+//!
+//! The series are mapped to pixels with the chart coordinates, and the
+//! hovered series is found with `nearest_series`. The cursor position is
+//! kept in a state shared between the mouse and paint handlers.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use plotters::prelude::*;
+use plotters_wxdragon::{WxBackend, nearest_series};
+use wxdragon::{self as wx, WindowEvents, WxWidget};
+
+/// Maximum distance in pixels from the cursor to a hovered series
+const HOVER_DISTANCE: f64 = 8.0;
+
+/// Damped oscillations with different frequencies
+fn data() -> Vec<Vec<(f64, f64)>> {
+    (1..=5)
+        .map(|k| {
+            (0..=200)
+                .map(|i| {
+                    let x = i as f64 / 20.0;
+                    (x, (-x / 5.0).exp() * (k as f64 * x / 2.0).sin())
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn draw(
+    dc: &wx::AutoBufferedPaintDC,
+    cursor: Option<(i32, i32)>,
+) -> anyhow::Result<()> {
+    let root = WxBackend::new(dc).into_drawing_area();
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Hover a series", ("sans-serif", 30))
+        .margin(10)
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(0f64..10f64, -1f64..1f64)?;
+    chart.configure_mesh().draw()?;
+
+    let series = data();
+    let polylines: Vec<Vec<(i32, i32)>> = series
+        .iter()
+        .map(|points| points.iter().map(|p| chart.backend_coord(p)).collect())
+        .collect();
+    let hovered = cursor
+        .and_then(|cursor| nearest_series(&polylines, cursor, HOVER_DISTANCE));
+
+    for (index, points) in series.into_iter().enumerate() {
+        let color = Palette99::pick(index);
+        let width = if hovered == Some(index) { 4 } else { 1 };
+        chart
+            .draw_series(LineSeries::new(points, color.stroke_width(width)))?;
+    }
+
+    root.present()?;
+    Ok(())
+}
+
+fn main() {
+    let _ = wxdragon::main(|_| {
+        let frame = wx::Frame::builder()
+            .with_title("Plotters example hover")
+            .with_size(wx::Size::new(800, 600))
+            .with_position(wx::Point::new(100, 100))
+            .build();
+
+        let cursor = Rc::new(RefCell::new(None));
+        let panel = wx::PanelBuilder::new(&frame).build();
+        panel.set_background_style(wx::BackgroundStyle::Paint);
+        {
+            let cursor = cursor.clone();
+            panel.on_paint(move |_event| {
+                let dc = wx::AutoBufferedPaintDC::new(&panel);
+                draw(&dc, *cursor.borrow()).expect("draw");
+            });
+        }
+        panel.on_mouse_motion(move |event| {
+            if let wx::WindowEventData::MouseMotion(event) = event {
+                *cursor.borrow_mut() = event.get_position().map(|p| (p.x, p.y));
+            }
+            panel.refresh(false, None);
+        });
+        panel.on_size(move |_event| {
+            panel.refresh(true, None);
+        });
+
+        frame.show(true);
+    });
+}
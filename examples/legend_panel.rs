@@ -0,0 +1,78 @@
+//! Example of a legend drawn in a side panel.
+//!
+//! The frame has two panels side by side: a chart panel showing two series
+//! without a legend, and a legend panel drawing the legend entries of the
+//! series with `WxBackend::draw_legend`. Both panels use the same series
+//! definitions, so the legend always matches the chart.
+
+use plotters::prelude::*;
+use plotters_wxdragon::{LegendLayout, WxBackend};
+use wxdragon::{self as wx, WindowEvents, WxWidget};
+
+/// Series shown in the chart: name, color and function
+const SERIES: [(&str, RGBColor, fn(f64) -> f64); 2] =
+    [("sine", RED, f64::sin), ("cosine", BLUE, f64::cos)];
+
+fn draw_chart(dc: &wx::AutoBufferedPaintDC) -> anyhow::Result<()> {
+    let root = WxBackend::new(dc).into_drawing_area();
+    let mut chart = ChartBuilder::on(&root)
+        .margin(10)
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(-3.2f64..3.2, -1.2f64..1.2)?;
+    chart.configure_mesh().draw()?;
+    for (_, color, f) in SERIES {
+        chart.draw_series(LineSeries::new(
+            (-320..=320).map(|x| x as f64 / 100.0).map(|x| (x, f(x))),
+            &color,
+        ))?;
+    }
+    root.present()?;
+    Ok(())
+}
+
+fn draw_legend(dc: &wx::AutoBufferedPaintDC) -> anyhow::Result<()> {
+    let mut backend = WxBackend::new(dc);
+    let entries: Vec<_> = SERIES
+        .iter()
+        .map(|(name, color, _)| (*name, color.filled()))
+        .collect();
+    let text_style = TextStyle::from(("sans-serif", 20).into_font());
+    backend.draw_legend(&entries, &text_style, LegendLayout::default())?;
+    backend.present()?;
+    Ok(())
+}
+
+fn main() {
+    let _ = wxdragon::main(|_| {
+        let frame = wx::Frame::builder()
+            .with_title("Plotters example legend_panel")
+            .with_size(wx::Size::new(800, 600))
+            .with_position(wx::Point::new(100, 100))
+            .build();
+
+        let chart_panel = wx::PanelBuilder::new(&frame).build();
+        chart_panel.set_background_style(wx::BackgroundStyle::Paint);
+        chart_panel.on_paint(move |_event| {
+            let dc = wx::AutoBufferedPaintDC::new(&chart_panel);
+            draw_chart(&dc).expect("draw chart");
+        });
+        chart_panel.on_size(move |_event| {
+            chart_panel.refresh(true, None);
+        });
+
+        let legend_panel = wx::PanelBuilder::new(&frame).build();
+        legend_panel.set_background_style(wx::BackgroundStyle::Paint);
+        legend_panel.on_paint(move |_event| {
+            let dc = wx::AutoBufferedPaintDC::new(&legend_panel);
+            draw_legend(&dc).expect("draw legend");
+        });
+
+        let sizer = wx::BoxSizer::builder(wx::Orientation::Horizontal).build();
+        sizer.add(&chart_panel, 4, wx::SizerFlag::Expand, 0);
+        sizer.add(&legend_panel, 1, wx::SizerFlag::Expand, 0);
+        frame.set_sizer(sizer, true);
+
+        frame.show(true);
+    });
+}
@@ -0,0 +1,79 @@
+//! Example of a legend whose entries toggle their series.
+//!
+//! This example draws two series in a `PlotPanel`, with a legend drawn by
+//! `WxBackend::draw_legend` in the upper-left corner. Clicking an entry
+//! hides or shows its series. With the keyboard, once the plot has the
+//! focus, e.g. after a click, Tab moves the focus to the next entry, shown by
+//! a dotted ring, and Space or Enter toggles its series.
+
+use plotters::prelude::*;
+use plotters_wxdragon::{LegendLayout, LegendToggle, PlotPanel, WxBackend};
+use wxdragon::{self as wx, WxWidget};
+
+/// Series shown in the chart: name, color and function
+const SERIES: [(&str, RGBColor, fn(f64) -> f64); 2] =
+    [("sine", RED, f64::sin), ("cosine", BLUE, f64::cos)];
+
+fn draw(
+    mut backend: WxBackend<'_, wx::AutoBufferedPaintDC>,
+    toggle: &LegendToggle,
+) -> anyhow::Result<()> {
+    // draw the chart with a second backend on the same device context, to
+    // keep this one for the legend
+    let root = WxBackend::new_no_clear(backend.context()).into_drawing_area();
+    let mut chart = ChartBuilder::on(&root)
+        .margin(10)
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(-3.2f64..3.2, -1.2f64..1.2)?;
+    chart.configure_mesh().draw()?;
+    for (index, (_, color, f)) in SERIES.iter().enumerate() {
+        if toggle.is_visible(index) {
+            chart.draw_series(LineSeries::new(
+                (-320..=320).map(|x| x as f64 / 100.0).map(|x| (x, f(x))),
+                color,
+            ))?;
+        }
+    }
+    root.present()?;
+
+    let entries: Vec<_> = SERIES
+        .iter()
+        .map(|(name, color, _)| (*name, color.filled()))
+        .collect();
+    let labels: Vec<&str> = SERIES.iter().map(|(name, _, _)| *name).collect();
+    let text_style = TextStyle::from(("sans-serif", 20).into_font());
+    let layout = LegendLayout {
+        origin: (60, 20),
+        ..LegendLayout::default()
+    };
+    backend.draw_legend(&entries, &text_style, layout)?;
+    toggle.set_regions(backend.legend_regions(&labels, &text_style, layout)?);
+    toggle.draw_focus(&mut backend, &BLACK)?;
+    Ok(())
+}
+
+fn main() {
+    let _ = wxdragon::main(|_| {
+        let frame = wx::Frame::builder()
+            .with_title("Plotters example legend_toggle")
+            .with_size(wx::Size::new(800, 600))
+            .with_position(wx::Point::new(100, 100))
+            .build();
+
+        let toggle = LegendToggle::new(SERIES.len());
+        let paint_toggle = toggle.clone();
+        let panel =
+            PlotPanel::new(&frame, move |backend| draw(backend, &paint_toggle));
+        panel.on_error(|e| eprintln!("failed to draw plot: {e}"));
+        toggle.attach(&panel, |index, visible| {
+            let (name, _, _) = SERIES[index];
+            println!("{name} {}", if visible { "shown" } else { "hidden" });
+        });
+
+        // Initial paint
+        panel.refresh();
+
+        frame.show(true);
+    });
+}
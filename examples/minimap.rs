@@ -0,0 +1,92 @@
+//! Example of a zoomable plot with a minimap.
+//!
+//! This example draws a damped oscillation in an `InteractivePlotPanel`, as
+//! the `zoom_pan` example, with a minimap in the bottom-right corner: a
+//! scaled-down copy of the whole curve, with a rectangle around the part
+//! shown by the zoomed plot. Scroll to zoom, and drag to pan.
+
+use std::ops::Range;
+
+use plotters::prelude::*;
+use plotters_wxdragon::{InteractivePlotPanel, WxBackend};
+use wxdragon::{self as wx, DeviceContext, WxWidget};
+
+const X_RANGE: Range<f64> = 0.0..10.0;
+const Y_RANGE: Range<f64> = -1.0..1.0;
+
+/// Draw the curve over the data ranges, and return their pixel area.
+fn draw_chart<C: DeviceContext>(
+    backend: WxBackend<'_, C>,
+    x_range: Range<f64>,
+    y_range: Range<f64>,
+) -> anyhow::Result<(Range<i32>, Range<i32>)> {
+    let root = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Scroll or drag", ("sans-serif", 30))
+        .margin(10)
+        .x_label_area_size(30)
+        .y_label_area_size(40)
+        .build_cartesian_2d(x_range.clone(), y_range)?;
+    chart.configure_mesh().draw()?;
+
+    let samples = 1000;
+    let step = (x_range.end - x_range.start) / samples as f64;
+    chart.draw_series(LineSeries::new(
+        (0..=samples)
+            .map(|i| x_range.start + i as f64 * step)
+            .map(|x| (x, (-x / 5.0).exp() * (3.0 * x).sin())),
+        &RED,
+    ))?;
+
+    root.present()?;
+    Ok(chart.plotting_area().get_pixel_range())
+}
+
+fn draw(
+    mut backend: WxBackend<'_, wx::AutoBufferedPaintDC>,
+    x_range: Range<f64>,
+    y_range: Range<f64>,
+) -> anyhow::Result<(Range<i32>, Range<i32>)> {
+    // draw the zoomed plot with a second backend on the same device
+    // context, to keep this one for the minimap
+    let pixel_area = draw_chart(
+        WxBackend::new_no_clear(backend.context()),
+        x_range.clone(),
+        y_range.clone(),
+    )?;
+
+    let (width, height) = backend.get_size();
+    let (width, height) = (width as i32, height as i32);
+    let inset = (
+        (width - width / 4 - 10, height - height / 4 - 10),
+        (width - 11, height - 11),
+    );
+    backend.draw_minimap(
+        inset,
+        (X_RANGE, Y_RANGE),
+        (x_range, y_range),
+        &BLUE.stroke_width(2),
+        draw_chart,
+    )?;
+    backend.draw_rect(inset.0, inset.1, &BLACK, false)?;
+    backend.present()?;
+    Ok(pixel_area)
+}
+
+fn main() {
+    let _ = wxdragon::main(|_| {
+        let frame = wx::Frame::builder()
+            .with_title("Plotters example minimap")
+            .with_size(wx::Size::new(800, 600))
+            .with_position(wx::Point::new(100, 100))
+            .build();
+
+        let plot_panel =
+            InteractivePlotPanel::new(&frame, X_RANGE, Y_RANGE, draw);
+
+        // Initial paint
+        plot_panel.refresh();
+
+        frame.show(true);
+    });
+}
@@ -0,0 +1,140 @@
+//! Example of line styles and widths.
+//!
+//! This example draws a zig-zag path in a `PlotPanel`, with the line style
+//! set by `WxBackend::set_line_style`. The toolbar buttons cycle through
+//! solid, dashed, dotted and dash-dotted lines, and make the line thicker or
+//! thinner. The caption shows the current settings.
+//!
+//! wxdragon pens only take a color, a width and a style, so the caps and
+//! joins of lines are those of wxWidgets, i.e. round, and cannot be chosen.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use plotters::prelude::*;
+use plotters_wxdragon::{PlotPanel, WxBackend};
+use wxdragon::{self as wx, WxWidget};
+
+/// Line styles the toolbar cycles through, with their names
+const STYLES: [(wx::PenStyle, &str); 4] = [
+    (wx::PenStyle::Solid, "solid"),
+    (wx::PenStyle::LongDash, "dashed"),
+    (wx::PenStyle::Dot, "dotted"),
+    (wx::PenStyle::DotDash, "dash-dotted"),
+];
+
+/// Shared application state
+struct State {
+    /// Index of the line style in `STYLES`
+    style: usize,
+    /// Stroke width, in pixels
+    width: u32,
+}
+
+fn draw(
+    backend: WxBackend<'_, wx::AutoBufferedPaintDC>,
+    state: &State,
+) -> anyhow::Result<()> {
+    let (pen_style, name) = STYLES[state.style];
+    backend.set_line_style(pen_style);
+    let root = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&root)
+        .caption(
+            format!("{name}, {} pixels wide", state.width),
+            ("sans-serif", 30),
+        )
+        .margin(20)
+        .build_cartesian_2d(0.0..10.0, 0.0..1.0)?;
+    chart.draw_series(LineSeries::new(
+        (0..=10).map(|x| (x as f64, if x % 2 == 0 { 0.1 } else { 0.9 })),
+        BLUE.stroke_width(state.width),
+    ))?;
+    root.present()?;
+    Ok(())
+}
+
+const ID_TOOL_STYLE: wx::Id = wx::ID_HIGHEST + 1;
+const ID_TOOL_THICKER: wx::Id = wx::ID_HIGHEST + 2;
+const ID_TOOL_THINNER: wx::Id = wx::ID_HIGHEST + 3;
+
+fn main() {
+    let _ = wxdragon::main(|_| {
+        let frame = wx::Frame::builder()
+            .with_title("Plotters example pen_styles")
+            .with_size(wx::Size::new(800, 600))
+            .with_position(wx::Point::new(100, 100))
+            .build();
+
+        add_toolbar(&frame);
+
+        let state = Rc::new(RefCell::new(State { style: 1, width: 3 }));
+        let paint_state = state.clone();
+        let plot_panel = PlotPanel::new(&frame, move |backend| {
+            draw(backend, &paint_state.borrow())
+        });
+        plot_panel.on_error(|e| eprintln!("failed to draw plot: {e}"));
+
+        // toolbar events: change the line style or width
+        let menu_panel = plot_panel.clone();
+        frame.on_menu(move |event| {
+            let mut state = state.borrow_mut();
+            match event.get_id() {
+                ID_TOOL_STYLE => state.style = (state.style + 1) % STYLES.len(),
+                ID_TOOL_THICKER => state.width = (state.width + 1).min(20),
+                ID_TOOL_THINNER => {
+                    state.width = state.width.saturating_sub(1).max(1)
+                }
+                _ => {
+                    event.skip(true);
+                    return;
+                }
+            }
+            menu_panel.refresh();
+        });
+
+        // Initial paint
+        plot_panel.refresh();
+
+        frame.show(true);
+    });
+}
+
+/// Creates the toolbar with the style and width tools
+fn add_toolbar(frame: &wx::Frame) {
+    use wx::ArtClient::Toolbar;
+    use wx::ArtId::{GoDown, GoForward, GoUp};
+    if let Some(toolbar) = frame
+        .create_tool_bar(Some(wx::ToolBarStyle::Default), wx::ID_ANY as i32)
+    {
+        if let Some(new_icon) =
+            wx::ArtProvider::get_bitmap(GoForward, Toolbar, None)
+        {
+            toolbar.add_tool(
+                ID_TOOL_STYLE,
+                "Style",
+                &new_icon,
+                "Next line style",
+            );
+        }
+        if let Some(new_icon) = wx::ArtProvider::get_bitmap(GoUp, Toolbar, None)
+        {
+            toolbar.add_tool(
+                ID_TOOL_THICKER,
+                "Thicker",
+                &new_icon,
+                "Thicker line",
+            );
+        }
+        if let Some(new_icon) =
+            wx::ArtProvider::get_bitmap(GoDown, Toolbar, None)
+        {
+            toolbar.add_tool(
+                ID_TOOL_THINNER,
+                "Thinner",
+                &new_icon,
+                "Thinner line",
+            );
+        }
+        toolbar.realize();
+    }
+}
@@ -0,0 +1,44 @@
+//! Example of a plot drawn with `PlotPanel`.
+//!
+//! This example draws the same $y=x^2$ plot as the `x2` example, but lets
+//! `PlotPanel` create the panel and register the paint and size handlers.
+//! Drawing errors are printed on the standard error.
+
+use plotters::prelude::*;
+use plotters_wxdragon::{PlotPanel, WxBackend};
+use wxdragon::{self as wx, WxWidget};
+
+fn draw(backend: WxBackend<'_, wx::AutoBufferedPaintDC>) -> anyhow::Result<()> {
+    let root = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&root)
+        .caption("y=x^2", ("sans-serif", 50).into_font())
+        .margin(5)
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(-1f32..1f32, -0.1f32..1f32)?;
+    chart.configure_mesh().draw()?;
+    chart.draw_series(LineSeries::new(
+        (-50..=50).map(|x| x as f32 / 50.0).map(|x| (x, x * x)),
+        &RED,
+    ))?;
+    root.present()?;
+    Ok(())
+}
+
+fn main() {
+    let _ = wxdragon::main(|_| {
+        let frame = wx::Frame::builder()
+            .with_title("Plotters example plot_panel")
+            .with_size(wx::Size::new(800, 600))
+            .with_position(wx::Point::new(100, 100))
+            .build();
+
+        let plot_panel = PlotPanel::new(&frame, draw);
+        plot_panel.on_error(|e| eprintln!("failed to draw plot: {e}"));
+
+        // Initial paint
+        plot_panel.refresh();
+
+        frame.show(true);
+    });
+}
@@ -0,0 +1,48 @@
+//! Example of a plot kept square in a window of any shape.
+//!
+//! This example draws a unit circle in a `PlotPanel` with an aspect ratio of
+//! 1, so that the circle stays round: the plot is drawn in the largest
+//! centered square of the wide window, with white margins on its sides, and
+//! is laid out again when the window is resized.
+
+use plotters::prelude::*;
+use plotters_wxdragon::{PlotPanel, WxBackend};
+use wxdragon::{self as wx, WxWidget};
+
+fn draw(backend: WxBackend<'_, wx::AutoBufferedPaintDC>) -> anyhow::Result<()> {
+    let root = backend.into_drawing_area();
+    root.fill(&RGBColor(240, 240, 250))?;
+    let mut chart = ChartBuilder::on(&root)
+        .caption("x^2 + y^2 = 1", ("sans-serif", 30))
+        .margin(10)
+        .x_label_area_size(30)
+        .y_label_area_size(40)
+        .build_cartesian_2d(-1.2f64..1.2f64, -1.2f64..1.2f64)?;
+    chart.configure_mesh().draw()?;
+    chart.draw_series(LineSeries::new(
+        (0..=200)
+            .map(|i| i as f64 / 200.0 * std::f64::consts::TAU)
+            .map(|t| (t.cos(), t.sin())),
+        &RED,
+    ))?;
+    root.present()?;
+    Ok(())
+}
+
+fn main() {
+    let _ = wxdragon::main(|_| {
+        let frame = wx::Frame::builder()
+            .with_title("Plotters example square")
+            .with_size(wx::Size::new(1000, 500))
+            .with_position(wx::Point::new(100, 100))
+            .build();
+
+        let plot_panel = PlotPanel::new(&frame, draw).with_aspect_ratio(1.0);
+        plot_panel.on_error(|e| eprintln!("failed to draw plot: {e}"));
+
+        // Initial paint
+        plot_panel.refresh();
+
+        frame.show(true);
+    });
+}
@@ -0,0 +1,73 @@
+//! Example of a plot of streaming data.
+//!
+//! This example feeds noisy samples to a `StreamingPlotPanel` from a timer,
+//! 50 times per second. The panel keeps the last 200 samples, and the x axis
+//! scrolls with them.
+
+use std::collections::VecDeque;
+use std::ops::Range;
+
+use plotters::prelude::*;
+use plotters_wxdragon::{StreamingPlotPanel, WxBackend};
+use wxdragon::{self as wx, WxWidget};
+
+fn draw(
+    backend: WxBackend<'_, wx::AutoBufferedPaintDC>,
+    points: &VecDeque<(f64, f64)>,
+    x_range: Range<f64>,
+) -> anyhow::Result<()> {
+    let root = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Last 200 samples", ("sans-serif", 30))
+        .margin(10)
+        .x_label_area_size(30)
+        .y_label_area_size(40)
+        .build_cartesian_2d(x_range, -2.0..2.0)?;
+    chart.configure_mesh().draw()?;
+    chart.draw_series(LineSeries::new(points.iter().copied(), &RED))?;
+    root.present()?;
+    Ok(())
+}
+
+/// A xorshift pseudo-random generator, enough for noise in an example.
+struct Noise(u64);
+
+impl Noise {
+    /// Next value, uniform in `-0.5..0.5`.
+    fn next(&mut self) -> f64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 >> 11) as f64 / (1u64 << 53) as f64 - 0.5
+    }
+}
+
+fn main() {
+    let _ = wxdragon::main(|_| {
+        let frame = wx::Frame::builder()
+            .with_title("Plotters example streaming")
+            .with_size(wx::Size::new(800, 600))
+            .with_position(wx::Point::new(100, 100))
+            .build();
+
+        let plot_panel = StreamingPlotPanel::new(&frame, 200, draw);
+
+        // feed a noisy sine wave, one sample every 20 ms
+        let timer = wx::Timer::new(&frame);
+        let mut noise = Noise(0x2545_f491_4f6c_dd1d);
+        let mut t = 0.0;
+        timer.on_tick(move |_event| {
+            t += 0.02;
+            plot_panel.push((t, t.sin() + noise.next()));
+        });
+        timer.start(20, false);
+
+        // the timer lives until the window is closed
+        frame.on_close(move |event| {
+            timer.stop();
+            event.skip(true);
+        });
+
+        frame.show(true);
+    });
+}
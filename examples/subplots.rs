@@ -0,0 +1,69 @@
+//! Example of a 2x2 grid of charts on one panel.
+//!
+//! Each chart is drawn by its own backend, over the same device context, with
+//! its drawing shifted to the upper-left corner of its cell by
+//! `WxBackend::with_offset`. The charts are thus independent, and each one is
+//! laid out in its cell as if it were alone on a panel.
+
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+use wxdragon::{self as wx, DeviceContext, WindowEvents, WxWidget};
+
+/// Draw the chart of `y = x^power` on `backend`, over a cell of `size`
+fn draw_chart(
+    backend: WxBackend<'_, wx::AutoBufferedPaintDC>,
+    size: (u32, u32),
+    power: i32,
+) -> anyhow::Result<()> {
+    let root = backend.into_drawing_area().shrink((0, 0), size);
+    let mut chart = ChartBuilder::on(&root)
+        .caption(format!("y=x^{power}"), ("sans-serif", 20).into_font())
+        .margin(10)
+        .x_label_area_size(30)
+        .y_label_area_size(40)
+        .build_cartesian_2d(-1f64..1f64, -1f64..1f64)?;
+    chart.configure_mesh().draw()?;
+    chart.draw_series(LineSeries::new(
+        (-50..=50)
+            .map(|x| x as f64 / 50.0)
+            .map(|x| (x, x.powi(power))),
+        &Palette99::pick(power as usize),
+    ))?;
+    root.present()?;
+    Ok(())
+}
+
+fn main() {
+    let _ = wxdragon::main(|_| {
+        let frame = wx::Frame::builder()
+            .with_title("Plotters example subplots")
+            .with_size(wx::Size::new(800, 600))
+            .with_position(wx::Point::new(100, 100))
+            .build();
+
+        let panel = wx::PanelBuilder::new(&frame).build();
+        panel.set_background_style(wx::BackgroundStyle::Paint);
+        panel.on_paint(move |_event| {
+            let dc = wx::AutoBufferedPaintDC::new(&panel);
+            let (width, height) = dc.get_size();
+            let cell = (width as u32 / 2, height as u32 / 2);
+
+            // clear once, then draw each chart without clearing
+            WxBackend::new_no_clear(&dc).clear();
+            for (index, power) in (1..=4).enumerate() {
+                let (column, row) = (index as i32 % 2, index as i32 / 2);
+                let backend = WxBackend::new_no_clear(&dc)
+                    .with_offset(column * cell.0 as i32, row * cell.1 as i32);
+                draw_chart(backend, cell, power).expect("draw chart");
+            }
+        });
+        panel.on_size(move |_event| {
+            panel.refresh(true, None);
+        });
+
+        // Initial paint
+        panel.refresh(true, None);
+
+        frame.show(true);
+    });
+}
@@ -25,11 +25,9 @@ impl DrawingPanel {
 
         // Register the paint handler with a move closure
         panel.on_paint(move |_event| {
-            // Create a PaintDC when handling paint events
-            let dc = wx::AutoBufferedPaintDC::new(&panel);
-
-            // Create a backend for plotters
-            let backend = WxBackend::new(&dc).into_drawing_area();
+            // Create a backend for plotters, which owns the paint device
+            // context of this event
+            let backend = WxBackend::from_panel(&panel).into_drawing_area();
 
             // Create a plotters plot as you would with any other backend
             let mut chart = ChartBuilder::on(&backend)
@@ -0,0 +1,59 @@
+//! Example of a plot zoomed and panned with the mouse.
+//!
+//! This example draws a damped oscillation in an `InteractivePlotPanel`:
+//! scroll to zoom around the cursor, drag with the left button to pan, drag
+//! with the right button to zoom to a rectangle, and right click to show the
+//! whole curve again.
+
+use std::ops::Range;
+
+use plotters::prelude::*;
+use plotters_wxdragon::{InteractivePlotPanel, WxBackend};
+use wxdragon::{self as wx, WxWidget};
+
+fn draw(
+    backend: WxBackend<'_, wx::AutoBufferedPaintDC>,
+    x_range: Range<f64>,
+    y_range: Range<f64>,
+) -> anyhow::Result<(Range<i32>, Range<i32>)> {
+    let root = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Scroll, drag or right click", ("sans-serif", 30))
+        .margin(10)
+        .x_label_area_size(30)
+        .y_label_area_size(40)
+        .build_cartesian_2d(x_range.clone(), y_range)?;
+    chart.configure_mesh().draw()?;
+
+    // sample the visible part of the curve, so that it stays smooth when
+    // zoomed in
+    let samples = 1000;
+    let step = (x_range.end - x_range.start) / samples as f64;
+    chart.draw_series(LineSeries::new(
+        (0..=samples)
+            .map(|i| x_range.start + i as f64 * step)
+            .map(|x| (x, (-x / 5.0).exp() * (3.0 * x).sin())),
+        &RED,
+    ))?;
+
+    root.present()?;
+    Ok(chart.plotting_area().get_pixel_range())
+}
+
+fn main() {
+    let _ = wxdragon::main(|_| {
+        let frame = wx::Frame::builder()
+            .with_title("Plotters example zoom_pan")
+            .with_size(wx::Size::new(800, 600))
+            .with_position(wx::Point::new(100, 100))
+            .build();
+
+        let plot_panel =
+            InteractivePlotPanel::new(&frame, 0.0..10.0, -1.0..1.0, draw);
+
+        // Initial paint
+        plot_panel.refresh();
+
+        frame.show(true);
+    });
+}
@@ -0,0 +1,102 @@
+//! Builder of a [`WxBackend`] with custom settings.
+
+use wxdragon::{self as wx, BackgroundMode, DeviceContext};
+
+use crate::font_cache::FontCacheRef;
+use crate::{BackendConfig, ContextRef, WxBackend, device_dpi};
+
+/// Builder of a [`WxBackend`], created with [`WxBackend::builder`].
+///
+/// ```no_run
+/// # use plotters_wxdragon::WxBackend;
+/// # use wxdragon as wx;
+/// # fn draw(dc: &wx::MemoryDC) {
+/// let backend = WxBackend::builder(dc)
+///     .background_color(wx::Colour::rgb(30, 30, 30))
+///     .auto_clear(false)
+///     .build();
+/// # }
+/// ```
+pub struct WxBackendBuilder<'context, C>
+where
+    C: DeviceContext,
+{
+    context: &'context C,
+    config: BackendConfig,
+    auto_clear: bool,
+}
+
+impl<'context, C> WxBackendBuilder<'context, C>
+where
+    C: DeviceContext,
+{
+    /// Creates a builder with the settings of [`WxBackend::new`].
+    pub(crate) fn new(context: &'context C) -> Self {
+        let (_, dpi) = device_dpi(context);
+        WxBackendBuilder {
+            context,
+            config: BackendConfig {
+                dpi: dpi as f64,
+                ..BackendConfig::default()
+            },
+            auto_clear: true,
+        }
+    }
+
+    /// Set the resolution used to convert font sizes, see
+    /// [`WxBackend::new_with_dpi`]. A `dpi` which is not a positive number is
+    /// replaced by [`DEFAULT_DPI`](crate::DEFAULT_DPI).
+    pub fn dpi(mut self, dpi: f64) -> Self {
+        self.config.dpi = dpi;
+        self
+    }
+
+    /// Set the background color, white by default, see
+    /// [`WxBackend::set_background_color`].
+    pub fn background_color(mut self, color: wx::Colour) -> Self {
+        self.config.background_color = color;
+        self
+    }
+
+    /// Set the background mode, transparent by default, see
+    /// [`WxBackend::set_background_mode`].
+    pub fn background_mode(mut self, mode: BackgroundMode) -> Self {
+        self.config.background_mode = mode;
+        self
+    }
+
+    /// Clear the device context to the background color when the backend is
+    /// built, or keep what is already drawn on it with `false`.
+    ///
+    /// The default is `true`. Disable it to draw a plot over a background
+    /// drawn beforehand, e.g. a gradient or a themed window background.
+    pub fn auto_clear(mut self, clear: bool) -> Self {
+        self.auto_clear = clear;
+        self
+    }
+
+    /// Set the magnification of drawing, `(1.0, 1.0)` by default, e.g. the
+    /// content scale factor of a high-DPI display, see
+    /// [`WxBackend::set_scale`].
+    pub fn scale(mut self, scale_x: f64, scale_y: f64) -> Self {
+        self.config.scale = (scale_x, scale_y);
+        self
+    }
+
+    /// Replace all settings with those of `config`, see
+    /// [`WxBackend::new_with_config`].
+    pub fn config(mut self, config: BackendConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Creates the backend, and initializes the device context.
+    pub fn build(self) -> WxBackend<'context, C> {
+        WxBackend::from_config(
+            ContextRef::Borrowed(self.context),
+            &self.config,
+            self.auto_clear,
+            FontCacheRef::default(),
+        )
+    }
+}
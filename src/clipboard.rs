@@ -0,0 +1,38 @@
+//! Copy of plots to the clipboard.
+
+use wxdragon as wx;
+
+use crate::render::render_bitmap;
+use crate::{Error, ErrorInner, WxBackend};
+
+/// Draw a plot on an off-screen bitmap of size `width` x `height`, and copy
+/// it to the clipboard.
+///
+/// This implements the usual "copy chart" action, to paste a plot into a
+/// document. The clipboard is opened and closed by this function, and its
+/// previous contents are replaced. The plot is drawn on a
+/// `wxdragon::MemoryDC`, so this must be called from the wxWidgets main
+/// loop, e.g. from a menu or toolbar event handler.
+pub fn copy_plot_to_clipboard<F, E>(
+    width: u32,
+    height: u32,
+    draw_fn: F,
+) -> Result<(), Error>
+where
+    F: FnOnce(WxBackend<'_, wx::MemoryDC>) -> Result<(), E>,
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    let bitmap = render_bitmap(width, height, 1, true, draw_fn)?;
+    let clipboard = wx::Clipboard::get();
+    if !clipboard.open() {
+        return Err(ErrorInner::OpenClipboard.into());
+    }
+    // the clipboard takes ownership of the data
+    let mut data = wx::BitmapDataObject::new(&bitmap);
+    let copied = clipboard.set_data(&mut data);
+    clipboard.close();
+    if !copied {
+        return Err(ErrorInner::SetClipboard.into());
+    }
+    Ok(())
+}
@@ -0,0 +1,123 @@
+//! Drawing of a colorbar, the legend of a heatmap.
+
+use std::ops::Range;
+
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendTextStyle, DrawingBackend,
+    DrawingErrorKind,
+    text_anchor::{HPos, Pos, VPos},
+};
+use wxdragon::DeviceContext;
+
+use crate::legend::AnchoredTextStyle;
+use crate::{Error, LegendOrientation, WxBackend, pixel_aligned_ticks};
+
+/// Length of the tick marks, in pixels.
+const TICK_SIZE: i32 = 4;
+
+/// Approximate space between two ticks, in pixels.
+const TICK_SPACING: i32 = 50;
+
+impl<'context, C> WxBackend<'context, C>
+where
+    C: DeviceContext,
+{
+    /// Draw a colorbar, mapping the colors of a heatmap to values.
+    ///
+    /// The gradient strip fills the rectangle between `upper_left` and
+    /// `bottom_right`, bounds included, with the colors of `colormap` for the
+    /// values of `value_range`. A vertical colorbar goes from
+    /// `value_range.start` at the bottom to `value_range.end` at the top, and
+    /// has its tick labels on its right. A horizontal colorbar goes from left
+    /// to right, and has its tick labels below it. Ticks are placed about
+    /// every 50 pixels, and the outline, tick marks and labels are drawn with
+    /// the color of `label_style`.
+    ///
+    /// Leave room for the labels next to the strip, e.g. by drawing the
+    /// colorbar in the margin of the heatmap chart, see the `colorbar`
+    /// example.
+    pub fn draw_colorbar<F, T>(
+        &mut self,
+        (upper_left, bottom_right): (BackendCoord, BackendCoord),
+        colormap: F,
+        value_range: Range<f64>,
+        orientation: LegendOrientation,
+        label_style: &T,
+    ) -> Result<(), DrawingErrorKind<Error>>
+    where
+        F: Fn(f64) -> BackendColor,
+        T: BackendTextStyle,
+    {
+        let (x1, x2) = (
+            upper_left.0.min(bottom_right.0),
+            upper_left.0.max(bottom_right.0),
+        );
+        let (y1, y2) = (
+            upper_left.1.min(bottom_right.1),
+            upper_left.1.max(bottom_right.1),
+        );
+        // pixels of `value_range.start` and `value_range.end`
+        let pixel_range = match orientation {
+            LegendOrientation::Vertical => (y2, y1),
+            LegendOrientation::Horizontal => (x1, x2),
+        };
+
+        // gradient, one line of pixels per value
+        let length = (pixel_range.1 - pixel_range.0).abs();
+        let direction = (pixel_range.1 - pixel_range.0).signum();
+        let span = value_range.end - value_range.start;
+        for i in 0..=length {
+            let ratio = if length == 0 {
+                0.0
+            } else {
+                i as f64 / length as f64
+            };
+            let color = colormap(value_range.start + ratio * span);
+            let p = pixel_range.0 + i * direction;
+            match orientation {
+                LegendOrientation::Vertical => {
+                    self.draw_line((x1, p), (x2, p), &color)?
+                }
+                LegendOrientation::Horizontal => {
+                    self.draw_line((p, y1), (p, y2), &color)?
+                }
+            }
+        }
+        let color = label_style.color();
+        self.draw_rect((x1, y1), (x2, y2), &color, false)?;
+
+        // tick marks and labels
+        let max_ticks = (length / TICK_SPACING).max(2) as usize;
+        let ticks = pixel_aligned_ticks(value_range, pixel_range, max_ticks);
+        let precision = match ticks.as_slice() {
+            [first, second, ..] => {
+                (-(second.value - first.value).log10().floor()).max(0.0)
+                    as usize
+            }
+            _ => 0,
+        };
+        let anchor = match orientation {
+            LegendOrientation::Vertical => Pos::new(HPos::Left, VPos::Center),
+            LegendOrientation::Horizontal => Pos::new(HPos::Center, VPos::Top),
+        };
+        let label_style = AnchoredTextStyle {
+            style: label_style,
+            anchor,
+        };
+        for tick in ticks {
+            let p = tick.pixel;
+            let (from, to) = match orientation {
+                LegendOrientation::Vertical => ((x2, p), (x2 + TICK_SIZE, p)),
+                LegendOrientation::Horizontal => ((p, y2), (p, y2 + TICK_SIZE)),
+            };
+            self.draw_line(from, to, &color)?;
+            let label = format!("{:.precision$}", tick.value);
+            let pos = match orientation {
+                LegendOrientation::Vertical => (to.0 + TICK_SIZE / 2, p),
+                LegendOrientation::Horizontal => (p, to.1 + TICK_SIZE / 2),
+            };
+            self.draw_text(&label, &label_style, pos)?;
+        }
+        Ok(())
+    }
+}
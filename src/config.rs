@@ -0,0 +1,85 @@
+//! Settings of a [`WxBackend`] that can be carried over to another device
+//! context.
+//!
+//! [`WxBackend`]: crate::WxBackend
+
+use wxdragon::{self as wx, BackgroundMode};
+
+use crate::{DEFAULT_DPI, FillPattern};
+
+/// Settings of a [`WxBackend`](crate::WxBackend), independent of its device
+/// context.
+///
+/// Get the settings of a backend with
+/// [`WxBackend::config`](crate::WxBackend::config), and apply them to a new
+/// backend over another device context with
+/// [`WxBackend::new_with_config`](crate::WxBackend::new_with_config), e.g. to
+/// export to a bitmap exactly what is drawn on screen.
+#[derive(Debug, Clone)]
+pub struct BackendConfig {
+    /// Resolution used to convert font sizes, see
+    /// [`WxBackend::new_with_dpi`](crate::WxBackend::new_with_dpi). A value
+    /// which is not a positive number is replaced by [`DEFAULT_DPI`].
+    pub dpi: f64,
+    /// Background color of the device context.
+    pub background_color: wx::Colour,
+    /// Background mode of the device context.
+    pub background_mode: BackgroundMode,
+    /// Factor applied to the alpha of every color, see
+    /// [`WxBackend::set_global_alpha`](crate::WxBackend::set_global_alpha).
+    pub global_alpha: f64,
+    /// Palette every color is mapped to, see
+    /// [`WxBackend::set_color_quantization`](crate::WxBackend::set_color_quantization).
+    pub color_quantization: Option<Vec<(u8, u8, u8)>>,
+    /// Whether digits are drawn with the same width, see
+    /// [`WxBackend::set_tabular_figures`](crate::WxBackend::set_tabular_figures).
+    pub tabular_figures: bool,
+    /// Pen style of lines and paths, see
+    /// [`WxBackend::set_line_style`](crate::WxBackend::set_line_style).
+    pub line_style: wx::PenStyle,
+    /// Whether all text is bold, see
+    /// [`WxBackend::set_bold`](crate::WxBackend::set_bold).
+    pub bold: bool,
+    /// Pattern of filled polygons and rectangles, see
+    /// [`WxBackend::set_fill_pattern`](crate::WxBackend::set_fill_pattern).
+    pub fill_pattern: FillPattern,
+    /// Whether edges are rounded to device pixels, see
+    /// [`WxBackend::set_pixel_snap`](crate::WxBackend::set_pixel_snap).
+    pub pixel_snap: bool,
+    /// Magnification of drawing, see
+    /// [`WxBackend::set_scale`](crate::WxBackend::set_scale).
+    pub scale: (f64, f64),
+    /// Shift of drawing, see
+    /// [`WxBackend::set_offset`](crate::WxBackend::set_offset).
+    pub offset: (i32, i32),
+    /// Whether the vertical axis points upward, see
+    /// [`WxBackend::set_flip_y`](crate::WxBackend::set_flip_y).
+    pub flip_y: bool,
+    /// Size plots are laid out on instead of the device context, see
+    /// [`WxBackend::set_virtual_size`](crate::WxBackend::set_virtual_size).
+    pub virtual_size: Option<(u32, u32)>,
+}
+
+impl Default for BackendConfig {
+    /// The settings of a backend created with
+    /// [`WxBackend::new`](crate::WxBackend::new), with a resolution of
+    /// [`DEFAULT_DPI`].
+    fn default() -> Self {
+        BackendConfig {
+            dpi: DEFAULT_DPI,
+            background_color: wx::Colour::rgb(255, 255, 255),
+            background_mode: BackgroundMode::Transparent,
+            global_alpha: 1.0,
+            color_quantization: None,
+            tabular_figures: false,
+            line_style: wx::PenStyle::Solid,
+            bold: false,
+            fill_pattern: FillPattern::Solid,
+            pixel_snap: false,
+            scale: (1.0, 1.0),
+            offset: (0, 0),
+            flip_y: false,
+            virtual_size: None,
+        }
+    }
+}
@@ -0,0 +1,74 @@
+//! Conversion between data coordinates and device pixels.
+
+use std::ops::Range;
+
+use plotters_backend::BackendCoord;
+
+/// Maps data coordinates of a cartesian chart to device pixels, and back.
+///
+/// This is useful for tooltips or click-to-select, to find the data point
+/// under the mouse cursor. Build it with the data ranges given to
+/// `build_cartesian_2d`, and the pixel ranges of the plotting area, i.e.
+/// `chart.plotting_area().get_pixel_range()`. As in plotters, the start of
+/// the x range is on the left, and the start of the y range is at the bottom.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoordMapper {
+    pixel_x: Range<i32>,
+    pixel_y: Range<i32>,
+    x: Range<f64>,
+    y: Range<f64>,
+}
+
+impl CoordMapper {
+    /// Creates a mapper between the pixel ranges `pixel_range` and the data
+    /// ranges `x` and `y`.
+    pub fn new(
+        pixel_range: (Range<i32>, Range<i32>),
+        x: Range<f64>,
+        y: Range<f64>,
+    ) -> CoordMapper {
+        let (pixel_x, pixel_y) = pixel_range;
+        CoordMapper {
+            pixel_x,
+            pixel_y,
+            x,
+            y,
+        }
+    }
+
+    /// Convert a pixel position to data coordinates.
+    ///
+    /// Positions outside the plotting area map to data coordinates outside
+    /// the data ranges. The result is not finite if a pixel range is empty.
+    pub fn to_data(&self, (px, py): BackendCoord) -> (f64, f64) {
+        let tx = (px - self.pixel_x.start) as f64 / pixel_len(&self.pixel_x);
+        // plotters maps the start of the y range to the last pixel row
+        let ty = (self.pixel_y.end - 1 - py) as f64 / pixel_len(&self.pixel_y);
+        (
+            self.x.start + tx * (self.x.end - self.x.start),
+            self.y.start + ty * (self.y.end - self.y.start),
+        )
+    }
+
+    /// Convert data coordinates to a pixel position, rounded as plotters
+    /// does, so that the position is the one plotters draws the point at.
+    pub fn to_pixel(&self, (x, y): (f64, f64)) -> BackendCoord {
+        let tx = (x - self.x.start) / (self.x.end - self.x.start);
+        let ty = (y - self.y.start) / (self.y.end - self.y.start);
+        (
+            self.pixel_x.start + offset(tx, &self.pixel_x),
+            self.pixel_y.end - 1 - offset(ty, &self.pixel_y),
+        )
+    }
+}
+
+/// Length of a pixel range, as a float.
+fn pixel_len(range: &Range<i32>) -> f64 {
+    (range.end - range.start) as f64
+}
+
+/// Offset in pixels of a fraction `t` of a pixel range, rounded as plotters
+/// does for floating point coordinates.
+fn offset(t: f64, range: &Range<i32>) -> i32 {
+    (t * pixel_len(range) + 1e-3).floor() as i32
+}
@@ -0,0 +1,163 @@
+//! Export of plots to image files.
+
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+
+use wxdragon as wx;
+
+use crate::render::render_supersampled;
+use crate::{Error, ErrorInner, WxBackend};
+
+/// Draw a plot on an off-screen bitmap of size `width` x `height`, and return
+/// it as an image.
+///
+/// This gives an in-memory image of a plot, e.g. to post-process it or to
+/// compare it in a test, without handling the device context and the bitmap.
+/// The plot is drawn on a `wxdragon::MemoryDC`, so this must be called from
+/// the wxWidgets main loop. `draw_fn` receives a backend of size `width` x
+/// `height`.
+pub fn render_to_rgba_image<F, E>(
+    width: u32,
+    height: u32,
+    draw_fn: F,
+) -> Result<image::RgbaImage, Error>
+where
+    F: FnOnce(WxBackend<'_, wx::MemoryDC>) -> Result<(), E>,
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    let data = render_supersampled(width, height, 1, true, draw_fn)?;
+    let image = image::RgbaImage::from_raw(width, height, data)
+        .ok_or(ErrorInner::ReadBitmap)?;
+    Ok(image)
+}
+
+/// Draw a plot on an off-screen bitmap, and save it as a PNG image of size
+/// `width` x `height`.
+///
+/// This takes care of the off-screen setup, so that a plot drawn in a window
+/// can also be saved to disk by calling the same drawing function, e.g.
+/// `render_to_png("plot.png", 800, 600, |backend| draw_plot(backend))`. The
+/// plot is drawn on a `wxdragon::MemoryDC`, so this must be called from the
+/// wxWidgets main loop, e.g. from an event handler. `draw_fn` receives a
+/// backend of size `width` x `height`.
+pub fn render_to_png<P, F, E>(
+    path: P,
+    width: u32,
+    height: u32,
+    draw_fn: F,
+) -> Result<(), Error>
+where
+    P: AsRef<Path>,
+    F: FnOnce(WxBackend<'_, wx::MemoryDC>) -> Result<(), E>,
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    save_png_supersampled(path, width, height, 1, draw_fn)
+}
+
+/// Draw a plot on a transparent off-screen bitmap, and save it as a PNG image
+/// of size `width` x `height`.
+///
+/// Unlike [`render_to_png`], the backend does not clear the bitmap, so that
+/// the pixels left undrawn stay transparent, e.g. to overlay the image on a
+/// slide or a web page. The image has straight alpha, as PNG requires: a
+/// half-transparent red is saved as `[255, 0, 0, 128]`, not with its color
+/// premultiplied by its alpha. Whether the device context draws translucent
+/// colors with their alpha depends on the platform.
+pub fn render_to_transparent_png<P, F, E>(
+    path: P,
+    width: u32,
+    height: u32,
+    draw_fn: F,
+) -> Result<(), Error>
+where
+    P: AsRef<Path>,
+    F: FnOnce(WxBackend<'_, wx::MemoryDC>) -> Result<(), E>,
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    let data = render_supersampled(width, height, 1, false, draw_fn)?;
+    save_png(path, &data, width, height)
+}
+
+/// Draw a plot on an off-screen bitmap, supersampled by `factor`, and save
+/// it as a PNG image of size `width` x `height`.
+///
+/// The plot is drawn on a bitmap `factor` times larger in each direction,
+/// then each block of `factor` x `factor` pixels is averaged into one pixel
+/// of the image. This gives anti-aliased lines and text from the plain
+/// device context, which does not anti-alias on all platforms. A `factor` of
+/// 0 or 1 disables supersampling.
+///
+/// `draw_fn` receives a backend of size `width` x `height`: a user scale is
+/// set on the device context, so that plots look the same as when drawn on
+/// screen at that size, only smoother.
+pub fn save_png_supersampled<P, F, E>(
+    path: P,
+    width: u32,
+    height: u32,
+    factor: u32,
+    draw_fn: F,
+) -> Result<(), Error>
+where
+    P: AsRef<Path>,
+    F: FnOnce(WxBackend<'_, wx::MemoryDC>) -> Result<(), E>,
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    let data = render_supersampled(width, height, factor, true, draw_fn)?;
+    save_png(path, &data, width, height)
+}
+
+/// Draw a plot on an off-screen bitmap, and save it as a PNG image of size
+/// `width` x `height` with a textual description of the plot.
+///
+/// The description is embedded as an international text chunk with the
+/// standard keyword `Description`, so that tools reading the image, e.g. to
+/// provide an alternative text for screen readers, can describe the chart.
+/// `draw_fn` receives a backend of size `width` x `height`.
+pub fn save_png_with_metadata<P, F, E>(
+    path: P,
+    width: u32,
+    height: u32,
+    description: &str,
+    draw_fn: F,
+) -> Result<(), Error>
+where
+    P: AsRef<Path>,
+    F: FnOnce(WxBackend<'_, wx::MemoryDC>) -> Result<(), E>,
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    let data = render_supersampled(width, height, 1, true, draw_fn)?;
+    let encode = || -> Result<(), png::EncodingError> {
+        let file = BufWriter::new(File::create(path)?);
+        let mut encoder = png::Encoder::new(file, width, height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.add_itxt_chunk(
+            "Description".to_string(),
+            description.to_string(),
+        )?;
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&data)?;
+        writer.finish()
+    };
+    encode().map_err(ErrorInner::EncodePng)?;
+    Ok(())
+}
+
+/// Save RGBA pixels with straight alpha as a PNG image.
+fn save_png<P: AsRef<Path>>(
+    path: P,
+    data: &[u8],
+    width: u32,
+    height: u32,
+) -> Result<(), Error> {
+    image::save_buffer(
+        path,
+        data,
+        width,
+        height,
+        image::ExtendedColorType::Rgba8,
+    )
+    .map_err(ErrorInner::SaveImage)?;
+    Ok(())
+}
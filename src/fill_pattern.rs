@@ -0,0 +1,44 @@
+//! Hatch patterns of filled shapes.
+
+use wxdragon as wx;
+
+/// Pattern used to fill polygons and rectangles, see
+/// [`WxBackend::set_fill_pattern`](crate::WxBackend::set_fill_pattern).
+///
+/// Hatches are drawn with the fill color, and with the default transparent
+/// background mode, what is below the shape stays visible between the lines.
+/// They tell series apart in print, or for readers who cannot tell their
+/// colors apart.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum FillPattern {
+    /// A solid fill.
+    #[default]
+    Solid,
+    /// Diagonal lines from the bottom-left to the top-right.
+    ForwardDiagonal,
+    /// Diagonal lines from the top-left to the bottom-right.
+    BackwardDiagonal,
+    /// Crossed diagonal lines.
+    CrossDiagonal,
+    /// Horizontal lines.
+    Horizontal,
+    /// Vertical lines.
+    Vertical,
+    /// Crossed horizontal and vertical lines.
+    Cross,
+}
+
+impl FillPattern {
+    /// The wxWidgets brush style drawing this pattern.
+    pub(crate) fn brush_style(self) -> wx::BrushStyle {
+        match self {
+            FillPattern::Solid => wx::BrushStyle::Solid,
+            FillPattern::ForwardDiagonal => wx::BrushStyle::FDiagonalHatch,
+            FillPattern::BackwardDiagonal => wx::BrushStyle::BDiagonalHatch,
+            FillPattern::CrossDiagonal => wx::BrushStyle::CrossDiagHatch,
+            FillPattern::Horizontal => wx::BrushStyle::HorizontalHatch,
+            FillPattern::Vertical => wx::BrushStyle::VerticalHatch,
+            FillPattern::Cross => wx::BrushStyle::CrossHatch,
+        }
+    }
+}
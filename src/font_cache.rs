@@ -0,0 +1,149 @@
+//! Cache of the fonts created from plotters text styles.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::ops::Deref;
+
+use plotters_backend::{FontFamily, FontStyle};
+use wxdragon as wx;
+
+/// Parameters of a `wx::Font`, used as the key of a [`FontCache`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct FontKey {
+    pub family: FamilyKey,
+    pub point_size: i32,
+    pub bold: bool,
+    pub slant: Slant,
+    pub underlined: bool,
+}
+
+/// Font family of a [`FontKey`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum FamilyKey {
+    Monospace,
+    SansSerif,
+    Serif,
+    /// A face name.
+    Name(String),
+}
+
+/// Slant of a [`FontKey`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Slant {
+    Upright,
+    Italic,
+    Oblique,
+}
+
+impl FontKey {
+    /// Build the key of a plotters font.
+    ///
+    /// Plotters styles are either bold or slanted, `bold` makes the font bold
+    /// whatever the style, to allow both.
+    pub fn new(
+        family: FontFamily,
+        point_size: i32,
+        style: FontStyle,
+        bold: bool,
+        underlined: bool,
+    ) -> Self {
+        let family = match family {
+            FontFamily::Monospace => FamilyKey::Monospace,
+            FontFamily::SansSerif => FamilyKey::SansSerif,
+            FontFamily::Serif => FamilyKey::Serif,
+            FontFamily::Name(name) => FamilyKey::Name(name.to_string()),
+        };
+        let slant = match style {
+            FontStyle::Italic => Slant::Italic,
+            FontStyle::Oblique => Slant::Oblique,
+            FontStyle::Bold | FontStyle::Normal => Slant::Upright,
+        };
+        FontKey {
+            family,
+            point_size,
+            bold: bold || matches!(style, FontStyle::Bold),
+            slant,
+            underlined,
+        }
+    }
+
+    /// Create the wx font.
+    fn build(&self) -> Option<wx::Font> {
+        // An empty face name lets the family alone select the native font.
+        let (family, face_name) = match &self.family {
+            // According to wx docs
+            // https://docs.wxwidgets.org/3.2/interface_2wx_2font_8h.html
+            FamilyKey::Monospace => (wx::FontFamily::Teletype, ""),
+            FamilyKey::SansSerif => (wx::FontFamily::Swiss, ""),
+            FamilyKey::Serif => (wx::FontFamily::Roman, ""),
+            FamilyKey::Name(name) => (wx::FontFamily::Default, name.as_str()),
+        };
+        let weight = match self.bold {
+            true => wx::FontWeight::Bold,
+            false => wx::FontWeight::Normal,
+        };
+        let style = match self.slant {
+            Slant::Upright => wx::FontStyle::Normal,
+            Slant::Italic => wx::FontStyle::Italic,
+            Slant::Oblique => wx::FontStyle::Slant,
+        };
+        wx::Font::builder()
+            .with_point_size(self.point_size)
+            .with_family(family)
+            .with_style(style)
+            .with_weight(weight)
+            .with_underline(self.underlined)
+            .with_face_name(face_name)
+            .build()
+    }
+}
+
+/// Fonts created by a backend, so that each font is created only once.
+///
+/// A plot uses a handful of fonts, e.g. for the caption and the tick labels,
+/// but draws many texts, and each text sets its font twice: to estimate its
+/// size, and to draw it.
+#[derive(Default)]
+pub(crate) struct FontCache {
+    fonts: HashMap<FontKey, wx::Font>,
+}
+
+impl FontCache {
+    /// Get the font for `key`, creating it if needed.
+    ///
+    /// Returns `None` if wxWidgets fails to create the font.
+    pub fn get(&mut self, key: FontKey) -> Option<&wx::Font> {
+        match self.fonts.entry(key) {
+            Entry::Occupied(entry) => Some(entry.into_mut()),
+            Entry::Vacant(entry) => {
+                let font = entry.key().build()?;
+                Some(entry.insert(font))
+            }
+        }
+    }
+}
+
+/// The font cache of a backend, owned by the backend, or borrowed from a
+/// [`WxBackendState`](crate::WxBackendState) so that it outlives the backend.
+pub(crate) enum FontCacheRef<'a> {
+    Owned(RefCell<FontCache>),
+    Borrowed(&'a RefCell<FontCache>),
+}
+
+impl Default for FontCacheRef<'_> {
+    fn default() -> Self {
+        FontCacheRef::Owned(RefCell::default())
+    }
+}
+
+impl Deref for FontCacheRef<'_> {
+    type Target = RefCell<FontCache>;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            FontCacheRef::Owned(fonts) => fonts,
+            FontCacheRef::Borrowed(fonts) => fonts,
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! Hit-testing of series drawn as polylines, e.g. to highlight the series
+//! under the mouse cursor.
+
+use plotters_backend::BackendCoord;
+
+/// Find the series nearest to `cursor`, among series drawn as polylines.
+///
+/// Each series is given by its points in device pixels, e.g. obtained from a
+/// plotters chart with `chart.backend_coord(&(x, y))`. The distance from the
+/// cursor to a series is the distance to its nearest segment, or to its point
+/// for a single-point series. Returns the index of the nearest series, if its
+/// distance is at most `threshold` pixels. Empty series are never hit.
+pub fn nearest_series<S>(
+    series: &[S],
+    cursor: BackendCoord,
+    threshold: f64,
+) -> Option<usize>
+where
+    S: AsRef<[BackendCoord]>,
+{
+    series
+        .iter()
+        .enumerate()
+        .filter_map(|(index, points)| {
+            let distance = match points.as_ref() {
+                [] => return None,
+                [point] => distance_to_segment(cursor, (*point, *point)),
+                points => points
+                    .windows(2)
+                    .map(|s| distance_to_segment(cursor, (s[0], s[1])))
+                    .fold(f64::INFINITY, f64::min),
+            };
+            Some((index, distance))
+        })
+        .filter(|&(_, distance)| distance <= threshold)
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(index, _)| index)
+}
+
+/// Euclidean distance in pixels from `point` to the segment `(start, end)`.
+///
+/// A segment whose ends are equal is a point.
+pub fn distance_to_segment(
+    point: BackendCoord,
+    (start, end): (BackendCoord, BackendCoord),
+) -> f64 {
+    let (px, py) = (point.0 as f64, point.1 as f64);
+    let (x0, y0) = (start.0 as f64, start.1 as f64);
+    let (dx, dy) = (end.0 as f64 - x0, end.1 as f64 - y0);
+    let length_squared = dx * dx + dy * dy;
+    // parameter of the projection of the point on the segment, in [0, 1]
+    let t = if length_squared == 0.0 {
+        0.0
+    } else {
+        (((px - x0) * dx + (py - y0) * dy) / length_squared).clamp(0.0, 1.0)
+    };
+    (px - (x0 + t * dx)).hypot(py - (y0 + t * dy))
+}
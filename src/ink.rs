@@ -0,0 +1,43 @@
+//! Bounding box of the drawn pixels of a bitmap, e.g. to crop an export.
+
+use wxdragon as wx;
+
+/// Compute the bounding box of the pixels of `bitmap` that differ from the
+/// `background` color.
+///
+/// This is useful to trim the whitespace around a plot rendered on a
+/// `wxdragon::MemoryDC`, before exporting it. Deselect the bitmap from the
+/// device context first, so that it holds the drawing. Pixels are compared on
+/// their RGB channels, alpha is ignored. Returns `None` if all pixels are
+/// background, or if the pixels of the bitmap cannot be read.
+pub fn ink_bounds(
+    bitmap: &wx::Bitmap,
+    background: wx::Colour,
+) -> Option<wx::Rect> {
+    let width = bitmap.get_width().max(0) as usize;
+    let data = bitmap.get_rgba_data()?;
+    if width == 0 {
+        return None;
+    }
+    let background = [background.r, background.g, background.b];
+    let mut bounds: Option<(usize, usize, usize, usize)> = None;
+    for (index, pixel) in data.chunks_exact(4).enumerate() {
+        if pixel[..3] == background {
+            continue;
+        }
+        let (x, y) = (index % width, index / width);
+        bounds = Some(match bounds {
+            None => (x, y, x, y),
+            Some((x0, y0, x1, y1)) => {
+                (x0.min(x), y0.min(y), x1.max(x), y1.max(y))
+            }
+        });
+    }
+    let (x0, y0, x1, y1) = bounds?;
+    Some(wx::Rect::new(
+        x0 as i32,
+        y0 as i32,
+        (x1 - x0 + 1) as i32,
+        (y1 - y0 + 1) as i32,
+    ))
+}
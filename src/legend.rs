@@ -0,0 +1,412 @@
+//! Drawing of a legend outside of a chart.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use plotters_backend::{
+    BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend,
+    DrawingErrorKind,
+    text_anchor::{HPos, Pos, VPos},
+};
+use wxdragon::{self as wx, DeviceContext, WindowEvents, WxWidget};
+
+use crate::panel::mouse_position;
+use crate::{Error, WxBackend};
+
+/// Direction in which legend entries are laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LegendOrientation {
+    /// One entry per row.
+    Vertical,
+    /// All entries on a single row.
+    Horizontal,
+}
+
+/// Layout of a legend drawn with [`WxBackend::draw_legend`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LegendLayout {
+    /// Upper-left corner of the first entry.
+    pub origin: BackendCoord,
+    /// Direction in which entries are laid out.
+    pub orientation: LegendOrientation,
+    /// Width and height of the color swatch of each entry.
+    pub swatch_size: u32,
+    /// Space between the swatch and the label, and between entries.
+    pub spacing: u32,
+}
+
+impl Default for LegendLayout {
+    fn default() -> Self {
+        LegendLayout {
+            origin: (10, 10),
+            orientation: LegendOrientation::Vertical,
+            swatch_size: 12,
+            spacing: 6,
+        }
+    }
+}
+
+impl<'context, C> WxBackend<'context, C>
+where
+    C: DeviceContext,
+{
+    /// Draw legend entries, each made of a color swatch and a label.
+    ///
+    /// Unlike the legend of a plotters chart, this legend is not tied to a
+    /// chart, so it can be drawn on its own device context, e.g. in a side
+    /// panel next to the chart panel. Each entry is a label with the style
+    /// of its series: the swatch is filled with the series color. Labels are
+    /// drawn with `text_style`, vertically centered on their swatch.
+    pub fn draw_legend<S, T>(
+        &mut self,
+        entries: &[(&str, S)],
+        text_style: &T,
+        layout: LegendLayout,
+    ) -> Result<(), DrawingErrorKind<Error>>
+    where
+        S: BackendStyle,
+        T: BackendTextStyle,
+    {
+        let labels: Vec<&str> =
+            entries.iter().map(|(label, _)| *label).collect();
+        let regions = self.legend_regions(&labels, text_style, layout)?;
+        let text_style = AnchoredTextStyle {
+            style: text_style,
+            anchor: Pos::new(HPos::Left, VPos::Center),
+        };
+        let swatch = layout.swatch_size as i32;
+        let spacing = layout.spacing as i32;
+        for ((label, style), ((x, top), (_, bottom))) in
+            entries.iter().zip(regions)
+        {
+            let center_y = top + (bottom - top) / 2;
+            self.draw_rect(
+                (x, center_y - swatch / 2),
+                (x + swatch, center_y - swatch / 2 + swatch),
+                style,
+                true,
+            )?;
+            self.draw_text(
+                label,
+                &text_style,
+                (x + swatch + spacing, center_y),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Get the regions covered by the entries of a legend drawn with
+    /// [`WxBackend::draw_legend`], with the same labels, text style and
+    /// layout, as their upper-left and bottom-right corners.
+    ///
+    /// Each region covers the swatch and the label of an entry, e.g. to find
+    /// the entry under the mouse cursor, see [`LegendToggle`].
+    pub fn legend_regions<T>(
+        &self,
+        labels: &[&str],
+        text_style: &T,
+        layout: LegendLayout,
+    ) -> Result<Vec<(BackendCoord, BackendCoord)>, DrawingErrorKind<Error>>
+    where
+        T: BackendTextStyle,
+    {
+        let text_style = AnchoredTextStyle {
+            style: text_style,
+            anchor: Pos::new(HPos::Left, VPos::Center),
+        };
+        let swatch = layout.swatch_size as i32;
+        let spacing = layout.spacing as i32;
+        let (mut x, mut y) = layout.origin;
+        let mut regions = Vec::with_capacity(labels.len());
+        for label in labels {
+            let (text_width, text_height) =
+                self.estimate_text_size(label, &text_style)?;
+            let row_height = swatch.max(text_height as i32);
+            let width = swatch + spacing + text_width as i32;
+            regions.push(((x, y), (x + width, y + row_height)));
+            match layout.orientation {
+                LegendOrientation::Vertical => y += row_height + spacing,
+                LegendOrientation::Horizontal => x += width + 2 * spacing,
+            }
+        }
+        Ok(regions)
+    }
+}
+
+/// Visibility of the series of a legend, toggled by clicking their entries.
+///
+/// Plotters does not expose where the legend of a chart is laid out, so the
+/// legend is drawn with [`WxBackend::draw_legend`], and its regions are
+/// obtained with [`WxBackend::legend_regions`] and registered with
+/// [`LegendToggle::set_regions`], typically in the drawing function. The
+/// drawing function then skips the series that are not
+/// [visible](LegendToggle::is_visible).
+///
+/// [`LegendToggle::attach`] handles clicks on a panel, e.g. a
+/// [`PlotPanel`](crate::PlotPanel): a click on an entry toggles its series,
+/// calls the callback with the index of the series and its new visibility,
+/// and refreshes the panel. Clones of a `LegendToggle` share their state, so
+/// that the drawing function and the click handler each own one.
+///
+/// The entries can also be toggled with the keyboard, when the panel has
+/// the keyboard focus:
+/// * Tab moves the focus to the next entry, and after the last entry, out
+///   of the legend, to the next control,
+/// * Space or Enter toggles the series of the focused entry.
+///
+/// The drawing function shows the focused entry with
+/// [`LegendToggle::draw_focus`].
+#[derive(Debug, Clone)]
+pub struct LegendToggle {
+    state: Rc<RefCell<ToggleState>>,
+}
+
+/// State shared between the clones of a [`LegendToggle`].
+#[derive(Debug)]
+struct ToggleState {
+    visible: Vec<bool>,
+    regions: Vec<(BackendCoord, BackendCoord)>,
+    /// Index of the entry with the keyboard focus, if any.
+    focused: Option<usize>,
+}
+
+/// Effect of a key on a [`LegendToggle`], see [`LegendToggle::key_down`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LegendKey {
+    /// The focus moved to the entry at this index, or out of the legend.
+    Focus(Option<usize>),
+    /// The series of the focused entry was toggled, with its index and new
+    /// visibility.
+    Toggle(usize, bool),
+    /// The key is not handled by the legend.
+    Ignored,
+}
+
+/// wxWidgets key codes of the keys handled by [`LegendToggle::key_down`].
+const KEY_TAB: i32 = 9;
+const KEY_RETURN: i32 = 13;
+const KEY_SPACE: i32 = 32;
+const KEY_NUMPAD_ENTER: i32 = 370;
+
+/// Distance between a legend entry and its focus ring.
+const FOCUS_MARGIN: i32 = 2;
+
+impl LegendToggle {
+    /// Creates a toggle for `count` series, all visible.
+    pub fn new(count: usize) -> LegendToggle {
+        LegendToggle {
+            state: Rc::new(RefCell::new(ToggleState {
+                visible: vec![true; count],
+                regions: Vec::new(),
+                focused: None,
+            })),
+        }
+    }
+
+    /// Returns `true` if the series at `index` is visible. Series beyond the
+    /// count given to [`LegendToggle::new`] are always visible.
+    pub fn is_visible(&self, index: usize) -> bool {
+        self.state
+            .borrow()
+            .visible
+            .get(index)
+            .copied()
+            .unwrap_or(true)
+    }
+
+    /// Set the regions of the legend entries, in the order of the series,
+    /// as drawn in the last frame.
+    pub fn set_regions(&self, regions: Vec<(BackendCoord, BackendCoord)>) {
+        self.state.borrow_mut().regions = regions;
+    }
+
+    /// Toggle the series whose entry is under `position`, and return its
+    /// index and new visibility, or `None` if no entry is there.
+    pub fn click(&self, (x, y): BackendCoord) -> Option<(usize, bool)> {
+        let mut state = self.state.borrow_mut();
+        let index = state.regions.iter().position(|((x1, y1), (x2, y2))| {
+            (*x1..=*x2).contains(&x) && (*y1..=*y2).contains(&y)
+        })?;
+        let visible = state.visible.get_mut(index)?;
+        *visible = !*visible;
+        Some((index, *visible))
+    }
+
+    /// Get the index of the entry with the keyboard focus, if any.
+    pub fn focused(&self) -> Option<usize> {
+        self.state.borrow().focused
+    }
+
+    /// Handle a key pressed on the panel of the legend, given by its
+    /// wxWidgets key code, e.g. from `get_key_code` in a key down handler.
+    ///
+    /// Tab (9) moves the focus to the next entry with a region, see
+    /// [`LegendToggle::set_regions`], and out of the legend after the last
+    /// one. Space (32) or Enter (13, or 370 on the numeric keypad) toggles
+    /// the series of the focused entry. Other keys, and Space or Enter
+    /// without a focused entry, are ignored.
+    pub fn key_down(&self, key_code: i32) -> LegendKey {
+        let mut state = self.state.borrow_mut();
+        let count = state.regions.len().min(state.visible.len());
+        match key_code {
+            KEY_TAB if count > 0 => {
+                state.focused = match state.focused {
+                    None => Some(0),
+                    Some(index) if index + 1 < count => Some(index + 1),
+                    Some(_) => None,
+                };
+                LegendKey::Focus(state.focused)
+            }
+            KEY_SPACE | KEY_RETURN | KEY_NUMPAD_ENTER => {
+                let Some(index) = state.focused.filter(|i| *i < count) else {
+                    return LegendKey::Ignored;
+                };
+                let visible = &mut state.visible[index];
+                *visible = !*visible;
+                LegendKey::Toggle(index, *visible)
+            }
+            _ => LegendKey::Ignored,
+        }
+    }
+
+    /// Draw a dotted focus ring around the focused entry, if any, with the
+    /// color of `style`.
+    ///
+    /// The ring is drawn two pixels outside of the region of the entry, so
+    /// call this after [`WxBackend::draw_legend`], with the regions of the
+    /// same frame.
+    pub fn draw_focus<C, S>(
+        &self,
+        backend: &mut WxBackend<'_, C>,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Error>>
+    where
+        C: DeviceContext,
+        S: BackendStyle,
+    {
+        let state = self.state.borrow();
+        let Some(((x1, y1), (x2, y2))) = state
+            .focused
+            .and_then(|index| state.regions.get(index).copied())
+        else {
+            return Ok(());
+        };
+        let (x1, y1) = (x1 - FOCUS_MARGIN, y1 - FOCUS_MARGIN);
+        let (x2, y2) = (x2 + FOCUS_MARGIN, y2 + FOCUS_MARGIN);
+        let line_style = backend.line_style();
+        backend.set_line_style(wx::PenStyle::Dot);
+        let result = backend.draw_path(
+            [(x1, y1), (x2, y1), (x2, y2), (x1, y2), (x1, y1)],
+            style,
+        );
+        backend.set_line_style(line_style);
+        result
+    }
+
+    /// Toggle series when their entries are clicked on `panel`, or with the
+    /// keyboard, calling `callback` with the index and new visibility of the
+    /// toggled series, then refreshing the panel.
+    ///
+    /// The panel is also refreshed when the focus moves between entries,
+    /// see [`LegendToggle::key_down`]. On some platforms, Tab only reaches
+    /// a panel created with the `wxWANTS_CHARS` style, and otherwise moves
+    /// the focus between controls.
+    pub fn attach<F>(&self, panel: &wx::Panel, callback: F)
+    where
+        F: Fn(usize, bool) + 'static,
+    {
+        let callback = Rc::new(callback);
+        let toggle = self.clone();
+        let click_callback = callback.clone();
+        let panel = *panel;
+        panel.on_mouse_left_down(move |event| {
+            let toggled = mouse_position(&event)
+                .and_then(|position| toggle.click(position));
+            match toggled {
+                Some((index, visible)) => {
+                    click_callback(index, visible);
+                    panel.refresh(true, None);
+                }
+                None => event.skip(true),
+            }
+        });
+
+        let toggle = self.clone();
+        panel.on_key_down(move |event| {
+            let key_code = match &event {
+                wx::WindowEventData::Keyboard(event) => event.get_key_code(),
+                _ => None,
+            };
+            let key = key_code
+                .map_or(LegendKey::Ignored, |code| toggle.key_down(code));
+            match key {
+                LegendKey::Focus(focused) => {
+                    panel.refresh(true, None);
+                    // let Tab move the focus to the next control
+                    if focused.is_none() {
+                        event.skip(true);
+                    }
+                }
+                LegendKey::Toggle(index, visible) => {
+                    callback(index, visible);
+                    panel.refresh(true, None);
+                }
+                LegendKey::Ignored => event.skip(true),
+            }
+        });
+    }
+}
+
+/// A text style with an overridden anchor.
+pub(crate) struct AnchoredTextStyle<'a, T> {
+    pub style: &'a T,
+    pub anchor: Pos,
+}
+
+impl<T: BackendTextStyle> BackendTextStyle for AnchoredTextStyle<'_, T> {
+    type FontError = T::FontError;
+
+    fn color(&self) -> plotters_backend::BackendColor {
+        self.style.color()
+    }
+
+    fn size(&self) -> f64 {
+        self.style.size()
+    }
+
+    fn transform(&self) -> plotters_backend::FontTransform {
+        self.style.transform()
+    }
+
+    fn style(&self) -> plotters_backend::FontStyle {
+        self.style.style()
+    }
+
+    fn anchor(&self) -> Pos {
+        self.anchor
+    }
+
+    fn family(&self) -> plotters_backend::FontFamily<'_> {
+        self.style.family()
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn layout_box(
+        &self,
+        text: &str,
+    ) -> Result<((i32, i32), (i32, i32)), Self::FontError> {
+        self.style.layout_box(text)
+    }
+
+    fn draw<
+        E,
+        DR: FnMut(i32, i32, plotters_backend::BackendColor) -> Result<(), E>,
+    >(
+        &self,
+        text: &str,
+        pos: BackendCoord,
+        draw: DR,
+    ) -> Result<Result<(), E>, Self::FontError> {
+        self.style.draw(text, pos, draw)
+    }
+}
@@ -122,6 +122,9 @@
 //!    + `text`: a single window that shows various text orientations and a
 //!      toolbar that can modify application state
 //!
+//!    For a panel that only shows a plot, [`PlotPanel`] does the above in a
+//!    single call, see the `plot_panel` example.
+//!
 //!    See also the existing tests, which illustrate that most existing
 //!    plotters examples work without change. In these tests we write to to an
 //!    in-memory device context instead of a device context linked to a
@@ -138,17 +141,89 @@
 //!
 //! [`plotters-backend`]: https://docs.rs/plotters-backend/latest/plotters_backend/
 //!
+//! Since `WxBackend` is generic over the device context, the same plot can be
+//! drawn on a vector device context, e.g. to export it for a publication.
+//! Lines, shapes and opaque text are then drawn as vector paths and text, but a
+//! few primitives are rasterized:
+//!
+//! * pixels drawn with [`DrawingBackend::draw_pixel`], which are batched in a
+//!   bitmap;
+//! * text drawn with a translucent color, or with
+//!   [`WxBackend::set_text_coverage_blend`], which is blended through a bitmap;
+//! * bitmaps drawn with [`DrawingBackend::blit_bitmap`].
+//!
+//! ## Features
+//!
+//! * `export`: export plots to PNG files, e.g. with `render_to_png` or
+//!   `save_png_supersampled`. This adds a dependency on the `image` and `png`
+//!   crates.
+//!
 //! ## License
 //!
 //! This project is dual-licensed under [Apache 2.0](./LICENSE-APACHE) and
 //! [`MIT`](./LICENSE-MIT) terms.
 
+mod builder;
+mod clipboard;
+mod colorbar;
+mod config;
+mod coord_mapper;
+#[cfg(feature = "export")]
+mod export;
+mod fill_pattern;
+mod font_cache;
+mod hit_test;
+mod ink;
+mod legend;
+mod minimap;
+mod overlay;
+pub mod palette;
+mod panel;
+mod pixel_buffer;
+mod render;
+mod scatter;
+mod sparkline;
+mod state;
+mod ticks;
+mod tooltip;
+mod viewport;
+
+use std::cell::{Cell, RefCell};
+use std::ops::Deref;
+
 use plotters_backend::{
-    BackendColor, DrawingBackend, FontFamily, FontStyle, FontTransform,
+    BackendColor, DrawingBackend, DrawingErrorKind, FontTransform,
     text_anchor::{HPos, Pos, VPos},
 };
 use wxdragon::{self as wx, BackgroundMode, DeviceContext};
 
+pub use builder::WxBackendBuilder;
+pub use clipboard::copy_plot_to_clipboard;
+pub use config::BackendConfig;
+pub use coord_mapper::CoordMapper;
+#[cfg(feature = "export")]
+pub use export::{
+    render_to_png, render_to_rgba_image, render_to_transparent_png,
+    save_png_supersampled, save_png_with_metadata,
+};
+pub use fill_pattern::FillPattern;
+use font_cache::{FontCacheRef, FontKey};
+pub use hit_test::{distance_to_segment, nearest_series};
+pub use ink::ink_bounds;
+pub use legend::{LegendKey, LegendLayout, LegendOrientation, LegendToggle};
+pub use overlay::Overlay;
+pub use panel::{
+    AnimatedPlotPanel, InteractivePlotPanel, PlotPanel, StreamingPlotPanel,
+};
+use pixel_buffer::PixelBuffer;
+pub use render::render_to_rgba_buffer;
+pub use scatter::MarkerShape;
+pub use sparkline::sparklines_to_image_list;
+pub use state::WxBackendState;
+pub use ticks::{Tick, pixel_aligned_ticks};
+pub use tooltip::HoverTooltip;
+pub use viewport::Viewport;
+
 /// Bridge struct to allow plotters to plot on a [`wxdragon::DeviceContext`].
 ///
 /// This backend works with any [`wxdragon::DeviceContext`] that implements the
@@ -231,11 +306,146 @@ use wxdragon::{self as wx, BackgroundMode, DeviceContext};
 ///     frame.show(true);
 /// });
 /// ```
+///
+/// # Pixels
+///
+/// Calls to [`DrawingBackend::draw_pixel`] are accumulated in an off-screen
+/// RGBA buffer, which is drawn on the device context as a single bitmap in
+/// [`DrawingBackend::present`], before any other drawing primitive, or when
+/// the backend is dropped. This makes pixel-heavy plots (e.g. a Mandelbrot
+/// set) much faster than issuing one pen change and one point per pixel.
 pub struct WxBackend<'context, C>
 where
     C: DeviceContext,
 {
-    context: &'context C,
+    context: ContextRef<'context, C>,
+    pixels: RefCell<PixelBuffer>,
+    global_alpha: Cell<f64>,
+    dpi: f64,
+    stipple: RefCell<Option<wx::Bitmap>>,
+    palette: RefCell<Option<Vec<(u8, u8, u8)>>>,
+    tabular_figures: Cell<bool>,
+    background_mode: Cell<BackgroundMode>,
+    valid: Cell<bool>,
+    line_style: Cell<wx::PenStyle>,
+    bold: Cell<bool>,
+    underline: Cell<bool>,
+    text_coverage_blend: Cell<bool>,
+    fonts: FontCacheRef<'context>,
+    font: RefCell<Option<FontKey>>,
+    pen: Cell<Option<PenKey>>,
+    brush: Cell<Option<BrushKey>>,
+    clip: Cell<Option<ClipRect>>,
+    outline: Cell<Option<([u8; 4], i32)>>,
+    outline_style: Cell<wx::PenStyle>,
+    fill_pattern: Cell<FillPattern>,
+    scale: Cell<(f64, f64)>,
+    pixel_snap: Cell<bool>,
+    offset: Cell<plotters_backend::BackendCoord>,
+    flip_y: Cell<bool>,
+    virtual_size: Cell<Option<(u32, u32)>>,
+    present_hook: RefCell<Option<PresentHook<'context, C>>>,
+    clear_each_frame: Cell<bool>,
+    /// Whether the current frame was prepared, i.e. nothing was presented
+    /// since the device context was last cleared.
+    prepared: Cell<bool>,
+}
+
+/// The device context of a backend, borrowed, or owned by the backend when
+/// created with [`WxBackend::from_panel`].
+pub(crate) enum ContextRef<'context, C> {
+    Owned(C),
+    Borrowed(&'context C),
+}
+
+impl<C> Deref for ContextRef<'_, C> {
+    type Target = C;
+
+    fn deref(&self) -> &C {
+        match self {
+            ContextRef::Owned(context) => context,
+            ContextRef::Borrowed(context) => context,
+        }
+    }
+}
+
+/// Function called by [`DrawingBackend::present`] with the device context.
+type PresentHook<'context, C> = Box<dyn FnMut(&C) + 'context>;
+
+/// Settings of the pen last set on the device context.
+type PenKey = ([u8; 4], i32, wx::PenStyle);
+
+/// Settings of the brush last set on the device context.
+type BrushKey = ([u8; 4], wx::BrushStyle);
+
+/// Upper-left and bottom-right corners of a clipping rectangle.
+type ClipRect = (
+    plotters_backend::BackendCoord,
+    plotters_backend::BackendCoord,
+);
+
+/// Resolution of an off-screen `wxdragon::MemoryDC`, in dots per inch.
+///
+/// [`WxBackend::new`] falls back to this resolution when the device context
+/// does not report one.
+pub const DEFAULT_DPI: f64 = 96.0;
+
+/// Ratio between the em size of a font and the size of a plotters font.
+///
+/// Plotters sizes fonts by their line height in pixels, which is about 1.25
+/// times the em size that wxWidgets point sizes refer to. This matches the
+/// text size of the plotters `BitMapBackend`.
+const PLOTTERS_FONT_SCALE: f64 = 0.8;
+
+/// Largest absolute coordinate passed to wxWidgets.
+///
+/// Data out of the range of the axes can map to coordinates near the limits
+/// of `i32`, where computing a size or an offset overflows. Coordinates are
+/// clamped to this limit first, far beyond the size of any device context,
+/// so that the visible part of a shape is unchanged.
+const COORD_LIMIT: i32 = 1 << 28;
+
+/// Clamp a coordinate to [`COORD_LIMIT`].
+fn clamp_coord((x, y): plotters_backend::BackendCoord) -> (i32, i32) {
+    (
+        x.clamp(-COORD_LIMIT, COORD_LIMIT),
+        y.clamp(-COORD_LIMIT, COORD_LIMIT),
+    )
+}
+
+/// Get the upper-left corner, the width and the height of the rectangle
+/// between two corners given in any order, bounds included.
+fn rect_bounds(
+    corner1: plotters_backend::BackendCoord,
+    corner2: plotters_backend::BackendCoord,
+) -> (i32, i32, i32, i32) {
+    let ((x1, y1), (x2, y2)) = (clamp_coord(corner1), clamp_coord(corner2));
+    let (left, top) = (x1.min(x2), y1.min(y2));
+    (left, top, x1.max(x2) - left + 1, y1.max(y2) - top + 1)
+}
+
+/// Get the horizontal and vertical resolution of a device context, in dots
+/// per inch, or [`DEFAULT_DPI`] for an axis without resolution.
+pub(crate) fn device_dpi<C: DeviceContext>(context: &C) -> (u32, u32) {
+    let (ppi_x, ppi_y) = context.get_ppi();
+    let dpi = |ppi: i32| {
+        if ppi > 0 {
+            ppi as u32
+        } else {
+            DEFAULT_DPI as u32
+        }
+    };
+    (dpi(ppi_x), dpi(ppi_y))
+}
+
+/// Get a resolution usable to convert font sizes, or [`DEFAULT_DPI`] if `dpi`
+/// is not a positive number.
+fn valid_dpi(dpi: f64) -> f64 {
+    if dpi.is_finite() && dpi > 0.0 {
+        dpi
+    } else {
+        DEFAULT_DPI
+    }
 }
 
 impl<'context, C> WxBackend<'context, C>
@@ -245,18 +455,187 @@ where
     /// Creates a new `WxBackend` from a `wxdragon::DeviceContext`.
     ///
     /// The `DeviceContext` is initialized with a white background color and
-    /// transparent background mode.
+    /// transparent background mode. Text is scaled for the resolution
+    /// reported by the device context, or [`DEFAULT_DPI`] if it reports none,
+    /// see [`WxBackend::new_with_dpi`]. Use [`WxBackend::builder`] for other
+    /// settings.
+    ///
+    /// On a printer or PostScript device context, which reports the resolution
+    /// of the printed page, text thus keeps the same physical size as on
+    /// screen, while coordinates are in printer dots: scale the plot to the
+    /// page size returned by [`DrawingBackend::get_size`].
     pub fn new(context: &'context C) -> WxBackend<'context, C> {
-        let backend = WxBackend { context };
-        backend.set_background_color(wx::Colour::rgb(255, 255, 255));
-        backend.set_background_mode(wx::BackgroundMode::Transparent);
-        backend.clear();
+        Self::builder(context).build()
+    }
+
+    /// Creates a new `WxBackend` from a `wxdragon::DeviceContext`, without
+    /// clearing it.
+    ///
+    /// This keeps what is already drawn on the device context, e.g. a
+    /// gradient or a themed background drawn before the plot. The settings
+    /// are those of [`WxBackend::new`], see [`WxBackend::builder`] to change
+    /// them.
+    pub fn new_no_clear(context: &'context C) -> WxBackend<'context, C> {
+        Self::builder(context).auto_clear(false).build()
+    }
+
+    /// Creates a new `WxBackend` from a `wxdragon::DeviceContext`, with an
+    /// explicit resolution in dots per inch.
+    ///
+    /// Plotters sizes text in pixels, while wxWidgets sizes fonts in points,
+    /// so the resolution is needed to convert from one to the other. Forcing
+    /// it makes the rendered output independent of the machine, which is
+    /// useful for reproducible off-screen rendering. A `dpi` which is not a
+    /// positive number, e.g. zero or NaN, is replaced by [`DEFAULT_DPI`].
+    ///
+    /// The `DeviceContext` is initialized as in [`WxBackend::new`].
+    pub fn new_with_dpi(
+        context: &'context C,
+        dpi: f64,
+    ) -> WxBackend<'context, C> {
+        let config = BackendConfig {
+            dpi,
+            ..BackendConfig::default()
+        };
+        Self::new_with_config(context, &config)
+    }
+
+    /// Creates a new `WxBackend` from a `wxdragon::DeviceContext`, with the
+    /// settings of `config`.
+    ///
+    /// Together with [`WxBackend::config`], this replicates the settings of a
+    /// backend on another device context, e.g. to export to a `MemoryDC`
+    /// exactly what is drawn in a window. The `DeviceContext` is cleared to
+    /// the background color of `config`.
+    pub fn new_with_config(
+        context: &'context C,
+        config: &BackendConfig,
+    ) -> WxBackend<'context, C> {
+        Self::from_config(context, config, true, FontCacheRef::default())
+    }
+
+    /// Creates a builder of a `WxBackend` over a `wxdragon::DeviceContext`,
+    /// to choose its settings before the device context is initialized.
+    ///
+    /// This is useful e.g. for a plot in a dark themed window, with a dark
+    /// background color, or to keep what is already drawn on the device
+    /// context, see [`WxBackendBuilder::auto_clear`]. The defaults are those
+    /// of [`WxBackend::new`].
+    pub fn builder(context: &'context C) -> WxBackendBuilder<'context, C> {
+        WxBackendBuilder::new(context)
+    }
+
+    /// Creates a new `WxBackend` with the settings of `config` and the font
+    /// cache `fonts`, and clears the device context if `clear` is `true`.
+    fn from_config(
+        context: ContextRef<'context, C>,
+        config: &BackendConfig,
+        clear: bool,
+        fonts: FontCacheRef<'context>,
+    ) -> WxBackend<'context, C> {
+        let backend = WxBackend {
+            context,
+            pixels: RefCell::new(PixelBuffer::default()),
+            global_alpha: Cell::new(1.0),
+            dpi: valid_dpi(config.dpi),
+            stipple: RefCell::new(None),
+            palette: RefCell::new(None),
+            tabular_figures: Cell::new(config.tabular_figures),
+            background_mode: Cell::new(config.background_mode),
+            valid: Cell::new(true),
+            line_style: Cell::new(config.line_style),
+            bold: Cell::new(config.bold),
+            underline: Cell::new(false),
+            text_coverage_blend: Cell::new(false),
+            fonts,
+            font: RefCell::new(None),
+            pen: Cell::new(None),
+            brush: Cell::new(None),
+            clip: Cell::new(None),
+            outline: Cell::new(None),
+            outline_style: Cell::new(wx::PenStyle::Solid),
+            fill_pattern: Cell::new(config.fill_pattern),
+            scale: Cell::new((1.0, 1.0)),
+            pixel_snap: Cell::new(config.pixel_snap),
+            offset: Cell::new((0, 0)),
+            flip_y: Cell::new(false),
+            virtual_size: Cell::new(config.virtual_size),
+            present_hook: RefCell::new(None),
+            clear_each_frame: Cell::new(false),
+            prepared: Cell::new(true),
+        };
+        backend.set_background_color(config.background_color);
+        backend.set_background_mode(config.background_mode);
+        backend.set_global_alpha(config.global_alpha);
+        backend.set_color_quantization(config.color_quantization.as_deref());
+        // nothing is drawn yet, so there are no pixels to flush
+        let _ = backend.set_scale(config.scale.0, config.scale.1);
+        let _ = backend.set_offset(config.offset);
+        if config.flip_y {
+            let _ = backend.set_flip_y(true);
+        }
+        if clear {
+            backend.clear();
+        }
         backend
     }
 
+    /// Get the settings of this backend, to apply them to another backend
+    /// with [`WxBackend::new_with_config`].
+    ///
+    /// A pending stipple brush, see [`WxBackend::set_stipple_brush`], only
+    /// applies to the next rectangle and is not part of the settings.
+    pub fn config(&self) -> BackendConfig {
+        BackendConfig {
+            dpi: self.dpi,
+            background_color: self.context.get_background(),
+            background_mode: self.background_mode.get(),
+            global_alpha: self.global_alpha.get(),
+            color_quantization: self.palette.borrow().clone(),
+            tabular_figures: self.tabular_figures.get(),
+            line_style: self.line_style.get(),
+            bold: self.bold.get(),
+            fill_pattern: self.fill_pattern.get(),
+            pixel_snap: self.pixel_snap.get(),
+            scale: self.scale.get(),
+            offset: self.offset.get(),
+            flip_y: self.flip_y.get(),
+            virtual_size: self.virtual_size.get(),
+        }
+    }
+
     /// Clear the device context.
+    ///
+    /// Pixels drawn with [`DrawingBackend::draw_pixel`] that were not flushed
+    /// yet are discarded.
     pub fn clear(&self) {
-        self.context.clear();
+        self.pixels.borrow_mut().discard();
+        if self.valid.get() {
+            self.context.clear();
+        }
+    }
+
+    /// Clear a rectangle of the device context to the background color,
+    /// bounds included.
+    ///
+    /// This is useful to erase an overlay such as a crosshair or a tooltip,
+    /// without clearing and redrawing the whole plot.
+    pub fn clear_rect(
+        &self,
+        upper_left: plotters_backend::BackendCoord,
+        bottom_right: plotters_backend::BackendCoord,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        if !self.begin_draw()? {
+            return Ok(());
+        }
+        let background = self.context.get_background();
+        let rgba = [background.r, background.g, background.b, background.a];
+        self.apply_pen((rgba, 0, wx::PenStyle::Transparent));
+        self.apply_brush((rgba, wx::BrushStyle::Solid));
+        let (x, y, width, height) =
+            self.context_rect(rect_bounds(upper_left, bottom_right));
+        self.context.draw_rectangle(x, y, width, height);
+        Ok(())
     }
 
     /// Set the background color of the device context.
@@ -274,268 +653,1818 @@ where
     /// background, otherwise leave the default
     /// [`BackgroundMode::Transparent`].
     pub fn set_background_mode(&self, mode: BackgroundMode) {
+        self.background_mode.set(mode);
         self.context.set_background_mode(mode);
     }
 
-    /// Set pen from plotters style.
-    fn set_pen_style<S: plotters_backend::BackendStyle>(&self, style: &S) {
-        let color = convert_color(style.color());
-        let width = style.stroke_width() as i32;
-        // FIXME: how to get info of other styles?
-        let style = wx::PenStyle::Solid;
-        self.context.set_pen(color, width, style);
+    /// Commit all pending drawing to the device context.
+    ///
+    /// Pixels drawn with [`DrawingBackend::draw_pixel`] are buffered until
+    /// [`DrawingBackend::present`] or the next drawing primitive. Call this
+    /// method when you need them on the device context earlier, e.g. to copy
+    /// its content with `DeviceContext::blit`, or before drawing on the
+    /// device context directly.
+    ///
+    /// The backend skips setting the pen and brush of the device context when
+    /// they are unchanged since the previous primitive. This method forgets
+    /// them, so that drawing on the device context directly between two calls
+    /// to the backend does not leak into the style of the backend.
+    ///
+    /// Returns an error if any drawing operation failed since the backend was
+    /// created, see [`WxBackend::is_valid`].
+    ///
+    /// Unlike `present`, this does not mark the end of a frame. Note that for
+    /// a `wxdragon::MemoryDC`, the selected bitmap is only guaranteed to hold
+    /// the drawing once it is deselected with `select_object`.
+    pub fn flush(&self) -> Result<(), DrawingErrorKind<Error>> {
+        self.flush_pixels().map_err(drawing_error)?;
+        self.pen.set(None);
+        self.brush.set(None);
+        if !self.valid.get() {
+            return Err(drawing_error(ErrorInner::Invalid));
+        }
+        Ok(())
     }
 
-    /// Set brush from plotters style.
-    fn set_brush_style(
-        &self,
-        fill: bool,
-        color: plotters_backend::BackendColor,
-    ) {
-        let style = match fill {
-            true => wx::BrushStyle::Solid,
-            false => wx::BrushStyle::Transparent,
-        };
-        let color = convert_color(color);
-        self.context.set_brush(color, style);
+    /// Clear the device context at the start of every frame, when the
+    /// backend is reused for several frames.
+    ///
+    /// Plotters calls [`DrawingBackend::ensure_prepared`] before every drawing
+    /// operation. With this setting, the first call after
+    /// [`DrawingBackend::present`] clears the device context to the
+    /// background color, so that each frame is drawn on a clean background,
+    /// as the first frame after [`WxBackend::new`]. Disabled by default.
+    pub fn set_clear_each_frame(&self, enabled: bool) {
+        self.clear_each_frame.set(enabled);
     }
 
-    /// Sets the font style from plotters BackendTextStyle.
+    /// Set a function called with the device context at the end of every
+    /// [`DrawingBackend::present`], after pending drawing is flushed.
     ///
-    /// Note: text background information is not present in
-    /// plotters_backend::BackendTextStyle, but it can be controlled using
-    /// [`WxBackend::set_background_mode`] and
-    /// [`WxBackend::set_background_color`]
-    fn set_font_style<TStyle: plotters_backend::BackendTextStyle>(
+    /// `present` draws what the backend still buffers, but does not finalize
+    /// the device context itself, which depends on its type: a
+    /// `wxdragon::AutoBufferedPaintDC` copies its buffer to the window when
+    /// it is dropped, and the bitmap of a `wxdragon::MemoryDC` is complete
+    /// once it is deselected. The hook lets a custom device context, or the
+    /// code owning it, finalize the frame when plotters presents it, e.g. to
+    /// copy a `MemoryDC` to the screen.
+    pub fn set_present_hook<F>(&self, hook: F)
+    where
+        F: FnMut(&C) + 'context,
+    {
+        *self.present_hook.borrow_mut() = Some(Box::new(hook));
+    }
+
+    /// Get the horizontal and vertical resolution of the device context, in
+    /// dots per inch.
+    ///
+    /// This is the resolution reported by the device context, e.g. 96 for a
+    /// `wxdragon::MemoryDC`, or [`DEFAULT_DPI`] if it reports none. Font sizes
+    /// are converted with the vertical resolution, unless another one is set
+    /// with [`WxBackend::new_with_dpi`].
+    pub fn dpi(&self) -> (u32, u32) {
+        device_dpi(&*self.context)
+    }
+
+    /// Magnify all subsequent drawing by `scale_x` horizontally and `scale_y`
+    /// vertically, `(1.0, 1.0)` by default.
+    ///
+    /// Coordinates are multiplied by the scale before drawing, and line
+    /// widths and text are scaled with them, so the same plot can be drawn at
+    /// different magnifications, e.g. for a zoomed view or to fit a printed
+    /// page, without recomputing its coordinates. [`DrawingBackend::get_size`]
+    /// returns the size of the device context divided by the scale, so a
+    /// plot laid out on the whole backend still fills the device context.
+    /// Scales that are not positive are ignored.
+    ///
+    /// This also draws crisp plots on a high-DPI display. Plots are laid out
+    /// in pixels: on a display with a content scale factor of 2, a plot drawn
+    /// in device pixels has half the intended size, with thin lines and small
+    /// margins. With a scale of the content scale factor of the window
+    /// (`wxWindow::GetContentScaleFactor`) on both axes, the plot has the
+    /// intended size, while the output keeps the full resolution of the
+    /// display.
+    ///
+    /// Whether a `wxdragon::AutoBufferedPaintDC` draws in device pixels
+    /// depends on the platform: on macOS and GTK, it already draws in logical
+    /// pixels, scaled by the system, so leave the scale to `1.0`. On Windows,
+    /// it draws in device pixels, so set the scale. For crisp off-screen
+    /// rendering, draw on a `wxdragon::MemoryDC` with a bitmap `factor` times
+    /// larger than the plot, with a scale of `factor`.
+    pub fn set_scale(
         &self,
-        style: &TStyle,
-    ) -> Result<(), ErrorInner> {
-        self.context
-            .set_text_background(self.context.get_background());
-        let color = convert_color(style.color());
-        self.context.set_text_foreground(color);
-        // FIXME: There is a discrepancy with font size compared to the
-        // BitmapBackend. For now using a coeficient 0.6. Note that in the
-        // tests of an off-screen wxBitmap, the dpi value is 96.
-        let point_size = (style.size() * 0.6) as i32;
-        let (family, face_name) = match style.family() {
-            // According to wx docs
-            // https://docs.wxwidgets.org/3.2/interface_2wx_2font_8h.html
-            FontFamily::Monospace => (wx::FontFamily::Teletype, "None"),
-            FontFamily::SansSerif => (wx::FontFamily::Swiss, "None"),
-            FontFamily::Serif => (wx::FontFamily::Roman, "None"),
-            FontFamily::Name(name) => (wx::FontFamily::Default, name),
-        };
-        use wx::FontStyle::{Italic, Normal, Slant};
-        let (style, weight) = match style.style() {
-            FontStyle::Bold => (Normal, wx::FontWeight::Bold),
-            FontStyle::Italic => (Italic, wx::FontWeight::Normal),
-            FontStyle::Normal => (Normal, wx::FontWeight::Normal),
-            FontStyle::Oblique => (Slant, wx::FontWeight::Normal),
-        };
-        let underlined = false;
-        let font = wx::Font::builder()
-            .with_point_size(point_size)
-            .with_family(family)
-            .with_style(style)
-            .with_weight(weight)
-            .with_underline(underlined)
-            // NOTE: wxdragon could be improved here. `with_face_name()`
-            // creates a string, and `build()` creates another string in its
-            // call to `wx::dc::Font::new_with_details()`.
-            .with_face_name(face_name)
-            .build()
-            .ok_or(ErrorInner::CreateFont)?;
-        self.context.set_font(&font);
+        scale_x: f64,
+        scale_y: f64,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        let valid = |scale: f64| scale > 0.0 && scale.is_finite();
+        if !(valid(scale_x) && valid(scale_y)) {
+            return Ok(());
+        }
+        // pending pixels are at the previous scale
+        self.flush_pixels().map_err(drawing_error)?;
+        let (previous_x, previous_y) = self.scale.replace((scale_x, scale_y));
+        self.rescale((scale_x / previous_x, scale_y / previous_y));
         Ok(())
     }
-}
 
-/// Convert color from plotters to wx
-fn convert_color(color: plotters_backend::BackendColor) -> wx::Colour {
-    let BackendColor { alpha, rgb } = color;
-    let (r, g, b) = rgb;
-    wx::Colour::new(r, g, b, (alpha * 255.0) as u8)
-}
+    /// Get the magnification of drawing, see [`WxBackend::set_scale`].
+    pub fn scale(&self) -> (f64, f64) {
+        self.scale.get()
+    }
 
-impl<'context, C> DrawingBackend for WxBackend<'context, C>
-where
-    C: DeviceContext,
-{
-    type ErrorType = Error;
-    fn get_size(&self) -> (u32, u32) {
-        let (width, height) = self.context.get_size();
-        (width as u32, height as u32)
+    /// Round the edges of filled rectangles and of horizontal and vertical
+    /// lines to device pixels, or draw them unsnapped with `false`, the
+    /// default.
+    ///
+    /// At a fractional scale, see [`WxBackend::set_scale`], the corners of a
+    /// rectangle land between device pixels, and wxWidgets rounds its
+    /// position and its size separately: adjacent bars of a histogram then
+    /// overlap or leave a gap, and bars of the same width differ by a pixel.
+    /// Snapping rounds each edge to the nearest device pixel boundary, so
+    /// that bars abut exactly and have sharp edges, and gridlines are a whole
+    /// number of device pixels wide. Leave it disabled for smooth scaling,
+    /// e.g. when animating the scale. Rectangles with an outline, see
+    /// [`WxBackend::set_shape_outline`] and
+    /// [`WxBackend::set_shape_outline_style`], are not snapped.
+    pub fn set_pixel_snap(&self, enabled: bool) {
+        self.pixel_snap.set(enabled);
     }
 
-    fn ensure_prepared(
-        &mut self,
-    ) -> Result<(), plotters_backend::DrawingErrorKind<Self::ErrorType>> {
-        Ok(())
+    /// Returns `true` if edges are rounded to device pixels, see
+    /// [`WxBackend::set_pixel_snap`].
+    pub fn pixel_snap(&self) -> bool {
+        self.pixel_snap.get()
     }
 
-    fn present(
-        &mut self,
-    ) -> Result<(), plotters_backend::DrawingErrorKind<Self::ErrorType>> {
-        Ok(())
+    /// Returns `true` if shapes are snapped to device pixels, i.e. pixel
+    /// snapping is enabled and the user scale is not 1.
+    fn snaps(&self) -> bool {
+        self.pixel_snap.get() && self.context.get_user_scale() != (1.0, 1.0)
     }
 
-    fn draw_pixel(
-        &mut self,
-        point: plotters_backend::BackendCoord,
-        color: plotters_backend::BackendColor,
-    ) -> Result<(), plotters_backend::DrawingErrorKind<Self::ErrorType>> {
-        let (x, y) = point;
-        let color = convert_color(color);
-        let width = 1;
-        let style = wx::PenStyle::Solid;
-        self.context.set_pen(color, width, style);
-        self.context.draw_point(x, y);
-        Ok(())
+    /// Run `draw` with a user scale of 1, i.e. in device pixels from the
+    /// device origin, to draw shapes snapped to device pixels.
+    fn draw_unscaled(&self, draw: impl FnOnce((f64, f64))) {
+        let scale = self.context.get_user_scale();
+        self.context.set_user_scale(1.0, 1.0);
+        draw(scale);
+        self.context.set_user_scale(scale.0, scale.1);
     }
 
-    fn draw_line<S: plotters_backend::BackendStyle>(
-        &mut self,
-        from: plotters_backend::BackendCoord,
-        to: plotters_backend::BackendCoord,
-        style: &S,
-    ) -> Result<(), plotters_backend::DrawingErrorKind<Self::ErrorType>> {
-        self.set_pen_style(style);
-        let (x1, y1) = from;
-        let (x2, y2) = to;
-        self.context.draw_line(x1, y1, x2, y2);
-        Ok(())
+    /// Multiply the user scale of the device context, and set the settings
+    /// that depend on it again.
+    fn rescale(&self, (ratio_x, ratio_y): (f64, f64)) {
+        if !self.valid.get() {
+            return;
+        }
+        let (scale_x, scale_y) = self.context.get_user_scale();
+        self.context
+            .set_user_scale(scale_x * ratio_x, scale_y * ratio_y);
+        // the clipping region is in logical coordinates
+        self.context.destroy_clipping_region();
+        self.apply_clip();
+        // the origin is in device pixels
+        self.apply_offset();
     }
 
-    fn draw_path<
-        S: plotters_backend::BackendStyle,
-        I: IntoIterator<Item = plotters_backend::BackendCoord>,
-    >(
-        &mut self,
-        path: I,
-        style: &S,
-    ) -> Result<(), plotters_backend::DrawingErrorKind<Self::ErrorType>> {
-        self.set_pen_style(style);
-        let points: Vec<wx::dc::Point> = path
-            .into_iter()
-            .map(|(x, y)| wx::dc::Point::new(x, y))
-            .collect();
-        let x_offset = 0;
-        let y_offset = 0;
-        self.context.draw_lines(&points[..], x_offset, y_offset);
+    /// Shift all subsequent drawing by `offset`, in logical pixels, or draw
+    /// in absolute coordinates again with `(0, 0)`, the default.
+    ///
+    /// This draws several independent plots on one device context, e.g. a
+    /// grid of charts on a panel: shift the backend to the upper-left corner
+    /// of each cell, and draw the chart on a drawing area shrunk to the size
+    /// of the cell, see the `subplots` example. The clipping rectangle, see
+    /// [`WxBackend::set_clip_rect`], is shifted too, while
+    /// [`DrawingBackend::get_size`] still returns the size of the whole
+    /// device context, or the virtual size, see
+    /// [`WxBackend::set_virtual_size`]. The offset is removed from the device
+    /// context when the backend is dropped.
+    pub fn set_offset(
+        &self,
+        offset: plotters_backend::BackendCoord,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        // pending pixels are at the previous offset
+        self.flush_pixels().map_err(drawing_error)?;
+        self.offset.set(offset);
+        if self.valid.get() {
+            self.apply_offset();
+        }
         Ok(())
     }
 
-    fn draw_circle<S: plotters_backend::BackendStyle>(
-        &mut self,
-        center: plotters_backend::BackendCoord,
-        radius: u32,
-        style: &S,
-        fill: bool,
-    ) -> Result<(), plotters_backend::DrawingErrorKind<Self::ErrorType>> {
-        self.set_pen_style(style);
-        self.set_brush_style(fill, style.color());
-        let (x, y) = center;
-        self.context.draw_circle(x, y, radius as i32);
-        Ok(())
+    /// Get the shift of drawing, see [`WxBackend::set_offset`].
+    pub fn offset(&self) -> plotters_backend::BackendCoord {
+        self.offset.get()
     }
 
-    fn draw_rect<S: plotters_backend::BackendStyle>(
-        &mut self,
-        upper_left: plotters_backend::BackendCoord,
-        bottom_right: plotters_backend::BackendCoord,
-        style: &S,
-        fill: bool,
-    ) -> Result<(), plotters_backend::DrawingErrorKind<Self::ErrorType>> {
-        self.set_pen_style(style);
-        self.set_brush_style(fill, style.color());
-        let (x1, y1) = upper_left;
-        let (x2, y2) = bottom_right;
-        let width = x2 - x1;
-        let height = y2 - y1;
-        self.context.draw_rectangle(x1, y1, width, height);
+    /// Shift all subsequent drawing by `(dx, dy)`, see
+    /// [`WxBackend::set_offset`], and return the backend, e.g. to turn it
+    /// into a plotters drawing area.
+    pub fn with_offset(self, dx: i32, dy: i32) -> Self {
+        // a failure invalidates the backend, and `present` reports it
+        let _ = self.set_offset((dx, dy));
+        self
+    }
+
+    /// Make the vertical axis point upward with `true`, with the origin at
+    /// the bottom-left corner, or downward again with `false`, the default.
+    ///
+    /// Plotters, like the device context, puts the origin at the top-left
+    /// corner, with y increasing downward. This flips the vertical axis for
+    /// code laid out with y increasing upward, and applies to every
+    /// primitive. Text is not mirrored, though: it is drawn upright, its
+    /// anchor is relative to the text as it appears, e.g. a bottom anchor
+    /// puts the text above its position, and [`WxBackend::draw_text_rotated`]
+    /// still rotates it clockwise as it appears. Likewise, pixels and bitmaps
+    /// are drawn upright, a bitmap hanging below its position.
+    ///
+    /// The flip combines with the offset, see [`WxBackend::set_offset`],
+    /// which then shifts drawing upward.
+    pub fn set_flip_y(
+        &self,
+        flip: bool,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        // pending pixels are in the previous orientation
+        self.flush_pixels().map_err(drawing_error)?;
+        self.flip_y.set(flip);
+        if self.valid.get() {
+            self.apply_offset();
+        }
         Ok(())
     }
 
-    fn fill_polygon<
-        S: plotters_backend::BackendStyle,
-        I: IntoIterator<Item = plotters_backend::BackendCoord>,
-    >(
-        &mut self,
-        vert: I,
-        style: &S,
-    ) -> Result<(), plotters_backend::DrawingErrorKind<Self::ErrorType>> {
-        self.set_pen_style(style);
-        self.set_brush_style(true, style.color());
-        let points: Vec<wx::dc::Point> = vert
-            .into_iter()
-            .map(|(x, y)| wx::dc::Point::new(x, y))
-            .collect();
-        let x_offset = 0;
-        let y_offset = 0;
-        let fill_mode = wx::dc::PolygonFillMode::OddEven;
-        self.context
-            .draw_polygon(&points[..], x_offset, y_offset, fill_mode);
+    /// Whether the vertical axis points upward, see
+    /// [`WxBackend::set_flip_y`].
+    pub fn flip_y(&self) -> bool {
+        self.flip_y.get()
+    }
+
+    /// Lay out plots on an area of `size` logical pixels instead of the
+    /// device context, or on the device context again with `None`, the
+    /// default.
+    ///
+    /// [`DrawingBackend::get_size`] returns the virtual size, and a flipped
+    /// vertical axis, see [`WxBackend::set_flip_y`], starts at the bottom of
+    /// the virtual area. Together with an offset, see
+    /// [`WxBackend::set_offset`], this draws a part of a larger plot, e.g.
+    /// to split a plot across the windows of a video wall: each window draws
+    /// the whole plot at the virtual size, and shows the tile with the
+    /// upper-left corner at `(x, y)` of the virtual area with an offset of
+    /// `(-x, -y)`, or `(-x, y)` with a flipped axis, which shifts drawing
+    /// upward. Lines and text crossing the edge of a tile continue on the
+    /// next tile, pixel for pixel.
+    pub fn set_virtual_size(
+        &self,
+        size: Option<(u32, u32)>,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        // pending pixels are at the previous origin
+        self.flush_pixels().map_err(drawing_error)?;
+        self.virtual_size.set(size);
+        if self.valid.get() {
+            self.apply_offset();
+        }
         Ok(())
     }
 
-    fn draw_text<TStyle: plotters_backend::BackendTextStyle>(
-        &mut self,
-        text: &str,
-        style: &TStyle,
-        pos: plotters_backend::BackendCoord,
-    ) -> Result<(), plotters_backend::DrawingErrorKind<Self::ErrorType>> {
-        // this also sets the font style
-        let (width, height) = self.estimate_text_size(text, style)?;
-        let width = width as i32;
-        let height = height as i32;
-        let (x, y) = pos;
+    /// Get the size plots are laid out on, see
+    /// [`WxBackend::set_virtual_size`].
+    pub fn virtual_size(&self) -> Option<(u32, u32)> {
+        self.virtual_size.get()
+    }
 
-        // plotters convention is that anchor position is relative to
-        // character's point of view
-        let Pos { h_pos, v_pos } = style.anchor();
-        let dx = match h_pos {
-            HPos::Left => 0,
-            HPos::Center => -width / 2,
-            HPos::Right => -width,
-        };
-        let dy = match v_pos {
-            VPos::Top => 0,
-            VPos::Center => -height / 2,
-            VPos::Bottom => -height,
+    /// Draw plots in the largest centered rectangle of the device context
+    /// with the aspect ratio `ratio`, i.e. its width divided by its height,
+    /// and clear the margins around it to the background color.
+    ///
+    /// This letterboxes a plot that must keep its shape, e.g. a square
+    /// scatter plot in a wide window, instead of stretching it. The rectangle
+    /// replaces the offset, the virtual size and the clipping rectangle, see
+    /// [`WxBackend::set_offset`], [`WxBackend::set_virtual_size`] and
+    /// [`WxBackend::set_clip_rect`], so that a plot laid out on the whole
+    /// backend fills the rectangle. Call it again when the size of the device
+    /// context changes. Does nothing if `ratio` is not a positive number.
+    pub fn letterbox(&self, ratio: f64) -> Result<(), DrawingErrorKind<Error>> {
+        if !(ratio > 0.0 && ratio.is_finite()) {
+            return Ok(());
+        }
+        self.set_offset((0, 0))?;
+        self.set_virtual_size(None)?;
+        self.set_clip_rect(None)?;
+        let (width, height) = self.logical_size();
+        if width <= 0 || height <= 0 {
+            return Ok(());
+        }
+        let (box_width, box_height) = if width as f64 > height as f64 * ratio {
+            (((height as f64 * ratio).round() as i32).max(1), height)
+        } else {
+            (width, ((width as f64 / ratio).round() as i32).max(1))
         };
-        let (dx, dy) = match style.transform() {
-            FontTransform::None => (dx, dy),
-            FontTransform::Rotate90 => (-dy, dx),
-            FontTransform::Rotate180 => (-dx, -dy),
-            FontTransform::Rotate270 => (dy, -dx),
+        // position of the rectangle as it appears, and in logical units
+        let (x, y) = ((width - box_width) / 2, (height - box_height) / 2);
+        let logical_y = match self.flip_y.get() {
+            true => height - y - box_height,
+            false => y,
         };
+        if x > 0 {
+            self.clear_rect((0, 0), (x - 1, height - 1))?;
+        }
+        if x + box_width < width {
+            self.clear_rect((x + box_width, 0), (width - 1, height - 1))?;
+        }
+        if logical_y > 0 {
+            self.clear_rect((0, 0), (width - 1, logical_y - 1))?;
+        }
+        if logical_y + box_height < height {
+            self.clear_rect(
+                (0, logical_y + box_height),
+                (width - 1, height - 1),
+            )?;
+        }
+        self.set_virtual_size(Some((box_width as u32, box_height as u32)))?;
+        self.set_offset((x, self.downward(y)))?;
+        self.set_clip_rect(Some(((0, 0), (box_width - 1, box_height - 1))))
+    }
 
-        // plotters rotates clockwise, wxwidgets rotates counterclockwise
-        let angle = match style.transform() {
-            FontTransform::None => None,
-            FontTransform::Rotate90 => Some(-90.0),
-            FontTransform::Rotate180 => Some(-180.0),
-            FontTransform::Rotate270 => Some(-270.0),
-        };
-        if let Some(angle) = angle {
-            self.context.draw_rotated_text(text, x + dx, y + dy, angle);
-        } else {
-            self.context.draw_text(text, x + dx, y + dy);
+    /// Size plots are laid out on, in logical units: the virtual size if
+    /// any, see [`WxBackend::set_virtual_size`], or the size of the device
+    /// context.
+    fn layout_size(&self) -> (i32, i32) {
+        match self.virtual_size.get() {
+            Some((width, height)) => (
+                width.min(COORD_LIMIT as u32) as i32,
+                height.min(COORD_LIMIT as u32) as i32,
+            ),
+            None => self.logical_size(),
         }
-        Ok(())
     }
 
-    fn estimate_text_size<TStyle: plotters_backend::BackendTextStyle>(
-        &self,
+    /// Set the device origin of the device context from the offset, which
+    /// shifts drawing upward with a flipped vertical axis.
+    fn apply_offset(&self) {
+        let (dx, dy) = self.offset.get();
+        let (scale_x, scale_y) = self.context.get_user_scale();
+        self.context.set_device_origin(
+            (dx as f64 * scale_x).round() as i32,
+            (self.downward(dy) as f64 * scale_y).round() as i32,
+        );
+    }
+
+    /// Convert a vertical distance as it appears, i.e. downward, to logical
+    /// units, see [`WxBackend::set_flip_y`].
+    fn downward(&self, dy: i32) -> i32 {
+        if self.flip_y.get() { -dy } else { dy }
+    }
+
+    /// Returns `false` if a call to wxWidgets failed while drawing.
+    ///
+    /// A failure, e.g. because the device context became invalid when its
+    /// window was destroyed, leaves the frame incomplete. From then on, the
+    /// backend does not call into wxWidgets anymore: drawing operations are
+    /// no-ops, and [`DrawingBackend::present`] returns an error.
+    pub fn is_valid(&self) -> bool {
+        self.valid.get()
+    }
+
+    /// Get the device context the backend draws on.
+    ///
+    /// This is an escape hatch for drawing operations the backend does not
+    /// wrap, e.g. a gradient fill, between two plotters operations. Call
+    /// [`WxBackend::flush`] first, so that buffered pixels are drawn below
+    /// and the backend sets its own pen and brush again afterwards.
+    ///
+    /// The pen, brush, font and text color of the device context are left in
+    /// whatever state the last primitive needed, and may be changed freely.
+    /// Restore any other setting you change, e.g. the clipping region or the
+    /// logical function, as the backend does not set them again.
+    pub fn context(&self) -> &C {
+        &self.context
+    }
+
+    /// Set a factor applied to the alpha of every color drawn.
+    ///
+    /// The factor is clamped to `[0, 1]`, and defaults to `1.0`. This is
+    /// useful to fade a whole plot in or out, e.g. by ramping the factor from
+    /// a timer, without changing the style of every series.
+    pub fn set_global_alpha(&self, alpha: f64) {
+        self.global_alpha.set(alpha.clamp(0.0, 1.0));
+    }
+
+    /// Get the factor applied to the alpha of every color drawn.
+    pub fn global_alpha(&self) -> f64 {
+        self.global_alpha.get()
+    }
+
+    /// Set the pen style of the lines and paths drawn from now on, e.g.
+    /// [`wx::PenStyle::Dot`] for dotted gridlines, or
+    /// [`wx::PenStyle::DotDash`] for a reference line.
+    ///
+    /// This applies to [`DrawingBackend::draw_line`] and
+    /// [`DrawingBackend::draw_path`], which plotters uses for gridlines, axes
+    /// and line series. Outlines of shapes stay solid. The default is
+    /// [`wx::PenStyle::Solid`]: set it back before drawing series that should
+    /// be solid. Plotters styles have no dash information, so this setting
+    /// is the only way to get dashed lines from this backend.
+    pub fn set_line_style(&self, style: wx::PenStyle) {
+        self.line_style.set(style);
+    }
+
+    /// Get the pen style of lines and paths.
+    pub fn line_style(&self) -> wx::PenStyle {
+        self.line_style.get()
+    }
+
+    /// Set the pattern of the polygons and rectangles filled from now on,
+    /// e.g. [`FillPattern::ForwardDiagonal`] for hatched bars.
+    ///
+    /// This applies to [`DrawingBackend::fill_polygon`] and to filled
+    /// rectangles, which plotters uses for areas, histograms and bars. Filled
+    /// circles stay solid. The default is [`FillPattern::Solid`].
+    pub fn set_fill_pattern(&self, pattern: FillPattern) {
+        self.fill_pattern.set(pattern);
+    }
+
+    /// Get the pattern of filled polygons and rectangles.
+    pub fn fill_pattern(&self) -> FillPattern {
+        self.fill_pattern.get()
+    }
+
+    /// Use a bitmap as a repeating pattern for the next filled rectangle.
+    ///
+    /// The bitmap is tiled from the upper-left corner of the rectangle, and
+    /// replaces the solid fill color; fills are solid again afterwards. This
+    /// complements hatch patterns with arbitrary textures, e.g. a tiled logo
+    /// as a background. Only rectangles support this pattern, other filled
+    /// shapes are drawn solid.
+    pub fn set_stipple_brush(&self, bitmap: wx::Bitmap) {
+        *self.stipple.borrow_mut() = Some(bitmap);
+    }
+
+    /// Fill a rectangle by tiling a bitmap, bounds included.
+    ///
+    /// wxdragon brushes only take a color and a style, without a stipple
+    /// bitmap, so tiles are drawn one by one.
+    fn fill_rect_with_stipple(
+        &self,
+        (x1, y1): plotters_backend::BackendCoord,
+        (x2, y2): plotters_backend::BackendCoord,
+        bitmap: &wx::Bitmap,
+    ) {
+        let (tile_width, tile_height) =
+            (bitmap.get_width(), bitmap.get_height());
+        if tile_width <= 0 || tile_height <= 0 {
+            return;
+        }
+        let transparent = true;
+        let (x, y, width, height) =
+            self.context_rect(rect_bounds((x1, y1), (x2, y2)));
+        self.context.set_clipping_region(x, y, width, height);
+        // only the tiles on the device context, aligned on the rectangle as
+        // it appears
+        let (size_x, size_y) = self.logical_size();
+        let (offset_x, offset_y) = self.offset.get();
+        let offset_y = self.downward(offset_y);
+        let first = |start: i32, tile: i32, visible: i32| {
+            if start < visible {
+                visible - (visible - start) % tile
+            } else {
+                start
+            }
+        };
+        let x1 = first(x, tile_width, -offset_x);
+        let y1 = first(y, tile_height, -offset_y);
+        let x2 = (x + width - 1).min(size_x - offset_x);
+        let y2 = (y + height - 1).min(size_y - offset_y);
+        for y in (y1..=y2).step_by(tile_height as usize) {
+            for x in (x1..=x2).step_by(tile_width as usize) {
+                self.context.draw_bitmap(bitmap, x, y, transparent);
+            }
+        }
+        self.context.destroy_clipping_region();
+        self.apply_clip();
+    }
+
+    /// Restrict drawing to a rectangle, bounds included, or draw on the whole
+    /// device context again with `None`, the default.
+    ///
+    /// This keeps series within the plotting area, e.g. when data overshoots
+    /// the range of the axes, so that they do not paint over the margins and
+    /// labels. The clipping applies to every drawing primitive, including
+    /// lines, paths and polygons. Pixels drawn before this call are clipped
+    /// with the previous setting.
+    pub fn set_clip_rect(
+        &self,
+        rect: Option<(
+            plotters_backend::BackendCoord,
+            plotters_backend::BackendCoord,
+        )>,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        self.flush_pixels().map_err(drawing_error)?;
+        self.clip.set(rect);
+        if self.valid.get() {
+            self.context.destroy_clipping_region();
+            self.apply_clip();
+        }
+        Ok(())
+    }
+
+    /// Get the rectangle drawing is restricted to, see
+    /// [`WxBackend::set_clip_rect`].
+    pub fn clip_rect(
+        &self,
+    ) -> Option<(
+        plotters_backend::BackendCoord,
+        plotters_backend::BackendCoord,
+    )> {
+        self.clip.get()
+    }
+
+    /// Set the clipping region of the device context from the clipping
+    /// rectangle.
+    fn apply_clip(&self) {
+        if let Some((corner1, corner2)) = self.clip.get() {
+            let (x, y, width, height) =
+                self.context_rect(rect_bounds(corner1, corner2));
+            self.context.set_clipping_region(x, y, width, height);
+        }
+    }
+
+    /// Use an outline of another color and width than the fill for the next
+    /// filled rectangle, circle or polygon, or the fill color and stroke
+    /// width of its style with `None`, the default.
+    ///
+    /// Plotters draws filled shapes with an outline of the fill color. This
+    /// gives e.g. bars with a contrasting border, or without a border with a
+    /// width of 0. The outline applies to a single shape, and is reset
+    /// afterwards.
+    pub fn set_shape_outline(&self, outline: Option<(wx::Colour, i32)>) {
+        self.outline.set(outline.map(|(color, width)| {
+            ([color.r, color.g, color.b, color.a], width)
+        }));
+    }
+
+    /// Draw the outline of the next filled rectangle, circle or polygon with
+    /// the pen style `style`, e.g. [`wx::PenStyle::ShortDash`] for a
+    /// selected or pending item.
+    ///
+    /// This combines with [`WxBackend::set_shape_outline`], e.g. for a solid
+    /// box with a dashed border of another color, while the inside stays
+    /// solid. Like the outline, the style applies to a single shape: the
+    /// next outlines, and lines drawn afterwards, are solid again.
+    pub fn set_shape_outline_style(&self, style: wx::PenStyle) {
+        self.outline_style.set(style);
+    }
+
+    /// Set pen for a shape, with the outline set by
+    /// [`WxBackend::set_shape_outline`] and
+    /// [`WxBackend::set_shape_outline_style`] if the shape is filled.
+    fn set_shape_pen_style<S: plotters_backend::BackendStyle>(
+        &self,
+        style: &S,
+        fill: bool,
+    ) {
+        if !fill {
+            self.set_pen_style(style);
+            return;
+        }
+        let pen_style = self.outline_style.replace(wx::PenStyle::Solid);
+        let (color, width) = match self.outline.take() {
+            Some(([r, g, b, a], width)) => {
+                let color = BackendColor {
+                    alpha: a as f64 / 255.0,
+                    rgb: (r, g, b),
+                };
+                (color, width)
+            }
+            None if pen_style == wx::PenStyle::Solid => {
+                self.set_pen_style(style);
+                return;
+            }
+            None => (style.color(), style.stroke_width() as i32),
+        };
+        let rgba = convert_rgba(self.transform_color(color));
+        let pen_style = match width {
+            0 => wx::PenStyle::Transparent,
+            _ => pen_style,
+        };
+        self.apply_pen((rgba, width, pen_style));
+    }
+
+    /// Draw a check mark inside a rectangle.
+    ///
+    /// This is handy for pass/fail indicators next to series. The check mark
+    /// is made of two strokes, from the middle of the left edge down to the
+    /// bottom edge, a third of the way across, and up to the upper-right
+    /// corner. The stroke color and
+    /// width are taken from `style`.
+    pub fn draw_check_mark<S: plotters_backend::BackendStyle>(
+        &self,
+        upper_left: plotters_backend::BackendCoord,
+        bottom_right: plotters_backend::BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        if !self.begin_draw()? {
+            return Ok(());
+        }
+        self.set_pen_style(style);
+        // plotters may pass the corners in any order
+        let (x, y, width, height) =
+            self.context_rect(rect_bounds(upper_left, bottom_right));
+        let (right, bottom) = (x + width - 1, y + height - 1);
+        let (middle_x, middle_y) = (x + (width - 1) / 3, y + (height - 1) / 2);
+        self.context.draw_line(x, middle_y, middle_x, bottom);
+        self.context.draw_line(middle_x, bottom, right, y);
+        Ok(())
+    }
+
+    /// Draw a cross (the two diagonals) inside a rectangle.
+    ///
+    /// This is the counterpart of [`WxBackend::draw_check_mark`] for failure
+    /// indicators. The stroke color and width are taken from `style`.
+    pub fn draw_cross<S: plotters_backend::BackendStyle>(
+        &self,
+        upper_left: plotters_backend::BackendCoord,
+        bottom_right: plotters_backend::BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        if !self.begin_draw()? {
+            return Ok(());
+        }
+        self.set_pen_style(style);
+        let (x1, y1) = self.context_position(upper_left);
+        let (x2, y2) = self.context_position(bottom_right);
+        self.context.draw_line(x1, y1, x2, y2);
+        self.context.draw_line(x1, y2, x2, y1);
+        Ok(())
+    }
+
+    /// Draw a line by XORing `color` with the pixels below it.
+    ///
+    /// Drawing the same line again restores the pixels, which erases a
+    /// transient line, e.g. a crosshair, without redrawing the plot. White
+    /// inverts the pixels, which is visible on any plot. The alpha of
+    /// `color`, the global alpha and the palette are ignored. See
+    /// [`Overlay`] to keep track of the lines to erase.
+    ///
+    /// This relies on the XOR logical function of the device context, which
+    /// some device contexts do not implement, e.g. with GTK 3.
+    pub fn draw_xor_line(
+        &self,
+        from: plotters_backend::BackendCoord,
+        to: plotters_backend::BackendCoord,
+        color: plotters_backend::BackendColor,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        if !self.begin_draw()? {
+            return Ok(());
+        }
+        let (r, g, b) = color.rgb;
+        self.apply_pen(([r, g, b, 255], 1, wx::PenStyle::Solid));
+        let (x1, y1) = self.context_position(from);
+        let (x2, y2) = self.context_position(to);
+        self.context
+            .set_logical_function(wx::dc::LogicalFunction::Xor);
+        self.context.draw_line(x1, y1, x2, y2);
+        self.context
+            .set_logical_function(wx::dc::LogicalFunction::Copy);
+        Ok(())
+    }
+
+    /// Draw the outline of a rectangle by XORing `color` with the pixels
+    /// below it, e.g. a rubber band selection.
+    ///
+    /// As for [`WxBackend::draw_xor_line`], drawing the same rectangle again
+    /// erases it.
+    pub fn draw_xor_rect(
+        &self,
+        corner1: plotters_backend::BackendCoord,
+        corner2: plotters_backend::BackendCoord,
+        color: plotters_backend::BackendColor,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        if !self.begin_draw()? {
+            return Ok(());
+        }
+        let (r, g, b) = color.rgb;
+        self.apply_pen(([r, g, b, 255], 1, wx::PenStyle::Solid));
+        self.apply_brush(([r, g, b, 255], wx::BrushStyle::Transparent));
+        let (x, y, width, height) =
+            self.context_rect(rect_bounds(corner1, corner2));
+        self.context
+            .set_logical_function(wx::dc::LogicalFunction::Xor);
+        self.context.draw_rectangle(x, y, width, height);
+        self.context
+            .set_logical_function(wx::dc::LogicalFunction::Copy);
+        Ok(())
+    }
+
+    /// Draw translucent filled circles over data points, e.g. to highlight a
+    /// selection.
+    ///
+    /// The circles have no outline, and `color` is expected to have an alpha
+    /// below 1 so that the points stay visible under the highlight. Pen and
+    /// brush are set anew by every drawing primitive, so this does not affect
+    /// the style of subsequent drawings.
+    ///
+    /// To call this from a plotters chart, wrap the points in a custom
+    /// element implementing `plotters::element::Drawable<WxBackend<C>>`, see
+    /// the `highlight` example.
+    pub fn highlight_points<I>(
+        &self,
+        points: I,
+        radius: u32,
+        color: plotters_backend::BackendColor,
+    ) -> Result<(), DrawingErrorKind<Error>>
+    where
+        I: IntoIterator<Item = plotters_backend::BackendCoord>,
+    {
+        if !self.begin_draw()? {
+            return Ok(());
+        }
+        let rgba = convert_rgba(self.transform_color(color));
+        self.apply_pen((rgba, 0, wx::PenStyle::Transparent));
+        self.apply_brush((rgba, wx::BrushStyle::Solid));
+        let radius = radius.min(COORD_LIMIT as u32) as i32;
+        for point in points {
+            let (x, y) = self.context_position(point);
+            self.context.draw_circle(x, y, radius);
+        }
+        Ok(())
+    }
+
+    /// Draw an RGBA bitmap scaled to a given logical size.
+    ///
+    /// [`DrawingBackend::blit_bitmap`] draws one source pixel per device
+    /// pixel, so on a display with a content scale factor of 2, an image
+    /// meant to be 64 logical pixels wide appears 32 logical pixels wide.
+    /// This method instead stretches the `src` image of size `(iw, ih)` to
+    /// cover `logical_size` pixels of the device context, e.g. a high
+    /// resolution 128x128 logo drawn at a logical size of 64x64.
+    ///
+    /// Nothing is drawn for an empty `logical_size`. An error is returned if
+    /// `src` does not match the image size, without drawing anything; the
+    /// frame remains valid.
+    pub fn blit_bitmap_logical(
+        &self,
+        pos: plotters_backend::BackendCoord,
+        (iw, ih): (u32, u32),
+        logical_size: (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        if src.len() != 4 * iw as usize * ih as usize {
+            return Err(drawing_error(ErrorInner::BufferSize));
+        }
+        if !self.begin_draw()? {
+            return Ok(());
+        }
+        if iw == 0 || ih == 0 || logical_size.0 == 0 || logical_size.1 == 0 {
+            return Ok(());
+        }
+        let bitmap = wx::Bitmap::from_rgba(src, iw, ih)
+            .ok_or_else(|| self.invalidate(ErrorInner::CreateBitmap))
+            .map_err(drawing_error)?;
+        let scale_x = logical_size.0 as f64 / iw as f64;
+        let scale_y = logical_size.1 as f64 / ih as f64;
+        // Move the device origin to the position instead of dividing the
+        // position by the stretch, which would round it to a multiple of the
+        // stretch.
+        let (x, y) = self.context_position(pos);
+        let (dx, dy) = self.offset.get();
+        let dy = self.downward(dy);
+        let (user_scale_x, user_scale_y) = self.context.get_user_scale();
+        self.context.set_device_origin(
+            ((dx as f64 + x as f64) * user_scale_x).round() as i32,
+            ((dy as f64 + y as f64) * user_scale_y).round() as i32,
+        );
+        self.context
+            .set_user_scale(user_scale_x * scale_x, user_scale_y * scale_y);
+        let transparent = false;
+        self.context.draw_bitmap(&bitmap, 0, 0, transparent);
+        self.context.set_user_scale(user_scale_x, user_scale_y);
+        self.apply_offset();
+        Ok(())
+    }
+
+    /// Draw an RGBA image through a mask, so that only some of its pixels
+    /// are drawn, e.g. a logo clipped to a circle.
+    ///
+    /// `mask` has one byte per pixel of the `src` image of size `(iw, ih)`,
+    /// row by row: pixels with a non-zero mask byte are drawn, others leave
+    /// the device context untouched. As with [`DrawingBackend::blit_bitmap`],
+    /// the alpha channel of drawn pixels is honored. The mask is applied to
+    /// the alpha channel of the bitmap, which wxWidgets draws on all
+    /// platforms, rather than to a `wxMask`.
+    ///
+    /// An error is returned if `src` or `mask` does not match the image size,
+    /// without drawing anything; the frame remains valid.
+    pub fn blit_bitmap_with_mask(
+        &self,
+        pos: plotters_backend::BackendCoord,
+        (iw, ih): (u32, u32),
+        src: &[u8],
+        mask: &[u8],
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        if src.len() != 4 * iw as usize * ih as usize {
+            return Err(drawing_error(ErrorInner::BufferSize));
+        }
+        if mask.len() != iw as usize * ih as usize {
+            return Err(drawing_error(ErrorInner::MaskSize));
+        }
+        if !self.begin_draw()? {
+            return Ok(());
+        }
+        let mut data = src.to_vec();
+        for (pixel, &visible) in data.chunks_exact_mut(4).zip(mask) {
+            if visible == 0 {
+                pixel[3] = 0;
+            }
+        }
+        let bitmap = wx::Bitmap::from_rgba(&data, iw, ih)
+            .ok_or_else(|| self.invalidate(ErrorInner::CreateBitmap))
+            .map_err(drawing_error)?;
+        let (x, y) = self.context_position(pos);
+        let transparent = true;
+        self.context.draw_bitmap(&bitmap, x, y, transparent);
+        Ok(())
+    }
+
+    /// Map every color drawn to the nearest color of a palette, or disable
+    /// the mapping with `None`.
+    ///
+    /// This gives a paletted look to the plot, e.g. for pixel-art charts or
+    /// display targets with few colors. Alpha is preserved. See [`palette`]
+    /// for built-in palettes.
+    pub fn set_color_quantization(&self, palette: Option<&[(u8, u8, u8)]>) {
+        *self.palette.borrow_mut() = palette.map(<[_]>::to_vec);
+    }
+
+    /// Fill a translucent vertical band, e.g. to shade a region of interest
+    /// such as a recession period on a time series.
+    ///
+    /// The band covers the pixel columns from `x_start` to `x_end` and the
+    /// rows of `y_range`, typically the full height of the plotting area,
+    /// bounds included. It has no outline, and `color` is expected to have an
+    /// alpha below 1. Draw the band before the series, so that the series are
+    /// drawn over it.
+    ///
+    /// To call this from a plotters chart, wrap the band in a custom element
+    /// implementing `plotters::element::Drawable<WxBackend<C>>`, see the
+    /// `band` example.
+    pub fn draw_band(
+        &self,
+        x_start: i32,
+        x_end: i32,
+        y_range: (i32, i32),
+        color: plotters_backend::BackendColor,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        if !self.begin_draw()? {
+            return Ok(());
+        }
+        let rgba = convert_rgba(self.transform_color(color));
+        self.apply_pen((rgba, 0, wx::PenStyle::Transparent));
+        self.apply_brush((rgba, wx::BrushStyle::Solid));
+        let (x, y, width, height) = self.context_rect(rect_bounds(
+            (x_start, y_range.0),
+            (x_end, y_range.1),
+        ));
+        self.context.draw_rectangle(x, y, width, height);
+        Ok(())
+    }
+
+    /// Draw many pixels, e.g. a heat map or a fractal.
+    ///
+    /// The output is identical to calling [`DrawingBackend::draw_pixel`] for
+    /// each pixel in turn, without the overhead of a call per pixel: the
+    /// size of the device context is queried once, and the pixel buffer is
+    /// borrowed once for all pixels.
+    pub fn draw_pixels<I>(
+        &self,
+        pixels: I,
+    ) -> Result<(), DrawingErrorKind<Error>>
+    where
+        I: IntoIterator<
+            Item = (
+                plotters_backend::BackendCoord,
+                plotters_backend::BackendColor,
+            ),
+        >,
+    {
+        if !self.valid.get() {
+            return Ok(());
+        }
+        let size = self.logical_size();
+        let mut buffer = self.pixels.borrow_mut();
+        for (point, color) in pixels {
+            let rgba = convert_rgba(self.transform_color(color));
+            buffer.set(size, self.device_position(point), rgba);
+        }
+        Ok(())
+    }
+
+    /// Draw every digit of a text in a cell of the same width, or disable this
+    /// with `false`, the default.
+    ///
+    /// With proportional digits, a number such as a tick label or a live value
+    /// changes width when its digits change, and jitters from frame to frame.
+    /// wxWidgets cannot select the tabular-figure variant of a font, so each
+    /// digit is drawn centered in a cell as wide as the widest digit of the
+    /// font. Other characters keep their proportional width.
+    pub fn set_tabular_figures(&self, enabled: bool) {
+        self.tabular_figures.set(enabled);
+    }
+
+    /// Draw all text in bold, or only text with a bold plotters style with
+    /// `false`, the default.
+    ///
+    /// A plotters `FontStyle` is either bold, italic or oblique, so plotters
+    /// cannot ask for a bold italic caption. Enable this setting and use an
+    /// italic style to get both.
+    pub fn set_bold(&self, bold: bool) {
+        self.bold.set(bold);
+    }
+
+    /// Underline the text of the next call to [`DrawingBackend::draw_text`].
+    ///
+    /// Plotters text styles have no underline flag, so this is the way to
+    /// draw underlined text, e.g. for labels styled as hyperlinks. The
+    /// setting applies to a single text, and is reset afterwards.
+    pub fn set_underline(&self, underline: bool) {
+        self.underline.set(underline);
+    }
+
+    /// Blend the anti-aliased edges of opaque text with the pixels under it,
+    /// or let the device context draw it with `false`, the default.
+    ///
+    /// Some device contexts smooth the edges of glyphs against the text
+    /// background color instead of the pixels actually drawn, which leaves a
+    /// fringe around labels drawn over a gradient or an image. With this
+    /// setting, opaque text is drawn as translucent text already is: the
+    /// glyph coverage is rendered off-screen and drawn as the alpha of the
+    /// text color, at the cost of a bitmap per text.
+    pub fn set_text_coverage_blend(&self, enabled: bool) {
+        self.text_coverage_blend.set(enabled);
+    }
+
+    /// Draw a text rotated clockwise by an arbitrary angle in degrees, e.g.
+    /// 45° for tilted annotations.
+    ///
+    /// Plotters styles only rotate text by multiples of 90°. This method
+    /// draws `text` with `style` as [`DrawingBackend::draw_text`] does, but
+    /// rotated by `degrees` instead of the transform of `style`. The anchor
+    /// of `style` is relative to the text, e.g. a centered anchor puts the
+    /// center of the rotated text at `pos`. With the vertical axis flipped,
+    /// see [`WxBackend::set_flip_y`], the text still turns clockwise as it
+    /// appears.
+    pub fn draw_text_rotated<TStyle: plotters_backend::BackendTextStyle>(
+        &self,
         text: &str,
         style: &TStyle,
-    ) -> Result<(u32, u32), plotters_backend::DrawingErrorKind<Self::ErrorType>>
-    {
+        pos: plotters_backend::BackendCoord,
+        degrees: f64,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        if !self.begin_draw()? {
+            return Ok(());
+        }
+        self.draw_text_at_angle(text, style, pos, degrees)
+    }
+
+    /// Split a text into runs to draw separately, with the offset of each run
+    /// from the upper-left corner of the text, and compute the extent of the
+    /// whole text.
+    ///
+    /// Lines, separated by `\n`, are stacked below each other and aligned
+    /// within the text as given by `h_pos`. The font must be set beforehand.
+    fn layout_text<'a>(
+        &self,
+        text: &'a str,
+        h_pos: HPos,
+    ) -> (Vec<(&'a str, (i32, i32))>, (i32, i32)) {
+        if !text.contains('\n') {
+            let (runs, extent) = self.layout_line(text);
+            let runs = runs.into_iter().map(|(run, dx)| (run, (dx, 0)));
+            return (runs.collect(), extent);
+        }
+        let lines: Vec<_> = text
+            .split('\n')
+            .map(|line| line.strip_suffix('\r').unwrap_or(line))
+            .map(|line| {
+                let (runs, (width, height)) = self.layout_line(line);
+                if line.is_empty() {
+                    // an empty line still takes the height of a line
+                    (runs, (0, self.context.get_text_extent(" ").1))
+                } else {
+                    (runs, (width, height))
+                }
+            })
+            .collect();
+        let width = lines.iter().map(|(_, (w, _))| *w).max().unwrap_or(0);
+        let mut runs = Vec::new();
+        let mut height = 0;
+        for (line_runs, (line_width, line_height)) in lines {
+            let dx = match h_pos {
+                HPos::Left => 0,
+                HPos::Center => (width - line_width) / 2,
+                HPos::Right => width - line_width,
+            };
+            runs.extend(
+                line_runs
+                    .into_iter()
+                    .filter(|(run, _)| !run.is_empty())
+                    .map(|(run, offset)| (run, (dx + offset, height))),
+            );
+            height += line_height;
+        }
+        (runs, (width, height))
+    }
+
+    /// Split a line of text into runs to draw separately, with the offset of
+    /// each run along the text direction, and compute the extent of the line.
+    ///
+    /// The font must be set beforehand. Without tabular figures, the line is
+    /// a single run.
+    fn layout_line<'a>(
+        &self,
+        text: &'a str,
+    ) -> (Vec<(&'a str, i32)>, (i32, i32)) {
+        if !self.tabular_figures.get()
+            || !text.contains(|c: char| c.is_ascii_digit())
+        {
+            return (vec![(text, 0)], self.context.get_text_extent(text));
+        }
+        let digit_width = ('0'..='9')
+            .map(|c| self.context.get_text_extent(c.encode_utf8(&mut [0; 4])).0)
+            .max()
+            .unwrap_or(0);
+        let mut runs = Vec::new();
+        let (mut width, mut height) = (0, 0);
+        let mut rest = text;
+        while !rest.is_empty() {
+            // a single digit, or the longest run of other characters
+            let len = match rest.find(|c: char| c.is_ascii_digit()) {
+                Some(0) => 1,
+                Some(len) => len,
+                None => rest.len(),
+            };
+            let (run, tail) = rest.split_at(len);
+            let (run_width, run_height) = self.context.get_text_extent(run);
+            if len == 1 && run.as_bytes()[0].is_ascii_digit() {
+                runs.push((run, width + (digit_width - run_width) / 2));
+                width += digit_width;
+            } else {
+                runs.push((run, width));
+                width += run_width;
+            }
+            height = height.max(run_height);
+            rest = tail;
+        }
+        (runs, (width, height))
+    }
+
+    /// Size of the device context in logical units, i.e. in the coordinates
+    /// used by the drawing primitives.
+    ///
+    /// This is the size in pixels, unless a user scale is set on the device
+    /// context, e.g. for supersampled export.
+    fn logical_size(&self) -> (i32, i32) {
+        let (width, height) = self.context.get_size();
+        let (scale_x, scale_y) = self.context.get_user_scale();
+        (
+            (width as f64 / scale_x).round() as i32,
+            (height as f64 / scale_y).round() as i32,
+        )
+    }
+
+    /// Apply backend-wide color settings to a plotters color.
+    fn transform_color(
+        &self,
+        color: plotters_backend::BackendColor,
+    ) -> plotters_backend::BackendColor {
+        let BackendColor { alpha, rgb } = color;
+        let alpha = alpha * self.global_alpha.get();
+        let rgb = match &*self.palette.borrow() {
+            Some(palette) => palette::nearest(palette, rgb),
+            None => rgb,
+        };
+        BackendColor { alpha, rgb }
+    }
+
+    /// Prepare the device context for a drawing primitive, by drawing the
+    /// pending pixels.
+    ///
+    /// Returns `false` if the backend is invalid, in which case the primitive
+    /// must not call into wxWidgets.
+    fn begin_draw(&self) -> Result<bool, DrawingErrorKind<Error>> {
+        if !self.valid.get() {
+            return Ok(false);
+        }
+        self.flush_pixels().map_err(drawing_error)?;
+        Ok(true)
+    }
+
+    /// Mark the backend as invalid after a failed call to wxWidgets, and
+    /// return the error.
+    fn invalidate(&self, error: ErrorInner) -> ErrorInner {
+        self.valid.set(false);
+        error
+    }
+
+    /// Draw the pixels accumulated by `draw_pixel` on the device context.
+    ///
+    /// This must be called before any other drawing primitive, so that the
+    /// drawing order is preserved.
+    fn flush_pixels(&self) -> Result<(), ErrorInner> {
+        let Some(region) = self.pixels.borrow_mut().take() else {
+            return Ok(());
+        };
+        if !self.valid.get() {
+            return Ok(());
+        }
+        let bitmap =
+            wx::Bitmap::from_rgba(&region.data, region.width, region.height)
+                .ok_or_else(|| self.invalidate(ErrorInner::CreateBitmap))?;
+        // the buffer covers the device context as it appears, whatever the
+        // offset and the orientation
+        let (x, y) =
+            self.context_position(self.logical_position((region.x, region.y)));
+        let transparent = true;
+        self.context.draw_bitmap(&bitmap, x, y, transparent);
+        Ok(())
+    }
+
+    /// Get the position of a point on the device context as it appears, i.e.
+    /// shifted by the offset and with y increasing downward, see
+    /// [`WxBackend::set_offset`] and [`WxBackend::set_flip_y`].
+    fn device_position(
+        &self,
+        point: plotters_backend::BackendCoord,
+    ) -> plotters_backend::BackendCoord {
+        let (x, y) = clamp_coord(point);
+        let (dx, dy) = self.offset.get();
+        let y = y.saturating_add(dy);
+        match self.flip_y.get() {
+            true => (x.saturating_add(dx), self.layout_size().1 - 1 - y),
+            false => (x.saturating_add(dx), y),
+        }
+    }
+
+    /// Get the logical position of a point on the device context as it
+    /// appears, the reverse of [`WxBackend::device_position`].
+    fn logical_position(
+        &self,
+        (x, y): plotters_backend::BackendCoord,
+    ) -> plotters_backend::BackendCoord {
+        let (dx, dy) = self.offset.get();
+        match self.flip_y.get() {
+            true => (x - dx, self.layout_size().1 - 1 - y - dy),
+            false => (x - dx, y - dy),
+        }
+    }
+
+    /// Get the position of a point in the logical coordinates of the device
+    /// context, where y increases downward from the offset, see
+    /// [`WxBackend::set_flip_y`].
+    ///
+    /// The device context has no flipped orientation, so every primitive
+    /// flips its coordinates with this.
+    fn context_position(
+        &self,
+        point: plotters_backend::BackendCoord,
+    ) -> plotters_backend::BackendCoord {
+        let (x, y) = clamp_coord(point);
+        match self.flip_y.get() {
+            true => (x, self.layout_size().1 - 1 - y),
+            false => (x, y),
+        }
+    }
+
+    /// Get the rectangle of the device context covering the rectangle of
+    /// `width` x `height` pixels from `(x, y)`, see
+    /// [`WxBackend::context_position`].
+    fn context_rect(
+        &self,
+        (x, y, width, height): (i32, i32, i32, i32),
+    ) -> (i32, i32, i32, i32) {
+        match self.flip_y.get() {
+            true => (x, self.layout_size().1 - y - height, width, height),
+            false => (x, y, width, height),
+        }
+    }
+
+    /// Set the pen of the device context, unless it is already set.
+    fn apply_pen(&self, pen: PenKey) {
+        if self.pen.get() != Some(pen) {
+            let ([r, g, b, a], width, style) = pen;
+            self.context
+                .set_pen(wx::Colour::new(r, g, b, a), width, style);
+            self.pen.set(Some(pen));
+        }
+    }
+
+    /// Set the brush of the device context, unless it is already set.
+    fn apply_brush(&self, brush: BrushKey) {
+        if self.brush.get() != Some(brush) {
+            let ([r, g, b, a], style) = brush;
+            self.context.set_brush(wx::Colour::new(r, g, b, a), style);
+            self.brush.set(Some(brush));
+        }
+    }
+
+    /// Set pen from plotters style.
+    fn set_pen_style<S: plotters_backend::BackendStyle>(&self, style: &S) {
+        let (_, width, pen_style, _) = style_to_wx(style, false);
+        let rgba = convert_rgba(self.transform_color(style.color()));
+        self.apply_pen((rgba, width, pen_style));
+    }
+
+    /// Returns `true` if strokes with a plotters style draw nothing, i.e. they
+    /// have a zero width or a fully transparent color.
+    ///
+    /// wxWidgets draws a zero-width pen as a one-pixel line on some
+    /// platforms, and ignores the alpha of pens on others, so such strokes,
+    /// e.g. hidden grid lines, must not reach the device context.
+    fn is_invisible_stroke<S: plotters_backend::BackendStyle>(
+        &self,
+        style: &S,
+    ) -> bool {
+        let [_, _, _, alpha] =
+            convert_rgba(self.transform_color(style.color()));
+        style.stroke_width() == 0 || alpha == 0
+    }
+
+    /// Set pen from plotters style, with the pen style of lines and paths.
+    fn set_line_pen_style<S: plotters_backend::BackendStyle>(&self, style: &S) {
+        let (_, width, _, _) = style_to_wx(style, false);
+        let rgba = convert_rgba(self.transform_color(style.color()));
+        self.apply_pen((rgba, width, self.line_style.get()));
+    }
+
+    /// Draw a horizontal or vertical line snapped to device pixels, with a
+    /// width of a whole number of device pixels, see
+    /// [`WxBackend::set_pixel_snap`].
+    fn draw_snapped_line<S: plotters_backend::BackendStyle>(
+        &self,
+        (x1, y1): plotters_backend::BackendCoord,
+        (x2, y2): plotters_backend::BackendCoord,
+        style: &S,
+    ) {
+        self.draw_unscaled(|(scale_x, scale_y)| {
+            // the width is across the line
+            let across = if y1 == y2 { scale_y } else { scale_x };
+            let width = (style.stroke_width() as f64 * across).round().max(1.0);
+            let rgba = convert_rgba(self.transform_color(style.color()));
+            self.apply_pen((rgba, width as i32, self.line_style.get()));
+            let pixel = |p: i32, scale: f64| (p as f64 * scale).round() as i32;
+            self.context.draw_line(
+                pixel(x1, scale_x),
+                pixel(y1, scale_y),
+                pixel(x2, scale_x),
+                pixel(y2, scale_y),
+            );
+        });
+    }
+
+    /// Set brush from plotters style.
+    fn set_brush_style(
+        &self,
+        fill: bool,
+        color: plotters_backend::BackendColor,
+    ) {
+        let style = match fill {
+            true => wx::BrushStyle::Solid,
+            false => wx::BrushStyle::Transparent,
+        };
+        let rgba = convert_rgba(self.transform_color(color));
+        self.apply_brush((rgba, style));
+    }
+
+    /// Set brush from plotters color, with the fill pattern of polygons and
+    /// rectangles.
+    fn set_fill_brush_style(&self, color: plotters_backend::BackendColor) {
+        let rgba = convert_rgba(self.transform_color(color));
+        self.apply_brush((rgba, self.fill_pattern.get().brush_style()));
+    }
+
+    /// Draw a text rotated clockwise by `degrees` around its anchor.
+    ///
+    /// The anchor of `style` is relative to the text itself, i.e. it is
+    /// rotated with the text. The transform of `style` is ignored.
+    fn draw_text_at_angle<TStyle: plotters_backend::BackendTextStyle>(
+        &self,
+        text: &str,
+        style: &TStyle,
+        pos: plotters_backend::BackendCoord,
+        degrees: f64,
+    ) -> Result<(), DrawingErrorKind<Error>> {
         self.set_font_style(style).map_err(|e| {
             plotters_backend::DrawingErrorKind::FontError(Box::new(Error(e)))
         })?;
+        let Pos { h_pos, v_pos } = style.anchor();
+        let (runs, (width, height)) = self.layout_text(text, h_pos);
+        // text is drawn upright, whatever the orientation of the vertical
+        // axis
+        let (x, y) = self.context_position(pos);
+        let color = convert_rgba(self.transform_color(style.color()));
+
+        // plotters convention is that anchor position is relative to
+        // character's point of view
+        let dx = match h_pos {
+            HPos::Left => 0,
+            HPos::Center => -width / 2,
+            HPos::Right => -width,
+        };
+        let dy = match v_pos {
+            VPos::Top => 0,
+            VPos::Center => -height / 2,
+            VPos::Bottom => -height,
+        };
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        let rotate = |dx: i32, dy: i32| {
+            let (dx, dy) = (dx as f64, dy as f64);
+            (
+                (dx * cos - dy * sin).round() as i32,
+                (dx * sin + dy * cos).round() as i32,
+            )
+        };
+
+        for (run, (offset_x, offset_y)) in runs {
+            let (dx, dy) = rotate(dx + offset_x, dy + offset_y);
+            if color[3] < 255 || self.text_coverage_blend.get() {
+                // device contexts draw text opaque, and may smooth its edges
+                // against the text background
+                self.draw_translucent_text(
+                    run,
+                    (x + dx, y + dy),
+                    degrees,
+                    color,
+                )
+                .map_err(drawing_error)?;
+            } else if degrees == 0.0 {
+                self.context.draw_text(run, x + dx, y + dy);
+            } else {
+                // wxwidgets rotates counterclockwise
+                self.context
+                    .draw_rotated_text(run, x + dx, y + dy, -degrees);
+            }
+        }
+        self.underline.set(false);
+        Ok(())
+    }
+
+    /// Draw a text blended with the pixels under it, rotated clockwise by
+    /// `degrees` around `pos`, its upper-left corner.
+    ///
+    /// The text is drawn in white on black on an off-screen bitmap with the
+    /// current font, and the gray levels, i.e. the glyph coverage, are
+    /// turned into the alpha of the color. The text background is never
+    /// drawn.
+    fn draw_translucent_text(
+        &self,
+        text: &str,
+        (x, y): plotters_backend::BackendCoord,
+        degrees: f64,
+        [r, g, b, a]: [u8; 4],
+    ) -> Result<(), ErrorInner> {
+        let Some(key) = self.font.borrow().clone() else {
+            return Ok(());
+        };
+        // bounding box of the rotated text, relative to `pos`
         let (width, height) = self.context.get_text_extent(text);
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        let corners = [(0, 0), (width, 0), (0, height), (width, height)]
+            .map(|(cx, cy)| (cx as f64, cy as f64))
+            .map(|(cx, cy)| (cx * cos - cy * sin, cx * sin + cy * cos));
+        let min_x = corners.iter().map(|c| c.0).fold(f64::MAX, f64::min);
+        let max_x = corners.iter().map(|c| c.0).fold(f64::MIN, f64::max);
+        let min_y = corners.iter().map(|c| c.1).fold(f64::MAX, f64::min);
+        let max_y = corners.iter().map(|c| c.1).fold(f64::MIN, f64::max);
+        let (left, top) = (min_x.floor() as i32, min_y.floor() as i32);
+        let box_width = max_x.ceil() as i32 - left;
+        let box_height = max_y.ceil() as i32 - top;
+        if box_width <= 0 || box_height <= 0 {
+            return Ok(());
+        }
+
+        let mut bitmap = wx::Bitmap::new(box_width, box_height)
+            .ok_or_else(|| self.invalidate(ErrorInner::CreateBitmap))?;
+        let mut dc = wx::MemoryDC::new();
+        dc.select_object(&mut bitmap);
+        dc.set_background(wx::Colour::rgb(0, 0, 0));
+        dc.clear();
+        dc.set_background_mode(BackgroundMode::Transparent);
+        dc.set_text_foreground(wx::Colour::rgb(255, 255, 255));
+        if let Some(font) = self.fonts.borrow_mut().get(key) {
+            dc.set_font(font);
+        }
+        if degrees == 0.0 {
+            dc.draw_text(text, -left, -top);
+        } else {
+            dc.draw_rotated_text(text, -left, -top, -degrees);
+        }
+        dc.select_object(&mut wx::Bitmap::null_bitmap());
+
+        let coverage = bitmap
+            .get_rgba_data()
+            .ok_or_else(|| self.invalidate(ErrorInner::ReadBitmap))?;
+        let data: Vec<u8> = coverage
+            .chunks_exact(4)
+            .flat_map(|pixel| {
+                let level = pixel[0].max(pixel[1]).max(pixel[2]);
+                let alpha = (level as u32 * a as u32 + 127) / 255;
+                [r, g, b, alpha as u8]
+            })
+            .collect();
+        let bitmap =
+            wx::Bitmap::from_rgba(&data, box_width as u32, box_height as u32)
+                .ok_or_else(|| self.invalidate(ErrorInner::CreateBitmap))?;
+        let transparent = true;
+        self.context
+            .draw_bitmap(&bitmap, x + left, y + top, transparent);
+        Ok(())
+    }
+
+    /// Sets the font style from plotters BackendTextStyle.
+    ///
+    /// Note: text background information is not present in
+    /// plotters_backend::BackendTextStyle, but it can be controlled using
+    /// [`WxBackend::set_background_mode`] and
+    /// [`WxBackend::set_background_color`]
+    fn set_font_style<TStyle: plotters_backend::BackendTextStyle>(
+        &self,
+        style: &TStyle,
+    ) -> Result<(), ErrorInner> {
+        self.context
+            .set_text_background(self.context.get_background());
+        let color = convert_color(self.transform_color(style.color()));
+        self.context.set_text_foreground(color);
+        self.apply_font(self.font_key(style))
+    }
+
+    /// Get the key of the font of a plotters text style.
+    fn font_key<TStyle: plotters_backend::BackendTextStyle>(
+        &self,
+        style: &TStyle,
+    ) -> FontKey {
+        // plotters sizes are in pixels, wxWidgets sizes are in points
+        let point_size =
+            (style.size() * PLOTTERS_FONT_SCALE * 72.0 / self.dpi) as i32;
+        FontKey::new(
+            style.family(),
+            point_size,
+            style.style(),
+            self.bold.get(),
+            self.underline.get(),
+        )
+    }
+
+    /// Set the font of the device context, and remember it.
+    fn apply_font(&self, key: FontKey) -> Result<(), ErrorInner> {
+        let mut fonts = self.fonts.borrow_mut();
+        let font = fonts
+            .get(key.clone())
+            .ok_or_else(|| self.invalidate(ErrorInner::CreateFont))?;
+        self.context.set_font(font);
+        *self.font.borrow_mut() = Some(key);
+        Ok(())
+    }
+}
+
+impl WxBackend<'static, wx::AutoBufferedPaintDC> {
+    /// Creates a new `WxBackend` drawing on `panel`, from its paint handler.
+    ///
+    /// This creates the `wxdragon::AutoBufferedPaintDC` of the paint event,
+    /// which the backend owns, with the settings of [`WxBackend::new`]. The
+    /// buffer of the device context is copied to the panel when the backend
+    /// is dropped, so create the backend in the paint handler, and drop it
+    /// before the handler returns: do not keep it for the next paint event.
+    ///
+    /// A buffered paint device context requires the background style of the
+    /// panel to be `wxdragon::BackgroundStyle::Paint`, which this sets. Set it
+    /// when creating the panel too, so that wxWidgets does not erase the
+    /// background before the first paint event.
+    ///
+    /// ```no_run
+    /// # use plotters::prelude::*;
+    /// # use plotters_wxdragon::WxBackend;
+    /// # use wxdragon::{self as wx, WindowEvents};
+    /// # fn setup(panel: wx::Panel) {
+    /// panel.on_paint(move |_event| {
+    ///     let backend = WxBackend::from_panel(&panel).into_drawing_area();
+    ///     backend.fill(&WHITE).expect("fill");
+    ///     backend.present().expect("present");
+    /// });
+    /// # }
+    /// ```
+    pub fn from_panel(
+        panel: &wx::Panel,
+    ) -> WxBackend<'static, wx::AutoBufferedPaintDC> {
+        panel.set_background_style(wx::BackgroundStyle::Paint);
+        let context = wx::AutoBufferedPaintDC::new(panel);
+        let (_, dpi) = device_dpi(&context);
+        let config = BackendConfig {
+            dpi: dpi as f64,
+            ..BackendConfig::default()
+        };
+        WxBackend::from_config(
+            ContextRef::Owned(context),
+            &config,
+            true,
+            FontCacheRef::default(),
+        )
+    }
+}
+
+/// Convert a plotters style to the settings of a wxWidgets pen and brush.
+///
+/// This is useful to mirror the style of a series in native widgets, e.g. a
+/// settings dialog previewing a series style. For a plotters `ShapeStyle`,
+/// pass its `filled` field as `filled`. Returns the color, the pen width, the
+/// pen style and the brush style, as used by [`WxBackend`]: the pen and the
+/// brush have the same color, and the brush is transparent unless `filled`.
+///
+/// Plotters stroke widths are whole pixels, so the pen width is exact. Lines
+/// are scaled with the device context, e.g. by [`WxBackend::set_scale`], so a
+/// width of 1 is 2 device pixels wide at a scale of 2.
+pub fn style_to_wx<S: plotters_backend::BackendStyle>(
+    style: &S,
+    filled: bool,
+) -> (wx::Colour, i32, wx::PenStyle, wx::BrushStyle) {
+    let color = convert_color(style.color());
+    let width = style.stroke_width() as i32;
+    // plotters styles have no dash information
+    let pen_style = wx::PenStyle::Solid;
+    let brush_style = match filled {
+        true => wx::BrushStyle::Solid,
+        false => wx::BrushStyle::Transparent,
+    };
+    (color, width, pen_style, brush_style)
+}
+
+/// Convert color from plotters to wx
+fn convert_color(color: plotters_backend::BackendColor) -> wx::Colour {
+    let [r, g, b, a] = convert_rgba(color);
+    wx::Colour::new(r, g, b, a)
+}
+
+/// Convert color from plotters to RGBA bytes
+fn convert_rgba(color: plotters_backend::BackendColor) -> [u8; 4] {
+    let BackendColor { alpha, rgb } = color;
+    let (r, g, b) = rgb;
+    [r, g, b, (alpha.clamp(0.0, 1.0) * 255.0).round() as u8]
+}
+
+impl<'context, C> DrawingBackend for WxBackend<'context, C>
+where
+    C: DeviceContext,
+{
+    type ErrorType = Error;
+    fn get_size(&self) -> (u32, u32) {
+        let (width, height) = self.layout_size();
+        (width as u32, height as u32)
+    }
+
+    fn ensure_prepared(
+        &mut self,
+    ) -> Result<(), plotters_backend::DrawingErrorKind<Self::ErrorType>> {
+        if !self.prepared.replace(true) && self.clear_each_frame.get() {
+            self.clear();
+        }
+        Ok(())
+    }
+
+    fn present(
+        &mut self,
+    ) -> Result<(), plotters_backend::DrawingErrorKind<Self::ErrorType>> {
+        self.flush()?;
+        self.prepared.set(false);
+        // the device context may need finalizing, see `set_present_hook`
+        if let Some(hook) = self.present_hook.get_mut() {
+            hook(&*self.context);
+        }
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: plotters_backend::BackendCoord,
+        color: plotters_backend::BackendColor,
+    ) -> Result<(), plotters_backend::DrawingErrorKind<Self::ErrorType>> {
+        if !self.valid.get() {
+            return Ok(());
+        }
+        let size = self.logical_size();
+        self.pixels.borrow_mut().set(
+            size,
+            self.device_position(point),
+            convert_rgba(self.transform_color(color)),
+        );
+        Ok(())
+    }
+
+    fn draw_line<S: plotters_backend::BackendStyle>(
+        &mut self,
+        from: plotters_backend::BackendCoord,
+        to: plotters_backend::BackendCoord,
+        style: &S,
+    ) -> Result<(), plotters_backend::DrawingErrorKind<Self::ErrorType>> {
+        if !self.begin_draw()? || self.is_invisible_stroke(style) {
+            return Ok(());
+        }
+        self.set_line_pen_style(style);
+        let (x1, y1) = self.context_position(from);
+        let (x2, y2) = self.context_position(to);
+        if (x1 == x2 || y1 == y2) && self.snaps() {
+            self.draw_snapped_line((x1, y1), (x2, y2), style);
+            return Ok(());
+        }
+        self.context.draw_line(x1, y1, x2, y2);
+        Ok(())
+    }
+
+    fn draw_path<
+        S: plotters_backend::BackendStyle,
+        I: IntoIterator<Item = plotters_backend::BackendCoord>,
+    >(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), plotters_backend::DrawingErrorKind<Self::ErrorType>> {
+        if !self.begin_draw()? || self.is_invisible_stroke(style) {
+            return Ok(());
+        }
+        self.set_line_pen_style(style);
+        let points: Vec<wx::dc::Point> = path
+            .into_iter()
+            .map(|point| self.context_position(point))
+            .map(|(x, y)| wx::dc::Point::new(x, y))
+            .collect();
+        // wxWidgets behavior for degenerate paths is platform-dependent
+        match points.as_slice() {
+            [] => {}
+            [point] => self.context.draw_point(point.x, point.y),
+            _ => {
+                let x_offset = 0;
+                let y_offset = 0;
+                self.context.draw_lines(&points[..], x_offset, y_offset);
+            }
+        }
+        Ok(())
+    }
+
+    fn draw_circle<S: plotters_backend::BackendStyle>(
+        &mut self,
+        center: plotters_backend::BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), plotters_backend::DrawingErrorKind<Self::ErrorType>> {
+        if !self.begin_draw()? {
+            return Ok(());
+        }
+        self.set_shape_pen_style(style, fill);
+        self.set_brush_style(fill, style.color());
+        let (x, y) = self.context_position(center);
+        let radius = radius.min(COORD_LIMIT as u32) as i32;
+        self.context.draw_circle(x, y, radius);
+        Ok(())
+    }
+
+    fn draw_rect<S: plotters_backend::BackendStyle>(
+        &mut self,
+        upper_left: plotters_backend::BackendCoord,
+        bottom_right: plotters_backend::BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), plotters_backend::DrawingErrorKind<Self::ErrorType>> {
+        if !self.begin_draw()? || (!fill && self.is_invisible_stroke(style)) {
+            return Ok(());
+        }
+        // plotters may pass the corners in any order
+        let (x1, y1, width, height) = rect_bounds(upper_left, bottom_right);
+        let (x2, y2) = (x1 + width - 1, y1 + height - 1);
+        let (upper_left, bottom_right) = ((x1, y1), (x2, y2));
+        if fill {
+            let stipple = self.stipple.borrow_mut().take();
+            if let Some(bitmap) = stipple {
+                self.fill_rect_with_stipple(upper_left, bottom_right, &bitmap);
+                return Ok(());
+            }
+        }
+        let snap = fill
+            && self.outline.get().is_none()
+            && self.outline_style.get() == wx::PenStyle::Solid
+            && self.snaps();
+        // the bottom-right corner is excluded
+        let (x, y, width, height) =
+            self.context_rect((x1, y1, width - 1, height - 1));
+        self.set_shape_pen_style(style, fill);
+        if fill {
+            self.set_fill_brush_style(style.color());
+        } else {
+            self.set_brush_style(fill, style.color());
+        }
+        if snap {
+            // round the edges, rather than the position and the size
+            self.draw_unscaled(|(scale_x, scale_y)| {
+                let edge =
+                    |p: i32, scale: f64| (p as f64 * scale).round() as i32;
+                let (left, right) =
+                    (edge(x, scale_x), edge(x + width, scale_x));
+                let (top, bottom) =
+                    (edge(y, scale_y), edge(y + height, scale_y));
+                self.context.draw_rectangle(
+                    left,
+                    top,
+                    right - left,
+                    bottom - top,
+                );
+            });
+            return Ok(());
+        }
+        self.context.draw_rectangle(x, y, width, height);
+        Ok(())
+    }
+
+    fn fill_polygon<
+        S: plotters_backend::BackendStyle,
+        I: IntoIterator<Item = plotters_backend::BackendCoord>,
+    >(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), plotters_backend::DrawingErrorKind<Self::ErrorType>> {
+        if !self.begin_draw()? {
+            return Ok(());
+        }
+        self.set_shape_pen_style(style, true);
+        self.set_fill_brush_style(style.color());
+        let points: Vec<wx::dc::Point> = vert
+            .into_iter()
+            .map(|point| self.context_position(point))
+            .map(|(x, y)| wx::dc::Point::new(x, y))
+            .collect();
+        // wxWidgets behavior for degenerate polygons is platform-dependent,
+        // and a polygon of fewer than two points has nothing to fill
+        if points.len() < 2 {
+            return Ok(());
+        }
+        let x_offset = 0;
+        let y_offset = 0;
+        let fill_mode = wx::dc::PolygonFillMode::OddEven;
+        self.context
+            .draw_polygon(&points[..], x_offset, y_offset, fill_mode);
+        Ok(())
+    }
+
+    fn draw_text<TStyle: plotters_backend::BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &TStyle,
+        pos: plotters_backend::BackendCoord,
+    ) -> Result<(), plotters_backend::DrawingErrorKind<Self::ErrorType>> {
+        if !self.begin_draw()? {
+            return Ok(());
+        }
+        // plotters rotates clockwise
+        let degrees = match style.transform() {
+            FontTransform::None => 0.0,
+            FontTransform::Rotate90 => 90.0,
+            FontTransform::Rotate180 => 180.0,
+            FontTransform::Rotate270 => 270.0,
+        };
+        self.draw_text_at_angle(text, style, pos, degrees)
+    }
+
+    fn estimate_text_size<TStyle: plotters_backend::BackendTextStyle>(
+        &self,
+        text: &str,
+        style: &TStyle,
+    ) -> Result<(u32, u32), plotters_backend::DrawingErrorKind<Self::ErrorType>>
+    {
+        if !self.valid.get() {
+            return Err(drawing_error(ErrorInner::Invalid));
+        }
+        // measure with the font of `style`, then set the previous font back,
+        // so that measuring does not change the state of the device context
+        let font_error = |e| {
+            plotters_backend::DrawingErrorKind::FontError(Box::new(Error(e)))
+        };
+        let key = self.font_key(style);
+        let previous = self.font.borrow().clone();
+        let (_, (width, height)) = if previous.as_ref() == Some(&key) {
+            self.layout_text(text, HPos::Left)
+        } else {
+            self.apply_font(key).map_err(font_error)?;
+            let layout = self.layout_text(text, HPos::Left);
+            if let Some(previous) = previous {
+                self.apply_font(previous).map_err(font_error)?;
+            }
+            layout
+        };
+        // size of the bounding box of the rotated text, as plotters uses it
+        // to lay out rotated labels
+        let (width, height) = match style.transform() {
+            FontTransform::None | FontTransform::Rotate180 => (width, height),
+            FontTransform::Rotate90 | FontTransform::Rotate270 => {
+                (height, width)
+            }
+        };
         Ok((width as u32, height as u32))
     }
 
@@ -545,18 +2474,44 @@ where
         (iw, ih): (u32, u32),
         src: &[u8],
     ) -> Result<(), plotters_backend::DrawingErrorKind<Self::ErrorType>> {
-        let (x, y) = pos;
+        if !self.begin_draw()? {
+            return Ok(());
+        }
+        let (x, y) = self.context_position(pos);
         let bitmap = wx::Bitmap::from_rgba(src, iw, ih).ok_or_else(|| {
             plotters_backend::DrawingErrorKind::FontError(Box::new(Error(
-                ErrorInner::CreateBitmap,
+                self.invalidate(ErrorInner::CreateBitmap),
             )))
         })?;
-        let transparent = false; // FIXME
+        // honor the alpha channel, e.g. for overlays with a transparent
+        // background
+        let transparent = true;
         self.context.draw_bitmap(&bitmap, x, y, transparent);
         Ok(())
     }
 }
 
+impl<'context, C> Drop for WxBackend<'context, C>
+where
+    C: DeviceContext,
+{
+    fn drop(&mut self) {
+        // Make sure pending pixels are drawn, even if the user did not call
+        // `present()`. Errors cannot be reported here.
+        let _ = self.flush_pixels();
+        // Draw in absolute coordinates again on the device context, which
+        // may outlive the backend.
+        if self.valid.get() && self.offset.get() != (0, 0) {
+            self.context.set_device_origin(0, 0);
+        }
+    }
+}
+
+/// Wrap an internal error for plotters.
+fn drawing_error(e: ErrorInner) -> DrawingErrorKind<Error> {
+    DrawingErrorKind::DrawingError(Error(e))
+}
+
 /// Represents an error when drawing on a [`WxBackend`].
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
@@ -569,4 +2524,29 @@ enum ErrorInner {
     CreateFont,
     #[error("failed to create bitmap")]
     CreateBitmap,
+    #[error("failed to add bitmap to image list")]
+    AddToImageList,
+    #[error("a previous drawing operation failed, the frame is incomplete")]
+    Invalid,
+    #[error("mask size does not match image size")]
+    MaskSize,
+    #[error("failed to read the pixels of a bitmap")]
+    ReadBitmap,
+    #[error("buffer size does not match image size")]
+    BufferSize,
+    #[error("failed to open the clipboard")]
+    OpenClipboard,
+    #[error("failed to copy the plot to the clipboard")]
+    SetClipboard,
+    #[error("failed to draw the plot")]
+    Draw(#[source] Box<dyn std::error::Error + Send + Sync>),
+    #[cfg(feature = "export")]
+    #[error("failed to save image")]
+    SaveImage(#[source] image::ImageError),
+    #[cfg(feature = "export")]
+    #[error("failed to encode PNG image")]
+    EncodePng(#[source] png::EncodingError),
+    #[cfg(feature = "export")]
+    #[error("unsupported image format: {0:?}")]
+    UnsupportedFormat(String),
 }
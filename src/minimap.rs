@@ -0,0 +1,96 @@
+//! Drawing of a minimap, a scaled-down overview of a zoomed plot.
+
+use std::ops::Range;
+
+use plotters_backend::{
+    BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind,
+};
+use wxdragon::{self as wx, DeviceContext};
+
+use crate::render::render_supersampled;
+use crate::{CoordMapper, Error, WxBackend};
+
+impl<'context, C> WxBackend<'context, C>
+where
+    C: DeviceContext,
+{
+    /// Draw a minimap: a scaled-down copy of the whole plot in an inset,
+    /// with a rectangle showing the part of the plot currently displayed.
+    ///
+    /// The inset fills the rectangle between `upper_left` and
+    /// `bottom_right`, bounds included, e.g. in a corner of a zoomed plot.
+    /// `draw_fn` draws the plot as it would on the whole backend, and
+    /// receives the data ranges `full_ranges`: it draws on an off-screen
+    /// backend with the size of this backend, see
+    /// [`DrawingBackend::get_size`], scaled down to the inset with
+    /// [`WxBackend::set_scale`]. It returns the pixel area covered by the
+    /// data ranges, as the drawing function of an
+    /// [`InteractivePlotPanel`](crate::InteractivePlotPanel), i.e.
+    /// `chart.plotting_area().get_pixel_range()` for a plotters chart.
+    ///
+    /// The rectangle of the data ranges `current_ranges`, e.g. those of the
+    /// [`Viewport`](crate::Viewport) of the zoomed plot, is then drawn with
+    /// the color and stroke width of `style`, and clipped to the inset. See
+    /// the `minimap` example.
+    pub fn draw_minimap<S, F, E>(
+        &mut self,
+        (upper_left, bottom_right): (BackendCoord, BackendCoord),
+        full_ranges: (Range<f64>, Range<f64>),
+        (x_current, y_current): (Range<f64>, Range<f64>),
+        style: &S,
+        draw_fn: F,
+    ) -> Result<(), DrawingErrorKind<Error>>
+    where
+        S: BackendStyle,
+        F: FnOnce(
+            WxBackend<'_, wx::MemoryDC>,
+            Range<f64>,
+            Range<f64>,
+        ) -> Result<(Range<i32>, Range<i32>), E>,
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        let (x1, x2) = (
+            upper_left.0.min(bottom_right.0),
+            upper_left.0.max(bottom_right.0),
+        );
+        let (y1, y2) = (
+            upper_left.1.min(bottom_right.1),
+            upper_left.1.max(bottom_right.1),
+        );
+        let (width, height) = ((x2 - x1 + 1) as u32, (y2 - y1 + 1) as u32);
+        let (full_width, full_height) = self.get_size();
+        if full_width == 0 || full_height == 0 {
+            return Ok(());
+        }
+        let scale_x = width as f64 / full_width as f64;
+        let scale_y = height as f64 / full_height as f64;
+
+        let (x_full, y_full) = full_ranges;
+        let mut pixel_area = None;
+        let data = render_supersampled(width, height, 1, true, |backend| {
+            backend.set_scale(scale_x, scale_y)?;
+            let area = draw_fn(backend, x_full.clone(), y_full.clone())
+                .map_err(Into::into)?;
+            pixel_area = Some(area);
+            Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+        })
+        .map_err(DrawingErrorKind::DrawingError)?;
+        self.blit_bitmap((x1, y1), (width, height), &data)?;
+
+        let Some(pixel_area) = pixel_area else {
+            return Ok(());
+        };
+        let mapper = CoordMapper::new(pixel_area, x_full, y_full);
+        // from the pixels of the whole plot to those of the inset
+        let to_inset = |(x, y): BackendCoord| {
+            (
+                (x1 + (x as f64 * scale_x).round() as i32).clamp(x1, x2),
+                (y1 + (y as f64 * scale_y).round() as i32).clamp(y1, y2),
+            )
+        };
+        let corner1 =
+            to_inset(mapper.to_pixel((x_current.start, y_current.start)));
+        let corner2 = to_inset(mapper.to_pixel((x_current.end, y_current.end)));
+        self.draw_rect(corner1, corner2, style, false)
+    }
+}
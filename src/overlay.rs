@@ -0,0 +1,110 @@
+//! Transient graphics drawn over a plot, and erased without redrawing it.
+
+use plotters_backend::{BackendColor, BackendCoord, DrawingErrorKind};
+use wxdragon::DeviceContext;
+
+use crate::{Error, WxBackend};
+
+/// Transient lines and rectangles drawn over a plot, e.g. a crosshair
+/// following the mouse, or a rubber band selection.
+///
+/// Redrawing the whole plot on every mouse move is wasteful. An overlay draws
+/// its shapes with [`WxBackend::draw_xor_line`] and
+/// [`WxBackend::draw_xor_rect`] and records them, so that [`Overlay::clear`]
+/// erases them by drawing them again, which restores the plot below. The
+/// backend is typically created on a `wxdragon::ClientDC` of the plot panel
+/// with [`WxBackend::new_no_clear`], in a mouse event handler.
+///
+/// When the plot is redrawn, e.g. by a paint event, the shapes are gone:
+/// call [`Overlay::reset`] so that they are not erased twice.
+#[derive(Debug, Clone, Default)]
+pub struct Overlay {
+    shapes: Vec<(Shape, (u8, u8, u8))>,
+}
+
+/// A shape drawn on an [`Overlay`].
+#[derive(Debug, Clone, Copy)]
+enum Shape {
+    Line(BackendCoord, BackendCoord),
+    Rect(BackendCoord, BackendCoord),
+}
+
+impl Overlay {
+    /// Creates an empty overlay.
+    pub fn new() -> Overlay {
+        Overlay::default()
+    }
+
+    /// Returns `true` if no shape is drawn.
+    pub fn is_empty(&self) -> bool {
+        self.shapes.is_empty()
+    }
+
+    /// Draw a line over the plot, see [`WxBackend::draw_xor_line`].
+    pub fn draw_line<C: DeviceContext>(
+        &mut self,
+        backend: &WxBackend<'_, C>,
+        from: BackendCoord,
+        to: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        self.draw(backend, Shape::Line(from, to), color.rgb)
+    }
+
+    /// Draw the outline of a rectangle over the plot, see
+    /// [`WxBackend::draw_xor_rect`].
+    pub fn draw_rect<C: DeviceContext>(
+        &mut self,
+        backend: &WxBackend<'_, C>,
+        corner1: BackendCoord,
+        corner2: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        self.draw(backend, Shape::Rect(corner1, corner2), color.rgb)
+    }
+
+    /// Erase the shapes drawn since the last clear or reset, restoring the
+    /// plot below them.
+    pub fn clear<C: DeviceContext>(
+        &mut self,
+        backend: &WxBackend<'_, C>,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        // in reverse order, in case shapes overlap
+        while let Some((shape, rgb)) = self.shapes.pop() {
+            draw_shape(backend, shape, rgb)?;
+        }
+        Ok(())
+    }
+
+    /// Forget the shapes drawn, without erasing them, after the plot was
+    /// redrawn.
+    pub fn reset(&mut self) {
+        self.shapes.clear();
+    }
+
+    fn draw<C: DeviceContext>(
+        &mut self,
+        backend: &WxBackend<'_, C>,
+        shape: Shape,
+        rgb: (u8, u8, u8),
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        draw_shape(backend, shape, rgb)?;
+        self.shapes.push((shape, rgb));
+        Ok(())
+    }
+}
+
+/// XOR a shape with the pixels of a backend.
+fn draw_shape<C: DeviceContext>(
+    backend: &WxBackend<'_, C>,
+    shape: Shape,
+    rgb: (u8, u8, u8),
+) -> Result<(), DrawingErrorKind<Error>> {
+    let color = BackendColor { alpha: 1.0, rgb };
+    match shape {
+        Shape::Line(from, to) => backend.draw_xor_line(from, to, color),
+        Shape::Rect(corner1, corner2) => {
+            backend.draw_xor_rect(corner1, corner2, color)
+        }
+    }
+}
@@ -0,0 +1,47 @@
+//! Built-in palettes for [`WxBackend::set_color_quantization`].
+//!
+//! [`WxBackend::set_color_quantization`]: crate::WxBackend::set_color_quantization
+
+/// Four levels of gray, from black to white.
+pub const GRAYSCALE_4: [(u8, u8, u8); 4] =
+    [(0, 0, 0), (85, 85, 85), (170, 170, 170), (255, 255, 255)];
+
+/// The 16 colors of the IBM CGA text mode palette.
+pub const CGA: [(u8, u8, u8); 16] = [
+    (0x00, 0x00, 0x00),
+    (0x00, 0x00, 0xAA),
+    (0x00, 0xAA, 0x00),
+    (0x00, 0xAA, 0xAA),
+    (0xAA, 0x00, 0x00),
+    (0xAA, 0x00, 0xAA),
+    (0xAA, 0x55, 0x00),
+    (0xAA, 0xAA, 0xAA),
+    (0x55, 0x55, 0x55),
+    (0x55, 0x55, 0xFF),
+    (0x55, 0xFF, 0x55),
+    (0x55, 0xFF, 0xFF),
+    (0xFF, 0x55, 0x55),
+    (0xFF, 0x55, 0xFF),
+    (0xFF, 0xFF, 0x55),
+    (0xFF, 0xFF, 0xFF),
+];
+
+/// Find the color of `palette` nearest to `rgb`, in euclidean RGB distance.
+///
+/// Returns `rgb` unchanged if the palette is empty.
+pub(crate) fn nearest(
+    palette: &[(u8, u8, u8)],
+    rgb: (u8, u8, u8),
+) -> (u8, u8, u8) {
+    let distance = |(r, g, b): (u8, u8, u8)| {
+        let dr = r as i32 - rgb.0 as i32;
+        let dg = g as i32 - rgb.1 as i32;
+        let db = b as i32 - rgb.2 as i32;
+        dr * dr + dg * dg + db * db
+    };
+    palette
+        .iter()
+        .copied()
+        .min_by_key(|&color| distance(color))
+        .unwrap_or(rgb)
+}
@@ -0,0 +1,913 @@
+//! Panel widgets drawing a plot.
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::ops::Range;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use wxdragon::{self as wx, WindowEvents, WxWidget};
+
+use plotters_backend::BackendColor;
+
+#[cfg(feature = "export")]
+use crate::{Error, ErrorInner};
+use crate::{Overlay, Viewport, WxBackend, WxBackendState};
+
+/// Zoom factor of one notch of the mouse wheel.
+const WHEEL_ZOOM: f64 = 1.2;
+
+/// Color XORed with the plot to draw the zoom rectangle, which inverts it.
+const RUBBER_BAND_COLOR: BackendColor = BackendColor {
+    alpha: 1.0,
+    rgb: (255, 255, 255),
+};
+
+/// Error of a drawing function, or of a drawing operation of a panel.
+type PanelError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Get the position of the mouse from a mouse button or motion event, or
+/// `None` for other events.
+pub(crate) fn mouse_position(
+    event: &wx::WindowEventData,
+) -> Option<(i32, i32)> {
+    let position = match event {
+        wx::WindowEventData::MouseButton(event) => event.get_position(),
+        wx::WindowEventData::MouseMotion(event) => event.get_position(),
+        _ => None,
+    }?;
+    Some((position.x, position.y))
+}
+
+/// Callback receiving the errors of the event handlers of a panel, see
+/// [`PlotPanel::on_error`], shared between the panel and its handlers.
+#[derive(Clone, Default)]
+struct ErrorCallback(Rc<RefCell<Option<Rc<dyn Fn(PanelError)>>>>);
+
+impl ErrorCallback {
+    /// Replace the callback.
+    fn set<F>(&self, callback: F)
+    where
+        F: Fn(PanelError) + 'static,
+    {
+        *self.0.borrow_mut() = Some(Rc::new(callback));
+    }
+
+    /// Pass an error to the callback, if any.
+    fn report(&self, error: impl Into<PanelError>) {
+        // the callback may set another callback
+        let callback = self.0.borrow().clone();
+        if let Some(callback) = callback {
+            callback(error.into());
+        }
+    }
+}
+
+/// A `wxdragon::Panel` that draws a plot, and redraws it when resized.
+///
+/// This wires up the boilerplate of drawing on a panel: the paint handler
+/// creates a `wxdragon::AutoBufferedPaintDC` and a [`WxBackend`] on it, and
+/// calls the drawing function, and the size handler refreshes the panel.
+///
+/// The drawing function receives the backend by value, as the other drawing
+/// functions of this crate, so that it can call `into_drawing_area`. Pending
+/// drawing is committed when the backend is dropped, so the drawing function
+/// need not call `present`. Errors
+/// cannot be propagated from a paint handler: they are passed to the callback
+/// set with [`PlotPanel::on_error`], if any, and the panel shows the
+/// incomplete frame.
+///
+/// To change several settings of the plot at once, e.g. from a toolbar,
+/// call [`PlotPanel::freeze`] first and [`PlotPanel::thaw`] last: the plot is
+/// then redrawn once, instead of once per call to [`PlotPanel::refresh`].
+///
+/// `PlotPanel` dereferences to the underlying `wxdragon::Panel`, e.g. to add
+/// it to a sizer.
+///
+/// ```no_run
+/// use plotters::prelude::*;
+/// use plotters_wxdragon::PlotPanel;
+/// use wxdragon::{self as wx, WxWidget};
+///
+/// let _ = wx::main(|_| {
+///     let frame = wx::Frame::builder().with_title("y=x^2").build();
+///     let _panel = PlotPanel::new(&frame, |backend| {
+///         let root = backend.into_drawing_area();
+///         let mut chart = ChartBuilder::on(&root)
+///             .build_cartesian_2d(-1f32..1f32, 0f32..1f32)?;
+///         let points = (-50..=50).map(|x| x as f32 / 50.0).map(|x| (x, x * x));
+///         chart.draw_series(LineSeries::new(points, &RED))?;
+///         anyhow::Ok(())
+///     });
+///     frame.show(true);
+/// });
+/// ```
+#[derive(Clone)]
+pub struct PlotPanel {
+    panel: wx::Panel,
+    state: Rc<RefCell<PlotState>>,
+    errors: ErrorCallback,
+}
+
+/// State shared between the clones of a [`PlotPanel`].
+#[derive(Default)]
+struct PlotState {
+    /// Number of calls to [`PlotPanel::freeze`] not matched by a call to
+    /// [`PlotPanel::thaw`] yet.
+    freeze_count: u32,
+    /// Whether a refresh was requested while the panel was frozen.
+    pending: bool,
+    /// Identity of the data, see [`PlotPanel::set_content_hash`].
+    content_hash: Option<u64>,
+    /// Identity of the data when the plot was last refreshed.
+    refreshed_hash: Option<u64>,
+    /// Aspect ratio of the plot, see [`PlotPanel::with_aspect_ratio`].
+    aspect_ratio: Option<f64>,
+    /// Threshold and callback of slow frames, see
+    /// [`PlotPanel::on_slow_frame`].
+    slow_frame: Option<(Duration, Rc<dyn Fn(Duration)>)>,
+}
+
+impl PlotPanel {
+    /// Creates a new panel in `parent`, that draws with `draw_fn`.
+    pub fn new<W, F, E>(parent: &W, draw_fn: F) -> PlotPanel
+    where
+        W: WxWidget,
+        F: Fn(WxBackend<'_, wx::AutoBufferedPaintDC>) -> Result<(), E>
+            + 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        let panel = wx::PanelBuilder::new(parent).build();
+        panel.set_background_style(wx::BackgroundStyle::Paint);
+        let state = Rc::new(RefCell::new(PlotState::default()));
+        let errors = ErrorCallback::default();
+
+        let paint_state = state.clone();
+        let paint_errors = errors.clone();
+        panel.on_paint(move |_event| {
+            // the callback may set another callback
+            let slow = paint_state.borrow().slow_frame.clone();
+            let threshold = slow.as_ref().map_or(Duration::MAX, |(t, _)| *t);
+            let elapsed = slow_frame(threshold, || {
+                let dc = wx::AutoBufferedPaintDC::new(&panel);
+                let backend = WxBackend::new(&dc);
+                let aspect_ratio = paint_state.borrow().aspect_ratio;
+                let letterboxed: Result<(), PanelError> = match aspect_ratio {
+                    Some(ratio) => backend.letterbox(ratio).map_err(Into::into),
+                    None => Ok(()),
+                };
+                let result = letterboxed
+                    .and_then(|()| draw_fn(backend).map_err(Into::into));
+                if let Err(e) = result {
+                    paint_errors.report(e);
+                }
+                // the buffered drawing is presented in the timed frame
+                drop(dc);
+            });
+            if let (Some(elapsed), Some((_, callback))) = (elapsed, slow) {
+                callback(elapsed);
+            }
+        });
+
+        panel.on_size(move |_event| {
+            panel.refresh(true, None);
+        });
+
+        PlotPanel {
+            panel,
+            state,
+            errors,
+        }
+    }
+
+    /// Keep the plot at the aspect ratio `ratio`, i.e. its width divided by
+    /// its height, e.g. `1.0` for a square scatter plot, and return the
+    /// panel.
+    ///
+    /// The plot is drawn in the largest centered rectangle of the panel with
+    /// this ratio, recomputed when the panel is resized, and the margins
+    /// around it are left to the background color, see
+    /// [`WxBackend::letterbox`]. The drawing function lays out the plot on
+    /// the whole backend, as without this setting. A `ratio` which is not a
+    /// positive number stretches the plot to the panel again.
+    pub fn with_aspect_ratio(self, ratio: f64) -> PlotPanel {
+        let valid = ratio > 0.0 && ratio.is_finite();
+        self.state.borrow_mut().aspect_ratio = valid.then_some(ratio);
+        self.panel.refresh(true, None);
+        self
+    }
+
+    /// Redraw the plot, e.g. after the data changed.
+    ///
+    /// While the panel is frozen, see [`PlotPanel::freeze`], the plot is only
+    /// redrawn when the panel is thawed. Once a content hash is set, see
+    /// [`PlotPanel::set_content_hash`], the plot is not redrawn if the hash is
+    /// unchanged since the last refresh.
+    pub fn refresh(&self) {
+        let mut state = self.state.borrow_mut();
+        if state.content_hash.is_some()
+            && state.content_hash == state.refreshed_hash
+        {
+            return;
+        }
+        state.refreshed_hash = state.content_hash;
+        if state.freeze_count > 0 {
+            state.pending = true;
+            return;
+        }
+        drop(state);
+        self.panel.refresh(true, None);
+    }
+
+    /// Stop redrawing the plot until [`PlotPanel::thaw`] is called, e.g.
+    /// while updating several settings that each refresh the panel.
+    ///
+    /// This freezes the underlying window, so that it is not repainted, and
+    /// defers the calls to [`PlotPanel::refresh`]. Calls can be nested: the
+    /// panel is redrawn when every call to `freeze` is matched by a call to
+    /// `thaw`.
+    pub fn freeze(&self) {
+        self.state.borrow_mut().freeze_count += 1;
+        self.panel.freeze();
+    }
+
+    /// Redraw the plot again, after [`PlotPanel::freeze`].
+    ///
+    /// If the panel was refreshed while it was frozen, the plot is redrawn
+    /// once. Does nothing if the panel is not frozen.
+    pub fn thaw(&self) {
+        let mut state = self.state.borrow_mut();
+        if state.freeze_count == 0 {
+            return;
+        }
+        state.freeze_count -= 1;
+        let refresh =
+            state.freeze_count == 0 && std::mem::take(&mut state.pending);
+        drop(state);
+        self.panel.thaw();
+        if refresh {
+            self.panel.refresh(true, None);
+        }
+    }
+
+    /// Set a hash of the data drawn by the drawing function, so that
+    /// [`PlotPanel::refresh`] skips redrawing the plot when the data did not
+    /// change.
+    ///
+    /// This saves redraws in an idle dashboard, whose data source refreshes
+    /// the panel periodically, whether the data changed or not. The hash is
+    /// computed by the application, e.g. from a version number of the data,
+    /// and must change whenever the drawing changes. The panel is still
+    /// redrawn when it is resized or uncovered.
+    pub fn set_content_hash(&self, hash: u64) {
+        self.state.borrow_mut().content_hash = Some(hash);
+    }
+
+    /// Returns `true` if the panel is frozen, see [`PlotPanel::freeze`].
+    pub fn is_frozen(&self) -> bool {
+        self.state.borrow().freeze_count > 0
+    }
+
+    /// Call `callback` with the errors of the drawing function, e.g. to log
+    /// them or show them in a status bar.
+    ///
+    /// Errors are ignored until a callback is set, and a new callback replaces
+    /// the previous one.
+    pub fn on_error<F>(&self, callback: F)
+    where
+        F: Fn(PanelError) + 'static,
+    {
+        self.errors.set(callback);
+    }
+
+    /// Call `callback` with the duration of each frame that takes longer
+    /// than `threshold` to draw, e.g. to switch to a simpler rendering.
+    ///
+    /// The duration covers the whole paint handler: the creation of the
+    /// device context, the drawing function, and the commit of the pending
+    /// drawing when the backend is dropped. An application can react by
+    /// drawing fewer points, e.g. by decimating its series, and refreshing
+    /// the panel. A new callback replaces the previous one.
+    pub fn on_slow_frame<F>(&self, threshold: Duration, callback: F)
+    where
+        F: Fn(Duration) + 'static,
+    {
+        self.state.borrow_mut().slow_frame =
+            Some((threshold, Rc::new(callback)));
+    }
+}
+
+#[cfg(feature = "export")]
+impl PlotPanel {
+    /// Draw the plot at the current size of the panel, and save it to
+    /// `path`, in the format given by the extension of `path`.
+    ///
+    /// This is the backend of a "Save As" command. Only PNG images, with the
+    /// `.png` extension in any case, are supported, since wxdragon has no
+    /// vector device context to export to: other extensions return an error,
+    /// and nothing is saved. The plot is letterboxed as on the panel, see
+    /// [`PlotPanel::with_aspect_ratio`].
+    ///
+    /// The drawing function of the panel only draws on the paint device
+    /// context of the panel, so `draw_fn` draws the plot off-screen: write
+    /// the drawing function generic over the device context, and pass it to
+    /// both, as in the `copy_plot` example.
+    pub fn export<P, F, E>(&self, path: P, draw_fn: F) -> Result<(), Error>
+    where
+        P: AsRef<std::path::Path>,
+        F: FnOnce(WxBackend<'_, wx::MemoryDC>) -> Result<(), E>,
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        if extension != "png" {
+            return Err(ErrorInner::UnsupportedFormat(extension).into());
+        }
+        let size = self.panel.get_size();
+        let width = size.width.max(1) as u32;
+        let height = size.height.max(1) as u32;
+        let aspect_ratio = self.state.borrow().aspect_ratio;
+        crate::render_to_png(
+            path,
+            width,
+            height,
+            |backend| -> Result<(), PanelError> {
+                if let Some(ratio) = aspect_ratio {
+                    backend.letterbox(ratio)?;
+                }
+                draw_fn(backend).map_err(Into::into)
+            },
+        )
+    }
+}
+
+impl std::ops::Deref for PlotPanel {
+    type Target = wx::Panel;
+
+    fn deref(&self) -> &Self::Target {
+        &self.panel
+    }
+}
+
+/// A panel that draws a plot, which the user can zoom and pan with the
+/// mouse.
+///
+/// The panel owns a [`Viewport`], i.e. the data ranges shown by the plot:
+/// * the mouse wheel zooms around the cursor,
+/// * dragging with the left button pans,
+/// * dragging with the right button draws a rectangle, and zooms to it when
+///   the button is released, unless the rectangle is too small,
+/// * a right click, i.e. releasing the right button where it was pressed,
+///   shows the initial ranges again.
+///
+/// The drawing function receives the backend and the current data ranges,
+/// and returns the pixel area covered by these ranges, which is used to map
+/// mouse positions to data coordinates. For a plotters chart built with the
+/// given ranges, return `chart.plotting_area().get_pixel_range()`. Errors are
+/// reported as in [`PlotPanel`], including the errors of drawing the zoom
+/// rectangle, see [`InteractivePlotPanel::on_error`].
+///
+/// `InteractivePlotPanel` dereferences to the underlying `wxdragon::Panel`.
+#[derive(Clone)]
+pub struct InteractivePlotPanel {
+    panel: wx::Panel,
+    state: Rc<RefCell<InteractiveState>>,
+    errors: ErrorCallback,
+}
+
+/// State shared between the event handlers of an [`InteractivePlotPanel`].
+struct InteractiveState {
+    viewport: Viewport,
+    /// Pixel area of the data ranges, as returned by the last drawing.
+    pixel_area: (Range<i32>, Range<i32>),
+    /// Last mouse position of a drag in progress.
+    drag: Option<(i32, i32)>,
+    /// Start position of a zoom rectangle in progress.
+    zoom_start: Option<(i32, i32)>,
+    /// Zoom rectangle drawn over the plot.
+    overlay: Overlay,
+}
+
+impl InteractivePlotPanel {
+    /// Creates a new panel in `parent`, that draws the data ranges `x_range`
+    /// and `y_range` with `draw_fn`, until the user zooms or pans.
+    pub fn new<W, F, E>(
+        parent: &W,
+        x_range: Range<f64>,
+        y_range: Range<f64>,
+        draw_fn: F,
+    ) -> InteractivePlotPanel
+    where
+        W: WxWidget,
+        F: Fn(
+                WxBackend<'_, wx::AutoBufferedPaintDC>,
+                Range<f64>,
+                Range<f64>,
+            ) -> Result<(Range<i32>, Range<i32>), E>
+            + 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        let panel = wx::PanelBuilder::new(parent).build();
+        panel.set_background_style(wx::BackgroundStyle::Paint);
+        let state = Rc::new(RefCell::new(InteractiveState {
+            viewport: Viewport::new(x_range, y_range),
+            pixel_area: (0..0, 0..0),
+            drag: None,
+            zoom_start: None,
+            overlay: Overlay::new(),
+        }));
+        let errors = ErrorCallback::default();
+
+        let paint_state = state.clone();
+        let paint_errors = errors.clone();
+        panel.on_paint(move |_event| {
+            let dc = wx::AutoBufferedPaintDC::new(&panel);
+            let backend = WxBackend::new(&dc);
+            let (x_range, y_range) = {
+                let state = paint_state.borrow();
+                (state.viewport.x_range(), state.viewport.y_range())
+            };
+            let result = draw_fn(backend, x_range, y_range);
+            let mut state = paint_state.borrow_mut();
+            // the plot was redrawn over the zoom rectangle
+            state.overlay.reset();
+            match result {
+                Ok(pixel_area) => state.pixel_area = pixel_area,
+                Err(e) => {
+                    drop(state);
+                    paint_errors.report(e);
+                }
+            }
+        });
+
+        let wheel_state = state.clone();
+        panel.on_mouse_wheel(move |event| {
+            // wheel events are mouse button events for wxdragon
+            let wx::WindowEventData::MouseButton(event) = event else {
+                return;
+            };
+            let Some(position) = event.get_position() else {
+                return;
+            };
+            let delta = event.event.get_wheel_delta();
+            if delta == 0 {
+                return;
+            }
+            let notches =
+                event.event.get_wheel_rotation() as f64 / delta as f64;
+            let mut state = wheel_state.borrow_mut();
+            let InteractiveState {
+                viewport,
+                pixel_area,
+                ..
+            } = &mut *state;
+            viewport.zoom(
+                (position.x, position.y),
+                pixel_area,
+                WHEEL_ZOOM.powf(notches),
+            );
+            panel.refresh(false, None);
+        });
+
+        let down_state = state.clone();
+        panel.on_mouse_left_down(move |event| {
+            down_state.borrow_mut().drag = mouse_position(&event);
+        });
+
+        let up_state = state.clone();
+        panel.on_mouse_left_up(move |_event| {
+            up_state.borrow_mut().drag = None;
+        });
+
+        let right_down_state = state.clone();
+        panel.on_mouse_right_down(move |event| {
+            right_down_state.borrow_mut().zoom_start = mouse_position(&event);
+        });
+
+        let right_up_state = state.clone();
+        let right_up_errors = errors.clone();
+        panel.on_mouse_right_up(move |event| {
+            let mut state = right_up_state.borrow_mut();
+            let Some(start) = state.zoom_start.take() else {
+                return;
+            };
+            let dc = wx::ClientDC::new(&panel);
+            let backend = WxBackend::new_no_clear(&dc);
+            let result = state.overlay.clear(&backend);
+            let InteractiveState {
+                viewport,
+                pixel_area,
+                ..
+            } = &mut *state;
+            let zoomed = match mouse_position(&event) {
+                // a click without dragging resets the zoom
+                Some(end) if end == start => {
+                    viewport.reset();
+                    true
+                }
+                Some(end) => viewport.zoom_to_rect(start, end, pixel_area),
+                None => false,
+            };
+            drop(state);
+            if let Err(e) = result {
+                right_up_errors.report(e);
+            }
+            if zoomed {
+                panel.refresh(false, None);
+            }
+        });
+
+        let motion_state = state.clone();
+        let motion_errors = errors.clone();
+        panel.on_mouse_motion(move |event| {
+            let mut state = motion_state.borrow_mut();
+            if let (Some(start), Some(end)) =
+                (state.zoom_start, mouse_position(&event))
+            {
+                // move the zoom rectangle without redrawing the plot
+                let dc = wx::ClientDC::new(&panel);
+                let backend = WxBackend::new_no_clear(&dc);
+                let result = state.overlay.clear(&backend).and_then(|()| {
+                    state.overlay.draw_rect(
+                        &backend,
+                        start,
+                        end,
+                        RUBBER_BAND_COLOR,
+                    )
+                });
+                if let Err(e) = result {
+                    drop(state);
+                    motion_errors.report(e);
+                }
+                return;
+            }
+            let (Some((x0, y0)), Some((x, y))) =
+                (state.drag, mouse_position(&event))
+            else {
+                return;
+            };
+            let InteractiveState {
+                viewport,
+                pixel_area,
+                ..
+            } = &mut *state;
+            viewport.pan((x - x0, y - y0), pixel_area);
+            state.drag = Some((x, y));
+            panel.refresh(false, None);
+        });
+
+        panel.on_size(move |_event| {
+            panel.refresh(true, None);
+        });
+
+        InteractivePlotPanel {
+            panel,
+            state,
+            errors,
+        }
+    }
+
+    /// Get the data ranges currently shown.
+    pub fn viewport(&self) -> Viewport {
+        self.state.borrow().viewport.clone()
+    }
+
+    /// Redraw the plot, e.g. after the data changed.
+    pub fn refresh(&self) {
+        self.panel.refresh(true, None);
+    }
+
+    /// Call `callback` with the errors of the drawing function and of the
+    /// zoom rectangle, see [`PlotPanel::on_error`].
+    pub fn on_error<F>(&self, callback: F)
+    where
+        F: Fn(PanelError) + 'static,
+    {
+        self.errors.set(callback);
+    }
+}
+
+impl std::ops::Deref for InteractivePlotPanel {
+    type Target = wx::Panel;
+
+    fn deref(&self) -> &Self::Target {
+        &self.panel
+    }
+}
+
+/// A panel that redraws a plot at a fixed rate, e.g. for live data.
+///
+/// The panel owns a `wxdragon::Timer`, which refreshes the panel every
+/// `interval` while the animation runs. The drawing function receives the
+/// backend, the index of the frame, counted from 0, and the time elapsed
+/// since the panel was created, e.g. to compute the phase of a moving curve.
+/// Errors are reported as in [`PlotPanel`].
+///
+/// If drawing a frame takes longer than the interval, ticks that come before
+/// the previous frame was painted are skipped, so that paint events do not
+/// pile up and the panel stays responsive.
+///
+/// Each frame is drawn with a backend bound to the same
+/// [`WxBackendState`], so that fonts are created once for all frames.
+///
+/// The animation starts when the panel is created, and can be stopped and
+/// started again. `AnimatedPlotPanel` dereferences to the underlying
+/// `wxdragon::Panel`.
+#[derive(Clone)]
+pub struct AnimatedPlotPanel {
+    panel: wx::Panel,
+    timer: Rc<wx::Timer>,
+    interval: Duration,
+    errors: ErrorCallback,
+}
+
+/// State shared between the event handlers of an [`AnimatedPlotPanel`].
+struct AnimationState {
+    /// Index of the next frame.
+    frame: u64,
+    start: Instant,
+    /// Whether a refresh was requested and the frame is not painted yet.
+    pending: bool,
+}
+
+impl AnimatedPlotPanel {
+    /// Creates a new panel in `parent`, that draws with `draw_fn` every
+    /// `interval`.
+    pub fn new<W, F, E>(
+        parent: &W,
+        interval: Duration,
+        draw_fn: F,
+    ) -> AnimatedPlotPanel
+    where
+        W: WxWidget,
+        F: Fn(
+                WxBackend<'_, wx::AutoBufferedPaintDC>,
+                u64,
+                Duration,
+            ) -> Result<(), E>
+            + 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        let panel = wx::PanelBuilder::new(parent).build();
+        panel.set_background_style(wx::BackgroundStyle::Paint);
+        let state = Rc::new(RefCell::new(AnimationState {
+            frame: 0,
+            start: Instant::now(),
+            pending: false,
+        }));
+
+        let timer = Rc::new(wx::Timer::new(&panel));
+        let errors = ErrorCallback::default();
+
+        let paint_state = state.clone();
+        let paint_errors = errors.clone();
+        // the timer lives as long as the panel's paint handler
+        let paint_timer = timer.clone();
+        // fonts are kept from one frame to the next
+        let backend_state: RefCell<Option<WxBackendState>> = RefCell::new(None);
+        panel.on_paint(move |_event| {
+            let _ = &paint_timer;
+            let dc = wx::AutoBufferedPaintDC::new(&panel);
+            let mut backend_state = backend_state.borrow_mut();
+            let backend = backend_state
+                .get_or_insert_with(|| WxBackendState::new(&dc))
+                .bind(&dc);
+            let (frame, elapsed) = {
+                let mut state = paint_state.borrow_mut();
+                state.pending = false;
+                state.frame += 1;
+                (state.frame - 1, state.start.elapsed())
+            };
+            if let Err(e) = draw_fn(backend, frame, elapsed) {
+                paint_errors.report(e);
+            }
+        });
+
+        panel.on_size(move |_event| {
+            panel.refresh(true, None);
+        });
+
+        let tick_state = state.clone();
+        timer.on_tick(move |_event| {
+            let mut state = tick_state.borrow_mut();
+            if !state.pending {
+                state.pending = true;
+                panel.refresh(false, None);
+            }
+        });
+
+        let animated = AnimatedPlotPanel {
+            panel,
+            timer,
+            interval,
+            errors,
+        };
+        animated.start();
+        animated
+    }
+
+    /// Start the animation, or restart it after [`AnimatedPlotPanel::stop`].
+    pub fn start(&self) {
+        let milliseconds = self.interval.as_millis().clamp(1, i32::MAX as u128);
+        let one_shot = false;
+        self.timer.start(milliseconds as i32, one_shot);
+    }
+
+    /// Stop the animation, keeping the last frame on screen.
+    pub fn stop(&self) {
+        self.timer.stop();
+    }
+
+    /// Returns `true` if the animation is running.
+    pub fn is_running(&self) -> bool {
+        self.timer.is_running()
+    }
+
+    /// Call `callback` with the errors of the drawing function, see
+    /// [`PlotPanel::on_error`].
+    pub fn on_error<F>(&self, callback: F)
+    where
+        F: Fn(PanelError) + 'static,
+    {
+        self.errors.set(callback);
+    }
+}
+
+impl std::ops::Deref for AnimatedPlotPanel {
+    type Target = wx::Panel;
+
+    fn deref(&self) -> &Self::Target {
+        &self.panel
+    }
+}
+
+/// A panel that plots the last samples of a data stream, e.g. live
+/// telemetry.
+///
+/// The panel keeps the last `capacity` points pushed with
+/// [`StreamingPlotPanel::push`], oldest first. The drawing function receives
+/// the backend, the points, and the x range they span, so that the x axis
+/// scrolls with the data, e.g. to draw them with a plotters `LineSeries`.
+/// Errors are reported as in [`PlotPanel`].
+///
+/// Pushing a point refreshes the panel, but points pushed before the panel
+/// is painted are drawn together in a single frame, so that pushing at a
+/// high rate does not queue a paint event per point.
+///
+/// `StreamingPlotPanel` dereferences to the underlying `wxdragon::Panel`.
+#[derive(Clone)]
+pub struct StreamingPlotPanel {
+    panel: wx::Panel,
+    state: Rc<RefCell<StreamState>>,
+    errors: ErrorCallback,
+}
+
+/// State shared between a [`StreamingPlotPanel`] and its paint handler.
+struct StreamState {
+    points: VecDeque<(f64, f64)>,
+    capacity: usize,
+    /// Whether a refresh was requested and the points are not painted yet.
+    pending: bool,
+}
+
+impl StreamingPlotPanel {
+    /// Creates a new panel in `parent`, that keeps the last `capacity` points
+    /// and draws them with `draw_fn`.
+    pub fn new<W, F, E>(
+        parent: &W,
+        capacity: usize,
+        draw_fn: F,
+    ) -> StreamingPlotPanel
+    where
+        W: WxWidget,
+        F: Fn(
+                WxBackend<'_, wx::AutoBufferedPaintDC>,
+                &VecDeque<(f64, f64)>,
+                Range<f64>,
+            ) -> Result<(), E>
+            + 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        let panel = wx::PanelBuilder::new(parent).build();
+        panel.set_background_style(wx::BackgroundStyle::Paint);
+        let state = Rc::new(RefCell::new(StreamState {
+            points: VecDeque::with_capacity(capacity),
+            capacity,
+            pending: false,
+        }));
+        let errors = ErrorCallback::default();
+
+        let paint_state = state.clone();
+        let paint_errors = errors.clone();
+        panel.on_paint(move |_event| {
+            let dc = wx::AutoBufferedPaintDC::new(&panel);
+            let backend = WxBackend::new(&dc);
+            let mut state = paint_state.borrow_mut();
+            state.pending = false;
+            let x_range = stream_x_range(&state.points);
+            let result = draw_fn(backend, &state.points, x_range);
+            drop(state);
+            if let Err(e) = result {
+                paint_errors.report(e);
+            }
+        });
+
+        panel.on_size(move |_event| {
+            panel.refresh(true, None);
+        });
+
+        StreamingPlotPanel {
+            panel,
+            state,
+            errors,
+        }
+    }
+
+    /// Add a point, dropping the oldest one if the panel already holds
+    /// `capacity` points, and refresh the panel.
+    pub fn push(&self, point: (f64, f64)) {
+        let mut state = self.state.borrow_mut();
+        if state.capacity == 0 {
+            return;
+        }
+        while state.points.len() >= state.capacity {
+            state.points.pop_front();
+        }
+        state.points.push_back(point);
+        if !state.pending {
+            state.pending = true;
+            self.panel.refresh(false, None);
+        }
+    }
+
+    /// Remove all points, and refresh the panel.
+    pub fn clear(&self) {
+        self.state.borrow_mut().points.clear();
+        self.panel.refresh(false, None);
+    }
+
+    /// Get the number of points held.
+    pub fn len(&self) -> usize {
+        self.state.borrow().points.len()
+    }
+
+    /// Returns `true` if no point is held.
+    pub fn is_empty(&self) -> bool {
+        self.state.borrow().points.is_empty()
+    }
+
+    /// Call `callback` with the errors of the drawing function, see
+    /// [`PlotPanel::on_error`].
+    pub fn on_error<F>(&self, callback: F)
+    where
+        F: Fn(PanelError) + 'static,
+    {
+        self.errors.set(callback);
+    }
+}
+
+impl std::ops::Deref for StreamingPlotPanel {
+    type Target = wx::Panel;
+
+    fn deref(&self) -> &Self::Target {
+        &self.panel
+    }
+}
+
+/// Call `draw` and return the time it took, if longer than `threshold`.
+fn slow_frame<F: FnOnce()>(threshold: Duration, draw: F) -> Option<Duration> {
+    let start = Instant::now();
+    draw();
+    let elapsed = start.elapsed();
+    (elapsed > threshold).then_some(elapsed)
+}
+
+/// Range from the x of the oldest point to the x of the newest one, widened
+/// to a unit range around a single point, and `0.0..1.0` without points.
+fn stream_x_range(points: &VecDeque<(f64, f64)>) -> Range<f64> {
+    match (points.front(), points.back()) {
+        (Some(&(first, _)), Some(&(last, _))) if first != last => first..last,
+        (Some(&(x, _)), _) => x - 0.5..x + 0.5,
+        _ => 0.0..1.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::slow_frame;
+
+    #[test]
+    fn slow_frame_threshold() {
+        let sleep = || std::thread::sleep(Duration::from_millis(20));
+        let elapsed = slow_frame(Duration::from_millis(5), sleep);
+        assert!(elapsed.is_some_and(|e| e >= Duration::from_millis(20)));
+        assert_eq!(slow_frame(Duration::from_secs(10), sleep), None);
+        assert_eq!(slow_frame(Duration::MAX, || ()), None);
+    }
+}
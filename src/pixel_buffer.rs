@@ -0,0 +1,109 @@
+//! Off-screen RGBA buffer used to batch `draw_pixel` calls.
+
+/// An RGBA buffer covering the whole device context, that accumulates pixels
+/// until it is flushed to the device context as a single bitmap.
+#[derive(Debug, Default)]
+pub(crate) struct PixelBuffer {
+    width: i32,
+    height: i32,
+    data: Vec<u8>,
+    /// Bounding box `(x_min, y_min, x_max, y_max)` of the pixels set since
+    /// the last flush, bounds included.
+    dirty: Option<(i32, i32, i32, i32)>,
+}
+
+/// A rectangular region of RGBA pixels taken out of a [`PixelBuffer`].
+pub(crate) struct PixelRegion {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub data: Vec<u8>,
+}
+
+impl PixelBuffer {
+    /// Set a pixel, compositing it over any pixel previously set at the same
+    /// position since the last flush.
+    ///
+    /// The buffer is (re)allocated to `size` if needed. Pixels outside the
+    /// buffer are ignored, as the device context would clip them anyway.
+    pub fn set(&mut self, size: (i32, i32), (x, y): (i32, i32), rgba: [u8; 4]) {
+        if size != (self.width, self.height) {
+            self.resize(size);
+        }
+        if x < 0 || y < 0 || x >= self.width || y >= self.height {
+            return;
+        }
+        let offset = 4 * (y as usize * self.width as usize + x as usize);
+        let pixel = &mut self.data[offset..offset + 4];
+        let previous = [pixel[0], pixel[1], pixel[2], pixel[3]];
+        pixel.copy_from_slice(&blend_over(rgba, previous));
+        self.dirty = Some(match self.dirty {
+            None => (x, y, x, y),
+            Some((x0, y0, x1, y1)) => {
+                (x0.min(x), y0.min(y), x1.max(x), y1.max(y))
+            }
+        });
+    }
+
+    /// Take the pixels set since the last flush, and reset the buffer.
+    ///
+    /// The returned region is the bounding box of the pixels that were set;
+    /// pixels within this box that were not set are fully transparent.
+    pub fn take(&mut self) -> Option<PixelRegion> {
+        let (x0, y0, x1, y1) = self.dirty.take()?;
+        let width = (x1 - x0 + 1) as usize;
+        let height = (y1 - y0 + 1) as usize;
+        let mut data = Vec::with_capacity(4 * width * height);
+        for y in y0..=y1 {
+            let start = 4 * (y as usize * self.width as usize + x0 as usize);
+            let row = &mut self.data[start..start + 4 * width];
+            data.extend_from_slice(row);
+            row.fill(0);
+        }
+        Some(PixelRegion {
+            x: x0,
+            y: y0,
+            width: width as u32,
+            height: height as u32,
+            data,
+        })
+    }
+
+    /// Discard the pixels set since the last flush.
+    pub fn discard(&mut self) {
+        let _ = self.take();
+    }
+
+    fn resize(&mut self, (width, height): (i32, i32)) {
+        self.width = width.max(0);
+        self.height = height.max(0);
+        self.data.clear();
+        self.data
+            .resize(4 * self.width as usize * self.height as usize, 0);
+        self.dirty = None;
+    }
+}
+
+/// Composite `src` over `dst` (straight alpha).
+fn blend_over(src: [u8; 4], dst: [u8; 4]) -> [u8; 4] {
+    match (src[3], dst[3]) {
+        (255, _) | (_, 0) => src,
+        (0, _) => dst,
+        (sa, da) => {
+            let sa = sa as f64 / 255.0;
+            let da = da as f64 / 255.0;
+            let out_a = sa + da * (1.0 - sa);
+            let channel = |s: u8, d: u8| {
+                let c = (s as f64 * sa + d as f64 * da * (1.0 - sa)) / out_a;
+                c.round() as u8
+            };
+            [
+                channel(src[0], dst[0]),
+                channel(src[1], dst[1]),
+                channel(src[2], dst[2]),
+                (out_a * 255.0).round() as u8,
+            ]
+        }
+    }
+}
@@ -0,0 +1,137 @@
+//! Off-screen rendering of plots to RGBA pixels.
+
+use wxdragon::{self as wx, DeviceContext};
+
+use crate::{Error, ErrorInner, WxBackend};
+
+/// Draw a plot on an off-screen bitmap of size `width` x `height`, and copy
+/// its RGBA pixels into `buffer`, row by row.
+///
+/// This integrates plots into another rendering pipeline, e.g. to composite
+/// them into a framebuffer owned by the application. `buffer` must hold
+/// exactly `4 * width * height` bytes. The plot is drawn on a
+/// `wxdragon::MemoryDC`, so this must be called from the wxWidgets main
+/// loop.
+pub fn render_to_rgba_buffer<F, E>(
+    buffer: &mut [u8],
+    width: u32,
+    height: u32,
+    draw_fn: F,
+) -> Result<(), Error>
+where
+    F: FnOnce(WxBackend<'_, wx::MemoryDC>) -> Result<(), E>,
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    if buffer.len() != 4 * width as usize * height as usize {
+        return Err(ErrorInner::BufferSize.into());
+    }
+    let data = render_supersampled(width, height, 1, true, draw_fn)?;
+    buffer.copy_from_slice(&data);
+    Ok(())
+}
+
+/// Draw a plot on an off-screen bitmap supersampled by `factor`, and return
+/// the RGBA pixels downsampled to `width` x `height`.
+///
+/// The bitmap is fully transparent until drawn on, and is cleared by the
+/// backend if `auto_clear` is `true`.
+pub(crate) fn render_supersampled<F, E>(
+    width: u32,
+    height: u32,
+    factor: u32,
+    auto_clear: bool,
+    draw_fn: F,
+) -> Result<Vec<u8>, Error>
+where
+    F: FnOnce(WxBackend<'_, wx::MemoryDC>) -> Result<(), E>,
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    let factor = factor.max(1);
+    let (large_width, large_height) = (width * factor, height * factor);
+    let bitmap = render_bitmap(width, height, factor, auto_clear, draw_fn)?;
+    let data = bitmap.get_rgba_data().ok_or(ErrorInner::ReadBitmap)?;
+    if data.len() != (4 * large_width * large_height) as usize {
+        return Err(ErrorInner::ReadBitmap.into());
+    }
+    if factor == 1 {
+        return Ok(data);
+    }
+    Ok(downsample(&data, large_width, width, height, factor))
+}
+
+/// Draw a plot on an off-screen bitmap supersampled by `factor`, and return
+/// the bitmap, of size `factor * width` x `factor * height`.
+///
+/// `draw_fn` receives a backend of size `width` x `height`, which clears the
+/// bitmap if `auto_clear` is `true`.
+pub(crate) fn render_bitmap<F, E>(
+    width: u32,
+    height: u32,
+    factor: u32,
+    auto_clear: bool,
+    draw_fn: F,
+) -> Result<wx::Bitmap, Error>
+where
+    F: FnOnce(WxBackend<'_, wx::MemoryDC>) -> Result<(), E>,
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    let factor = factor.max(1);
+    let (large_width, large_height) = (width * factor, height * factor);
+    let mut bitmap = wx::Bitmap::new(large_width as i32, large_height as i32)
+        .ok_or(ErrorInner::CreateBitmap)?;
+    let mut dc = wx::MemoryDC::new();
+    dc.select_object(&mut bitmap);
+    dc.set_user_scale(factor as f64, factor as f64);
+    let backend = WxBackend::builder(&dc).auto_clear(auto_clear).build();
+    let result = draw_fn(backend);
+    dc.select_object(&mut wx::Bitmap::null_bitmap());
+    result.map_err(|e| ErrorInner::Draw(e.into()))?;
+    Ok(bitmap)
+}
+
+/// Average each block of `factor` x `factor` pixels of an RGBA image of
+/// width `large_width` into one pixel of an image of size `width` x `height`.
+///
+/// Colors are weighted by their alpha, so that transparent pixels do not
+/// darken the edges.
+fn downsample(
+    data: &[u8],
+    large_width: u32,
+    width: u32,
+    height: u32,
+    factor: u32,
+) -> Vec<u8> {
+    let mut output = Vec::with_capacity((4 * width * height) as usize);
+    let count = (factor * factor) as f64;
+    for y in 0..height {
+        for x in 0..width {
+            let mut sum = [0.0; 4];
+            for dy in 0..factor {
+                let row = (y * factor + dy) * large_width;
+                for dx in 0..factor {
+                    let offset = (4 * (row + x * factor + dx)) as usize;
+                    let pixel = &data[offset..offset + 4];
+                    let alpha = pixel[3] as f64;
+                    for (sum, value) in sum.iter_mut().zip(&pixel[..3]) {
+                        *sum += *value as f64 * alpha;
+                    }
+                    sum[3] += alpha;
+                }
+            }
+            let [r, g, b] = [0, 1, 2].map(|channel| {
+                if sum[3] > 0.0 {
+                    (sum[channel] / sum[3]).round() as u8
+                } else {
+                    0
+                }
+            });
+            output.extend_from_slice(&[
+                r,
+                g,
+                b,
+                (sum[3] / count).round() as u8,
+            ]);
+        }
+    }
+    output
+}
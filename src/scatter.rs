@@ -0,0 +1,81 @@
+//! Drawing of connected scatter plots in a single pass.
+
+use plotters_backend::{
+    BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind,
+};
+use wxdragon::{self as wx, DeviceContext};
+
+use crate::{COORD_LIMIT, Error, WxBackend};
+
+/// Shape of the markers drawn by [`WxBackend::draw_connected_scatter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarkerShape {
+    /// A filled circle.
+    Circle,
+    /// A filled square.
+    Square,
+    /// A filled triangle, pointing up.
+    Triangle,
+    /// A diagonal cross, drawn with the stroke of the marker style.
+    Cross,
+}
+
+impl<'context, C> WxBackend<'context, C>
+where
+    C: DeviceContext,
+{
+    /// Draw a connected scatter plot: a polyline through `points`, and a
+    /// marker on each point.
+    ///
+    /// With plotters, this takes two passes over the data, e.g. a
+    /// `LineSeries` and a series of `Circle` elements, which set the pen and
+    /// brush again for every marker. This method draws the polyline, then
+    /// all the markers with a single pen and brush. `marker_size` is the
+    /// radius of the markers, in pixels, and markers are filled with the
+    /// color of `marker_style`.
+    pub fn draw_connected_scatter<L, M>(
+        &mut self,
+        points: &[BackendCoord],
+        line_style: &L,
+        marker_shape: MarkerShape,
+        marker_size: u32,
+        marker_style: &M,
+    ) -> Result<(), DrawingErrorKind<Error>>
+    where
+        L: BackendStyle,
+        M: BackendStyle,
+    {
+        self.draw_path(points.iter().copied(), line_style)?;
+        if !self.begin_draw()? {
+            return Ok(());
+        }
+        self.set_pen_style(marker_style);
+        self.set_brush_style(true, marker_style.color());
+        let r = marker_size.min(COORD_LIMIT as u32) as i32;
+        for (x, y) in points.iter().map(|point| self.context_position(*point)) {
+            match marker_shape {
+                MarkerShape::Circle => self.context.draw_circle(x, y, r),
+                MarkerShape::Square => self.context.draw_rectangle(
+                    x - r,
+                    y - r,
+                    2 * r + 1,
+                    2 * r + 1,
+                ),
+                MarkerShape::Triangle => {
+                    let vertices = [
+                        wx::dc::Point::new(x, y - r),
+                        wx::dc::Point::new(x - r, y + r),
+                        wx::dc::Point::new(x + r, y + r),
+                    ];
+                    let fill_mode = wx::dc::PolygonFillMode::OddEven;
+                    self.context.draw_polygon(&vertices, 0, 0, fill_mode);
+                }
+                MarkerShape::Cross => {
+                    self.context.draw_line(x - r, y - r, x + r, y + r);
+                    self.context.draw_line(x - r, y + r, x + r, y - r);
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+//! Rendering of sparklines into a `wxImageList`.
+
+use plotters_backend::{BackendStyle, DrawingBackend, DrawingErrorKind};
+use wxdragon as wx;
+
+use crate::{Error, ErrorInner, WxBackend, drawing_error};
+
+/// Render each series as a sparkline in a bitmap of the given `size`, and
+/// collect the bitmaps in a [`wxdragon::ImageList`].
+///
+/// This allows building a `wxdragon::ListCtrl` or any image-list-based
+/// control of mini-charts. Each series is drawn as a single line with
+/// `style`, scaled to fill its bitmap on a white background. All sparklines
+/// are drawn with the same in-memory device context.
+///
+/// Like any drawing with wxWidgets, this must be called from within the
+/// wxWidgets main loop, e.g. in the closure given to `wxdragon::main`.
+pub fn sparklines_to_image_list<I, V, S>(
+    size: (u32, u32),
+    series: I,
+    style: &S,
+) -> Result<wx::ImageList, DrawingErrorKind<Error>>
+where
+    I: IntoIterator<Item = V>,
+    V: AsRef<[f64]>,
+    S: BackendStyle,
+{
+    let (width, height) = (size.0 as i32, size.1 as i32);
+    let series = series.into_iter();
+    let mask = false;
+    let image_list =
+        wx::ImageList::new(width, height, mask, series.size_hint().0 as i32);
+    let mut dc = wx::MemoryDC::new();
+    for values in series {
+        let mut bitmap = wx::Bitmap::new(width, height)
+            .ok_or(ErrorInner::CreateBitmap)
+            .map_err(drawing_error)?;
+        dc.select_object(&mut bitmap);
+        {
+            let mut backend = WxBackend::new(&dc);
+            let path = sparkline_path(values.as_ref(), (width, height));
+            backend.draw_path(path, style)?;
+            backend.present()?;
+        }
+        dc.select_object(&mut wx::Bitmap::null_bitmap());
+        if image_list.add_bitmap(&bitmap) < 0 {
+            return Err(drawing_error(ErrorInner::AddToImageList));
+        }
+    }
+    Ok(image_list)
+}
+
+/// Scale `values` to a path spanning a canvas of the given size, leaving one
+/// pixel of margin so that the line is not clipped.
+fn sparkline_path(
+    values: &[f64],
+    (width, height): (i32, i32),
+) -> Vec<plotters_backend::BackendCoord> {
+    let (min, max) = values
+        .iter()
+        .filter(|v| v.is_finite())
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &v| {
+            (min.min(v), max.max(v))
+        });
+    let span = if max > min { max - min } else { 1.0 };
+    let x_step = (width - 3) as f64 / (values.len().max(2) - 1) as f64;
+    let y_scale = (height - 3) as f64 / span;
+    values
+        .iter()
+        .enumerate()
+        .filter(|(_, v)| v.is_finite())
+        .map(|(i, v)| {
+            let x = 1.0 + i as f64 * x_step;
+            let y = (height - 2) as f64 - (v - min) * y_scale;
+            (x.round() as i32, y.round() as i32)
+        })
+        .collect()
+}
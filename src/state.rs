@@ -0,0 +1,71 @@
+//! State of a backend that persists across frames.
+
+use std::cell::RefCell;
+
+use wxdragon::DeviceContext;
+
+use crate::font_cache::{FontCache, FontCacheRef};
+use crate::{BackendConfig, ContextRef, WxBackend, device_dpi};
+
+/// Settings and caches of a [`WxBackend`], kept from one frame to the next.
+///
+/// A `WxBackend` borrows its device context, and a paint handler creates a
+/// new device context for each frame, so a new backend is created for each
+/// frame too, and would create its fonts again. A `WxBackendState` owns the
+/// settings and the fonts created by the backends, and
+/// [`WxBackendState::bind`] creates the backend of a frame from them, which
+/// is cheap. This matters for plots redrawn at a high rate, see
+/// [`AnimatedPlotPanel`](crate::AnimatedPlotPanel).
+///
+/// Pens and brushes are not kept: they are state of the device context,
+/// which is new for every frame.
+pub struct WxBackendState {
+    config: BackendConfig,
+    fonts: RefCell<FontCache>,
+}
+
+impl WxBackendState {
+    /// Creates a state with the settings of [`WxBackend::new`] on `context`,
+    /// i.e. the resolution of `context` and the default settings otherwise.
+    pub fn new<C: DeviceContext>(context: &C) -> WxBackendState {
+        let (_, dpi) = device_dpi(context);
+        WxBackendState::with_config(BackendConfig {
+            dpi: dpi as f64,
+            ..BackendConfig::default()
+        })
+    }
+
+    /// Creates a state with the settings of `config`.
+    pub fn with_config(config: BackendConfig) -> WxBackendState {
+        WxBackendState {
+            config,
+            fonts: RefCell::default(),
+        }
+    }
+
+    /// Get the settings of the backends created by [`WxBackendState::bind`].
+    pub fn config(&self) -> &BackendConfig {
+        &self.config
+    }
+
+    /// Set the settings of the backends created by [`WxBackendState::bind`].
+    pub fn set_config(&mut self, config: BackendConfig) {
+        self.config = config;
+    }
+
+    /// Creates the backend of a frame over `context`, with the settings and
+    /// the fonts of this state, and clears `context`.
+    ///
+    /// Fonts created while drawing the frame are kept for the next frames.
+    pub fn bind<'a, C: DeviceContext>(
+        &'a self,
+        context: &'a C,
+    ) -> WxBackend<'a, C> {
+        WxBackend::from_config(
+            ContextRef::Borrowed(context),
+            &self.config,
+            true,
+            FontCacheRef::Borrowed(&self.fonts),
+        )
+    }
+}
@@ -0,0 +1,70 @@
+//! Pixel-aligned tick positions for custom overlays.
+
+use std::ops::Range;
+
+/// A tick on an axis: a data value and its position in pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tick {
+    /// Value of the tick, in data coordinates.
+    pub value: f64,
+    /// Position of the tick, in device pixels.
+    pub pixel: i32,
+}
+
+/// Compute "nice" tick positions for a linear axis, snapped to device pixels.
+///
+/// Ticks are multiples of a step of the form 1, 2 or 5 times a power of ten,
+/// with at most `max_ticks` ticks in `data_range`. Pixel positions use the
+/// same mapping as plotters for a `f64` axis spanning `pixel_range`, so that
+/// custom gridlines drawn at these positions align exactly with the chart.
+///
+/// `pixel_range` goes from the pixel of `data_range.start` to the pixel of
+/// `data_range.end`. For a vertical axis, where values increase upwards, this
+/// is `(bottom, top)` of the plotting area, e.g. obtained from plotters with
+/// `chart.plotting_area().get_pixel_range()`.
+pub fn pixel_aligned_ticks(
+    data_range: Range<f64>,
+    pixel_range: (i32, i32),
+    max_ticks: usize,
+) -> Vec<Tick> {
+    let Range { start, end } = data_range;
+    let span = end - start;
+    if !(span > 0.0 && span.is_finite()) || max_ticks == 0 {
+        return Vec::new();
+    }
+    let step = nice_step(span, max_ticks);
+    let first = (start / step).ceil() as i64;
+    let last = (end / step).floor() as i64;
+    (first..=last)
+        .map(|i| {
+            let value = i as f64 * step;
+            Tick {
+                value,
+                pixel: map_to_pixel(value, &data_range, pixel_range),
+            }
+        })
+        .collect()
+}
+
+/// Smallest step of the form {1, 2, 5} x 10^k giving at most `max_ticks`
+/// ticks over `span`.
+fn nice_step(span: f64, max_ticks: usize) -> f64 {
+    let raw_step = span / max_ticks as f64;
+    let magnitude = 10f64.powf(raw_step.log10().floor());
+    [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .map(|factor| factor * magnitude)
+        .find(|step| ((span / step).floor() as usize) < max_ticks)
+        .unwrap_or(10.0 * magnitude)
+}
+
+/// Map a value to pixels, like plotters does for a `f64` axis.
+fn map_to_pixel(value: f64, data_range: &Range<f64>, limit: (i32, i32)) -> i32 {
+    let logic_length =
+        (value - data_range.start) / (data_range.end - data_range.start);
+    let actual_length = limit.1 - limit.0;
+    if actual_length == 0 {
+        return limit.1;
+    }
+    limit.0 + (actual_length as f64 * logic_length + 1e-3).floor() as i32
+}
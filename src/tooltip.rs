@@ -0,0 +1,122 @@
+//! Tooltip showing the data point nearest to the mouse cursor.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use plotters_backend::BackendCoord;
+use wxdragon::{self as wx, WindowEvents, WxWidget};
+
+use crate::CoordMapper;
+use crate::panel::mouse_position;
+
+/// A tooltip showing the coordinates of the data point nearest to the mouse
+/// cursor, e.g. `(1.250, -0.371)`.
+///
+/// The data points are mapped to pixels with a [`CoordMapper`], and the
+/// nearest point within `threshold` pixels of the cursor is shown. Register
+/// the series and the mapper of the chart with [`HoverTooltip::set_data`],
+/// typically in the drawing function, and show the tooltip on a panel with
+/// [`HoverTooltip::attach`]. Clones of a `HoverTooltip` share their state, so
+/// that the drawing function and the mouse handler each own one.
+///
+/// The tooltip is only updated when the nearest point changes, not on every
+/// pixel of mouse motion, so that it does not flicker.
+#[derive(Debug, Clone)]
+pub struct HoverTooltip {
+    state: Rc<RefCell<TooltipState>>,
+}
+
+/// State shared between the clones of a [`HoverTooltip`].
+#[derive(Debug)]
+struct TooltipState {
+    threshold: f64,
+    mapper: Option<CoordMapper>,
+    series: Vec<Vec<(f64, f64)>>,
+    /// Series and point indices of the point shown in the tooltip.
+    shown: Option<(usize, usize)>,
+}
+
+impl HoverTooltip {
+    /// Creates a tooltip for points within `threshold` pixels of the cursor,
+    /// without data.
+    pub fn new(threshold: f64) -> HoverTooltip {
+        HoverTooltip {
+            state: Rc::new(RefCell::new(TooltipState {
+                threshold,
+                mapper: None,
+                series: Vec::new(),
+                shown: None,
+            })),
+        }
+    }
+
+    /// Set the data series, and the mapper from data coordinates to pixels of
+    /// the chart they are drawn on.
+    pub fn set_data(&self, mapper: CoordMapper, series: Vec<Vec<(f64, f64)>>) {
+        let mut state = self.state.borrow_mut();
+        state.mapper = Some(mapper);
+        state.series = series;
+    }
+
+    /// Find the data point nearest to `cursor`, within the threshold, as the
+    /// index of its series and its coordinates.
+    pub fn nearest_point(
+        &self,
+        cursor: BackendCoord,
+    ) -> Option<(usize, (f64, f64))> {
+        let state = self.state.borrow();
+        state
+            .nearest(cursor)
+            .map(|(series, point)| (series, state.series[series][point]))
+    }
+
+    /// Show the tooltip on `panel`, updated as the mouse moves over it.
+    ///
+    /// Mouse motion events are skipped after handling, so that other
+    /// handlers, e.g. of an
+    /// [`InteractivePlotPanel`](crate::InteractivePlotPanel), still get them.
+    pub fn attach(&self, panel: &wx::Panel) {
+        let state = self.state.clone();
+        let panel = *panel;
+        panel.on_mouse_motion(move |event| {
+            event.skip(true);
+            let Some(position) = mouse_position(&event) else {
+                return;
+            };
+            let mut state = state.borrow_mut();
+            let nearest = state.nearest(position);
+            if nearest == state.shown {
+                return;
+            }
+            state.shown = nearest;
+            match nearest {
+                Some((series, point)) => {
+                    let (x, y) = state.series[series][point];
+                    panel.set_tooltip(&format!("({x:.3}, {y:.3})"));
+                }
+                None => panel.set_tooltip(""),
+            }
+        });
+    }
+}
+
+impl TooltipState {
+    /// Find the series and point indices of the point nearest to `cursor`,
+    /// within the threshold.
+    fn nearest(&self, (cx, cy): BackendCoord) -> Option<(usize, usize)> {
+        let mapper = self.mapper.as_ref()?;
+        self.series
+            .iter()
+            .enumerate()
+            .flat_map(|(series, points)| {
+                points.iter().enumerate().map(move |(point, data)| {
+                    let (px, py) = mapper.to_pixel(*data);
+                    let distance = ((px - cx) as f64).hypot((py - cy) as f64);
+                    ((series, point), distance)
+                })
+            })
+            .filter(|&(_, distance)| distance <= self.threshold)
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(indices, _)| indices)
+    }
+}
@@ -0,0 +1,191 @@
+//! Data ranges shown by an interactive plot, zoomed and panned with the mouse.
+
+use std::ops::Range;
+
+use plotters_backend::BackendCoord;
+
+use crate::CoordMapper;
+
+/// Maximum ratio between the initial span of a range and its zoomed span, in
+/// either direction.
+///
+/// This keeps ranges finite and non-empty, however far the user zooms.
+const MAX_ZOOM: f64 = 1e6;
+
+/// Minimum width and height, in pixels, of a rectangle to zoom to.
+///
+/// Smaller rectangles are most likely clicks rather than selections.
+const MIN_ZOOM_RECT: i32 = 3;
+
+/// The data ranges shown by a plot, which can be zoomed and panned.
+///
+/// Zooming and panning take positions in device pixels, and map them to data
+/// coordinates with the pixel area of the plot, i.e. the pixel ranges covered
+/// by the data ranges. For a plotters chart, this is
+/// `chart.plotting_area().get_pixel_range()`. As in plotters, the start of
+/// the x range is on the left, and the start of the y range is at the bottom.
+///
+/// See [`InteractivePlotPanel`](crate::InteractivePlotPanel) for a panel that
+/// zooms and pans a viewport with the mouse.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Viewport {
+    x: Range<f64>,
+    y: Range<f64>,
+    initial: (Range<f64>, Range<f64>),
+}
+
+impl Viewport {
+    /// Creates a viewport showing the data ranges `x` and `y`.
+    pub fn new(x: Range<f64>, y: Range<f64>) -> Viewport {
+        Viewport {
+            initial: (x.clone(), y.clone()),
+            x,
+            y,
+        }
+    }
+
+    /// The data range currently shown along the x axis.
+    pub fn x_range(&self) -> Range<f64> {
+        self.x.clone()
+    }
+
+    /// The data range currently shown along the y axis.
+    pub fn y_range(&self) -> Range<f64> {
+        self.y.clone()
+    }
+
+    /// Zoom by `factor` around the data point under `cursor`, which stays
+    /// under the cursor. A factor above 1 zooms in, and below 1 zooms out.
+    ///
+    /// The zoom is limited so that ranges stay within a factor of a million
+    /// of their initial span. Does nothing if `factor` is not a positive
+    /// number or if the pixel area is empty.
+    pub fn zoom(
+        &mut self,
+        cursor: BackendCoord,
+        pixel_area: &(Range<i32>, Range<i32>),
+        factor: f64,
+    ) {
+        if !(factor > 0.0 && factor.is_finite()) {
+            return;
+        }
+        let (Some(tx), Some(ty)) = fractions(cursor, pixel_area) else {
+            return;
+        };
+        let tx = tx.clamp(0.0, 1.0);
+        let ty = ty.clamp(0.0, 1.0);
+        self.x = zoom_range(&self.x, &self.initial.0, tx, factor);
+        // pixel rows grow downwards, data grows upwards
+        self.y = zoom_range(&self.y, &self.initial.1, 1.0 - ty, factor);
+    }
+
+    /// Move the data ranges so that the data follows a mouse drag of `delta`
+    /// pixels. Does nothing if the pixel area is empty.
+    pub fn pan(
+        &mut self,
+        (dx, dy): (i32, i32),
+        pixel_area: &(Range<i32>, Range<i32>),
+    ) {
+        let (x_pixels, y_pixels) = pixel_area;
+        let width = (x_pixels.end - x_pixels.start) as f64;
+        let height = (y_pixels.end - y_pixels.start) as f64;
+        if width <= 0.0 || height <= 0.0 {
+            return;
+        }
+        let shift_x = -dx as f64 / width * (self.x.end - self.x.start);
+        let shift_y = dy as f64 / height * (self.y.end - self.y.start);
+        self.x = self.x.start + shift_x..self.x.end + shift_x;
+        self.y = self.y.start + shift_y..self.y.end + shift_y;
+    }
+
+    /// Zoom to the data shown in the pixel rectangle between the corners
+    /// `corner1` and `corner2`, e.g. selected with a rubber band.
+    ///
+    /// Returns `false` and does nothing if the rectangle is less than 3
+    /// pixels wide or high, if the pixel area is empty, or if the zoom would
+    /// exceed the limit of [`Viewport::zoom`].
+    pub fn zoom_to_rect(
+        &mut self,
+        corner1: BackendCoord,
+        corner2: BackendCoord,
+        pixel_area: &(Range<i32>, Range<i32>),
+    ) -> bool {
+        let (left, right) =
+            (corner1.0.min(corner2.0), corner1.0.max(corner2.0));
+        let (top, bottom) =
+            (corner1.1.min(corner2.1), corner1.1.max(corner2.1));
+        let (x_pixels, y_pixels) = pixel_area;
+        if right - left < MIN_ZOOM_RECT
+            || bottom - top < MIN_ZOOM_RECT
+            || x_pixels.is_empty()
+            || y_pixels.is_empty()
+        {
+            return false;
+        }
+        let mapper = CoordMapper::new(
+            pixel_area.clone(),
+            self.x.clone(),
+            self.y.clone(),
+        );
+        // pixel rows grow downwards, data grows upwards
+        let (x_start, y_start) = mapper.to_data((left, bottom));
+        let (x_end, y_end) = mapper.to_data((right, top));
+        let (x, y) = (x_start..x_end, y_start..y_end);
+        if !within_zoom(&x, &self.initial.0)
+            || !within_zoom(&y, &self.initial.1)
+        {
+            return false;
+        }
+        (self.x, self.y) = (x, y);
+        true
+    }
+
+    /// Show the initial data ranges again.
+    pub fn reset(&mut self) {
+        (self.x, self.y) = self.initial.clone();
+    }
+}
+
+/// Position of `cursor` in a pixel area, as fractions of its width and
+/// height, or `None` along an empty direction.
+fn fractions(
+    (x, y): BackendCoord,
+    (x_pixels, y_pixels): &(Range<i32>, Range<i32>),
+) -> (Option<f64>, Option<f64>) {
+    let fraction = |value: i32, pixels: &Range<i32>| {
+        let length = pixels.end - pixels.start;
+        (length > 0).then(|| (value - pixels.start) as f64 / length as f64)
+    };
+    (fraction(x, x_pixels), fraction(y, y_pixels))
+}
+
+/// Returns `true` if the span of `range` is within [`MAX_ZOOM`] of the span
+/// of `initial`.
+fn within_zoom(range: &Range<f64>, initial: &Range<f64>) -> bool {
+    let span = (range.end - range.start).abs();
+    let initial_span = (initial.end - initial.start).abs();
+    span.is_finite()
+        && span >= initial_span / MAX_ZOOM
+        && span <= initial_span * MAX_ZOOM
+}
+
+/// Zoom `range` by `factor` around the value at fraction `t` of the range,
+/// keeping its span within [`MAX_ZOOM`] of the span of `initial`.
+fn zoom_range(
+    range: &Range<f64>,
+    initial: &Range<f64>,
+    t: f64,
+    factor: f64,
+) -> Range<f64> {
+    let span = range.end - range.start;
+    let initial_span = (initial.end - initial.start).abs();
+    let new_span = (span.abs() / factor)
+        .clamp(initial_span / MAX_ZOOM, initial_span * MAX_ZOOM)
+        .copysign(span);
+    if !new_span.is_finite() || new_span == 0.0 {
+        return range.clone();
+    }
+    let anchor = range.start + t * span;
+    let start = anchor - t * new_span;
+    start..start + new_span
+}
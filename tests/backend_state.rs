@@ -0,0 +1,51 @@
+//! Backend state kept across frames
+//!
+//! This tests that frames drawn with backends bound to the same
+//! `WxBackendState`, which reuse its fonts, are identical to a frame drawn
+//! with a new backend.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::{WxBackend, WxBackendState};
+use wxdragon as wx;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+const WIDTH: u32 = 200;
+const HEIGHT: u32 = 100;
+
+#[test]
+fn test_backend_state() -> Result<()> {
+    run_wx_test(|| {
+        let expected = render_rgba_image(WIDTH, HEIGHT, |dc| {
+            draw_frame(WxBackend::new(dc))
+        })?;
+
+        let mut state = None;
+        for frame in 0..3 {
+            let image = render_rgba_image(WIDTH, HEIGHT, |dc| {
+                let state =
+                    state.get_or_insert_with(|| WxBackendState::new(dc));
+                draw_frame(state.bind(dc))
+            })?;
+            anyhow::ensure!(
+                image == expected,
+                "frame {frame} differs from a frame drawn with a new backend"
+            );
+        }
+        Ok(())
+    })
+}
+
+/// Draws a frame with texts in two fonts.
+fn draw_frame(mut backend: WxBackend<'_, wx::MemoryDC>) -> Result<()> {
+    let caption = TextStyle::from(("sans-serif", 30).into_font());
+    let label = TextStyle::from(("serif", 15).into_font());
+    backend.draw_text("Caption", &caption, (10, 10))?;
+    backend.draw_text("label", &label, (10, 60))?;
+    backend.draw_rect((120, 20), (180, 80), &RED, true)?;
+    backend.present()?;
+    Ok(())
+}
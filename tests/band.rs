@@ -0,0 +1,40 @@
+//! Translucent bands
+//!
+//! This tests `draw_band`, by shading a band over a white background, and
+//! checking the alpha-blended pixels. The band is given with reversed
+//! horizontal bounds, which are normalized.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_band() -> Result<()> {
+    run_wx_test(|| {
+        let image = render_rgba_image(100, 100, |dc| {
+            let mut backend = WxBackend::new(dc);
+            let color = BLUE.mix(0.5).to_backend_color();
+            backend.draw_band(60, 20, (10, 89), color)?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        for (x, y, pixel) in image.enumerate_pixels() {
+            let [r, g, b, _] = pixel.0;
+            let inside = (20..=60).contains(&x) && (10..=89).contains(&y);
+            // Over white, blue at alpha 0.5 blends to (~128, ~128, 255).
+            let ok = match inside {
+                true => {
+                    r.abs_diff(128) <= 2 && g.abs_diff(128) <= 2 && b == 255
+                }
+                false => [r, g, b] == [255, 255, 255],
+            };
+            anyhow::ensure!(ok, "unexpected pixel {:?} at ({x}, {y})", pixel.0);
+        }
+        Ok(())
+    })
+}
@@ -0,0 +1,51 @@
+//! Alpha channel of blitted bitmaps
+//!
+//! This tests that `blit_bitmap` honors the alpha channel of the source
+//! image, by blitting a red image over a blue background: the left half of
+//! the image is semi-transparent and blends with the background, the right
+//! half is fully transparent and keeps the background color.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+const SIZE: u32 = 40;
+const POS: (i32, i32) = (30, 20);
+
+#[test]
+fn test_blit_alpha() -> Result<()> {
+    run_wx_test(|| {
+        let src: Vec<u8> = (0..SIZE * SIZE)
+            .flat_map(|i| {
+                let alpha = if i % SIZE < SIZE / 2 { 128 } else { 0 };
+                [255, 0, 0, alpha]
+            })
+            .collect();
+
+        let image = render_rgba_image(100, 80, |dc| {
+            let mut backend = WxBackend::new(dc);
+            backend.draw_rect((0, 0), (100, 80), &BLUE, true)?;
+            backend.blit_bitmap(POS, (SIZE, SIZE), &src)?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        let (x0, y0) = (POS.0 as u32, POS.1 as u32);
+        let [r, g, b, a] = image.get_pixel(x0 + SIZE / 4, y0 + SIZE / 2).0;
+        anyhow::ensure!(
+            r.abs_diff(128) <= 2 && g == 0 && b.abs_diff(127) <= 2 && a == 255,
+            "semi-transparent half: {:?}",
+            [r, g, b, a]
+        );
+        let pixel = image.get_pixel(x0 + 3 * SIZE / 4, y0 + SIZE / 2).0;
+        anyhow::ensure!(
+            pixel == [0, 0, 255, 255],
+            "transparent half: {pixel:?}"
+        );
+        Ok(())
+    })
+}
@@ -0,0 +1,49 @@
+//! Bitmaps drawn at a logical size
+//!
+//! This tests `blit_bitmap_logical`, by drawing a 32x32 image at a logical
+//! size of 64x64, as needed on a display with a content scale factor of 2,
+//! at a position which is not a multiple of the stretch. An empty logical
+//! size draws nothing, and a buffer of the wrong size is rejected without
+//! invalidating the frame.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_blit_logical() -> Result<()> {
+    run_wx_test(|| {
+        let src = [255, 0, 0, 255].repeat(32 * 32);
+        let image = render_rgba_image(100, 100, |dc| {
+            let mut backend = WxBackend::new(dc);
+            backend.blit_bitmap_logical((11, 13), (32, 32), (64, 64), &src)?;
+            backend.blit_bitmap_logical((80, 80), (32, 32), (0, 10), &src)?;
+            anyhow::ensure!(
+                backend
+                    .blit_bitmap_logical((80, 80), (32, 32), (8, 8), &src[4..])
+                    .is_err(),
+                "image of the wrong size was accepted"
+            );
+            backend.present()?;
+            Ok(())
+        })?;
+
+        for (x, y, pixel) in image.enumerate_pixels() {
+            let inside = (11..75).contains(&x) && (13..77).contains(&y);
+            let expected = match inside {
+                true => [255, 0, 0, 255],
+                false => [255, 255, 255, 255],
+            };
+            anyhow::ensure!(
+                pixel.0 == expected,
+                "unexpected pixel {:?} at ({x}, {y})",
+                pixel.0
+            );
+        }
+        Ok(())
+    })
+}
@@ -0,0 +1,65 @@
+//! Masked bitmap
+//!
+//! This tests `blit_bitmap_with_mask`, by blitting a green square through a
+//! circular mask over a blue background, and checking that the corners of
+//! the square keep the background color. Buffers of the wrong size are
+//! rejected without invalidating the frame.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+const SIZE: u32 = 40;
+const POS: (i32, i32) = (30, 20);
+
+#[test]
+fn test_blit_mask() -> Result<()> {
+    run_wx_test(|| {
+        let src = [0, 255, 0, 255].repeat((SIZE * SIZE) as usize);
+        let radius = SIZE as f64 / 2.0;
+        let mask: Vec<u8> = (0..SIZE * SIZE)
+            .map(|i| {
+                let (x, y) = ((i % SIZE) as f64 + 0.5, (i / SIZE) as f64 + 0.5);
+                let inside = (x - radius).hypot(y - radius) <= radius;
+                inside as u8
+            })
+            .collect();
+
+        let image = render_rgba_image(100, 80, |dc| {
+            let mut backend = WxBackend::new(dc);
+            backend.draw_rect((0, 0), (100, 80), &BLUE, true)?;
+            backend.blit_bitmap_with_mask(POS, (SIZE, SIZE), &src, &mask)?;
+            anyhow::ensure!(
+                backend
+                    .blit_bitmap_with_mask(POS, (SIZE, SIZE), &src, &mask[1..])
+                    .is_err(),
+                "mask of the wrong size was accepted"
+            );
+            anyhow::ensure!(
+                backend
+                    .blit_bitmap_with_mask(POS, (SIZE, SIZE), &src[4..], &mask)
+                    .is_err(),
+                "image of the wrong size was accepted"
+            );
+            backend.present()?;
+            Ok(())
+        })?;
+
+        let (x0, y0) = (POS.0 as u32, POS.1 as u32);
+        let last = SIZE - 1;
+        for (dx, dy) in [(0, 0), (last, 0), (0, last), (last, last)] {
+            let pixel = image.get_pixel(x0 + dx, y0 + dy).0;
+            anyhow::ensure!(
+                pixel == [0, 0, 255, 255],
+                "corner ({dx}, {dy}) of the image: {pixel:?}"
+            );
+        }
+        let center = image.get_pixel(x0 + SIZE / 2, y0 + SIZE / 2).0;
+        anyhow::ensure!(center == [0, 255, 0, 255], "center: {center:?}");
+        Ok(())
+    })
+}
@@ -0,0 +1,77 @@
+//! Bold italic text
+//!
+//! This tests `set_bold` combined with an italic plotters style: the text is
+//! drawn with both a bold weight and a slant, so it differs from both the
+//! italic text and the bold text, and has more ink than the italic text. The
+//! reference image is the same text drawn by wxWidgets with a bold italic
+//! font, rather than a PNG fixture, whose glyphs would depend on the fonts of
+//! the machine that generated it.
+
+mod test_utils;
+
+use anyhow::{Context, Result};
+use image::RgbaImage;
+use plotters::prelude::*;
+use plotters::style::FontStyle;
+use plotters_wxdragon::WxBackend;
+use wxdragon::{self as wx, DeviceContext};
+
+use test_utils::{ink, render_rgba_image, run_wx_test};
+
+const TEXT: &str = "Bold italic caption";
+
+#[test]
+fn test_bold_italic() -> Result<()> {
+    run_wx_test(|| {
+        let render = |font_style: FontStyle, bold: bool| {
+            render_rgba_image(300, 60, |dc| {
+                let mut backend = WxBackend::new(dc);
+                backend.set_bold(bold);
+                let style = TextStyle::from(
+                    ("sans-serif", 30).into_font().style(font_style),
+                );
+                backend.draw_text(TEXT, &style, (10, 10))?;
+                backend.present()?;
+                Ok(())
+            })
+        };
+        let italic = render(FontStyle::Italic, false)?;
+        let bold = render(FontStyle::Bold, false)?;
+        let bold_italic = render(FontStyle::Italic, true)?;
+
+        anyhow::ensure!(bold_italic != italic, "bold italic is not bold");
+        anyhow::ensure!(bold_italic != bold, "bold italic is not italic");
+        anyhow::ensure!(
+            ink(&bold_italic) > ink(&italic),
+            "bold italic text has less ink ({}) than italic text ({})",
+            ink(&bold_italic),
+            ink(&italic)
+        );
+        anyhow::ensure!(
+            bold_italic == render_reference()?,
+            "bold italic text differs from a bold italic wxWidgets font"
+        );
+        Ok(())
+    })
+}
+
+/// Draw the text with a bold italic font of the same size, directly on the
+/// device context.
+fn render_reference() -> Result<RgbaImage> {
+    render_rgba_image(300, 60, |dc| {
+        let backend = WxBackend::new(dc);
+        // 30 pixels at 96 dpi, as converted by the backend
+        let font = wx::Font::builder()
+            .with_point_size(18)
+            .with_family(wx::FontFamily::Swiss)
+            .with_style(wx::FontStyle::Italic)
+            .with_weight(wx::FontWeight::Bold)
+            .build()
+            .context("failed to create the reference font")?;
+        let dc = backend.context();
+        dc.set_font(&font);
+        dc.set_text_foreground(wx::Colour::rgb(0, 0, 0));
+        dc.draw_text(TEXT, 10, 10);
+        Ok(())
+    })
+}
@@ -0,0 +1,45 @@
+//! Backend builder
+//!
+//! This tests `WxBackend::builder`, by building a backend with a dark
+//! background, and checking that the device context is cleared to it, and
+//! that the background color is part of the settings of the backend.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::{DEFAULT_DPI, WxBackend};
+use wxdragon as wx;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_builder() -> Result<()> {
+    run_wx_test(|| {
+        let image = render_rgba_image(100, 80, |dc| {
+            let mut backend = WxBackend::builder(dc)
+                .dpi(DEFAULT_DPI)
+                .background_color(wx::Colour::rgb(30, 30, 30))
+                .background_mode(wx::BackgroundMode::Solid)
+                .build();
+            let config = backend.config();
+            anyhow::ensure!(
+                config.background_mode == wx::BackgroundMode::Solid,
+                "background mode is {:?}",
+                config.background_mode
+            );
+            backend.draw_rect((10, 10), (30, 30), &WHITE, true)?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        let background = image.get_pixel(80, 60).0;
+        anyhow::ensure!(
+            background == [30, 30, 30, 255],
+            "background pixel: {background:?}"
+        );
+        let rect = image.get_pixel(20, 20).0;
+        anyhow::ensure!(rect == [255, 255, 255, 255], "rect pixel: {rect:?}");
+        Ok(())
+    })
+}
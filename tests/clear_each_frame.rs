@@ -0,0 +1,47 @@
+//! Clearing the device context at each frame
+//!
+//! This tests `set_clear_each_frame`, by drawing two frames with the same
+//! backend: the second frame is drawn on a clean background, and drawing
+//! operations within a frame do not clear each other.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_clear_each_frame() -> Result<()> {
+    run_wx_test(|| {
+        let image = render_rgba_image(60, 40, |dc| {
+            let mut backend = WxBackend::new(dc);
+            backend.set_clear_each_frame(true);
+
+            // first frame
+            backend.ensure_prepared()?;
+            backend.draw_rect((5, 5), (15, 15), &RED, true)?;
+            backend.present()?;
+
+            // second frame, with two drawing operations
+            backend.ensure_prepared()?;
+            backend.draw_rect((25, 5), (35, 15), &BLUE, true)?;
+            backend.ensure_prepared()?;
+            backend.draw_rect((45, 5), (55, 15), &GREEN, true)?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        let expected = [
+            ((10, 10), [255, 255, 255, 255]),
+            ((30, 10), [0, 0, 255, 255]),
+            ((50, 10), [0, 255, 0, 255]),
+        ];
+        for ((x, y), color) in expected {
+            let pixel = image.get_pixel(x, y).0;
+            anyhow::ensure!(pixel == color, "pixel ({x}, {y}): {pixel:?}");
+        }
+        Ok(())
+    })
+}
@@ -0,0 +1,41 @@
+//! Partial clear
+//!
+//! This tests `clear_rect`, by filling the canvas, clearing a sub-rectangle,
+//! and checking that only this sub-rectangle reverts to the background.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+use wxdragon as wx;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_clear_rect() -> Result<()> {
+    run_wx_test(|| {
+        let image = render_rgba_image(100, 100, |dc| {
+            let mut backend = WxBackend::new(dc);
+            backend.set_background_color(wx::Colour::rgb(0, 0, 255));
+            backend.draw_rect((0, 0), (100, 100), &RED, true)?;
+            backend.clear_rect((20, 30), (59, 69))?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        for (x, y, pixel) in image.enumerate_pixels() {
+            let cleared = (20..60).contains(&x) && (30..70).contains(&y);
+            let expected = match cleared {
+                true => [0, 0, 255, 255],
+                false => [255, 0, 0, 255],
+            };
+            anyhow::ensure!(
+                pixel.0 == expected,
+                "unexpected pixel {:?} at ({x}, {y})",
+                pixel.0
+            );
+        }
+        Ok(())
+    })
+}
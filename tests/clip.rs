@@ -0,0 +1,56 @@
+//! Clipping rectangle
+//!
+//! This tests `set_clip_rect`, by drawing a line series that overshoots a
+//! clipping rectangle, and checking that it is only drawn inside the
+//! rectangle. Drawing is not clipped anymore once the rectangle is cleared.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+const CLIP: ((i32, i32), (i32, i32)) = ((20, 10), (79, 49));
+
+#[test]
+fn test_clip() -> Result<()> {
+    run_wx_test(|| {
+        let image = render_rgba_image(100, 80, |dc| {
+            let mut backend = WxBackend::new(dc);
+            backend.set_clip_rect(Some(CLIP))?;
+            anyhow::ensure!(
+                backend.clip_rect() == Some(CLIP),
+                "clip rect: {:?}",
+                backend.clip_rect()
+            );
+            backend.draw_path([(0, 30), (50, 30), (99, 0)], &BLACK)?;
+            backend.fill_polygon([(0, 40), (99, 40), (99, 45)], &BLUE)?;
+            backend.set_clip_rect(None)?;
+            backend.draw_line((0, 70), (99, 70), &RED)?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        let black = [0, 0, 0, 255];
+        let white = [255, 255, 255, 255];
+        let expected = [
+            // the line series inside and outside the rectangle
+            ((20, 30), black),
+            ((50, 30), black),
+            ((10, 30), white),
+            ((19, 30), white),
+            // the polygon, cut at the right edge of the rectangle
+            ((79, 42), [0, 0, 255, 255]),
+            ((85, 42), white),
+            // not clipped anymore
+            ((5, 70), [255, 0, 0, 255]),
+        ];
+        for ((x, y), color) in expected {
+            let pixel = image.get_pixel(x, y).0;
+            anyhow::ensure!(pixel == color, "pixel ({x}, {y}): {pixel:?}");
+        }
+        Ok(())
+    })
+}
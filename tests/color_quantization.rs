@@ -0,0 +1,54 @@
+//! Color quantization
+//!
+//! This tests `set_color_quantization`, by drawing colors with the built-in
+//! palettes and checking that they snap to the nearest palette entry.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::{WxBackend, palette};
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_color_quantization() -> Result<()> {
+    run_wx_test(|| {
+        let image = render_rgba_image(100, 50, |dc| {
+            let mut backend = WxBackend::new(dc);
+            backend.set_color_quantization(Some(&palette::CGA[..]));
+            backend.draw_rect(
+                (0, 0),
+                (50, 50),
+                &RGBColor(200, 30, 40),
+                true,
+            )?;
+            backend.set_color_quantization(Some(&palette::GRAYSCALE_4[..]));
+            backend.draw_pixel(
+                (75, 25),
+                RGBColor(100, 110, 90).to_backend_color(),
+            )?;
+            backend.set_color_quantization(None);
+            backend.draw_pixel(
+                (76, 25),
+                RGBColor(100, 110, 90).to_backend_color(),
+            )?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        let checks = [
+            ((25, 25), [0xAA, 0x00, 0x00, 0xFF]),
+            ((75, 25), [85, 85, 85, 255]),
+            ((76, 25), [100, 110, 90, 255]),
+        ];
+        for ((x, y), expected) in checks {
+            let actual = image.get_pixel(x, y).0;
+            anyhow::ensure!(
+                actual == expected,
+                "expected {expected:?} at ({x}, {y}), got {actual:?}"
+            );
+        }
+        Ok(())
+    })
+}
@@ -0,0 +1,76 @@
+//! Colorbar
+//!
+//! This tests `draw_colorbar`, by drawing a vertical grayscale colorbar, and
+//! checking that the gradient goes from black at the bottom to white at the
+//! top, and that tick labels are drawn on its right.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::{LegendOrientation, WxBackend};
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+/// Columns and rows of the gradient strip, outline excluded
+const STRIP_X: std::ops::Range<u32> = 21..39;
+const STRIP_Y: std::ops::Range<u32> = 21..179;
+
+#[test]
+fn test_colorbar() -> Result<()> {
+    run_wx_test(|| {
+        let image = render_rgba_image(120, 200, |dc| {
+            let mut backend = WxBackend::new(dc);
+            let label_style = TextStyle::from(("sans-serif", 15).into_font());
+            let gray = |value: f64| {
+                let level = (value * 255.0).round() as u8;
+                RGBColor(level, level, level).to_backend_color()
+            };
+            backend.draw_colorbar(
+                ((20, 20), (40, 180)),
+                gray,
+                0.0..1.0,
+                LegendOrientation::Vertical,
+                &label_style,
+            )?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        // gray levels along the strip, from the bottom up
+        let levels: Vec<u8> = STRIP_Y
+            .rev()
+            .map(|y| image.get_pixel(30, y).0)
+            .map(|[r, g, b, _]| {
+                anyhow::ensure!(r == g && g == b, "not gray: {r} {g} {b}");
+                Ok(r)
+            })
+            .collect::<Result<_>>()?;
+        anyhow::ensure!(
+            levels.windows(2).all(|w| w[0] <= w[1]),
+            "gradient is not increasing: {levels:?}"
+        );
+        let (bottom, top) = (levels[0], levels[levels.len() - 1]);
+        anyhow::ensure!(bottom < 10, "bottom of the gradient: {bottom}");
+        anyhow::ensure!(top > 245, "top of the gradient: {top}");
+
+        // the strip is uniform across its width
+        for y in STRIP_Y {
+            let center = image.get_pixel(30, y).0;
+            anyhow::ensure!(
+                STRIP_X
+                    .map(|x| image.get_pixel(x, y).0)
+                    .all(|p| p == center),
+                "row {y} is not uniform"
+            );
+        }
+
+        // labels are drawn right of the strip
+        let ink = image
+            .enumerate_pixels()
+            .filter(|(x, _, pixel)| *x > 46 && pixel.0 != [255, 255, 255, 255])
+            .count();
+        anyhow::ensure!(ink > 0, "labels were not drawn");
+        Ok(())
+    })
+}
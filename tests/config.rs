@@ -0,0 +1,75 @@
+//! Backend configuration
+//!
+//! This tests `WxBackend::config` and `WxBackend::new_with_config`: a backend
+//! created with the configuration of another one, over another `MemoryDC`,
+//! draws the same image.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::{BackendConfig, WxBackend, palette};
+use wxdragon as wx;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+const WIDTH: u32 = 200;
+const HEIGHT: u32 = 100;
+
+#[test]
+fn test_config() -> Result<()> {
+    run_wx_test(|| {
+        let mut config = BackendConfig::default();
+        let configured = render_rgba_image(WIDTH, HEIGHT, |dc| {
+            let backend = WxBackend::new_with_dpi(dc, 120.0);
+            backend.set_background_color(wx::Colour::rgb(250, 240, 200));
+            backend.set_background_mode(wx::BackgroundMode::Solid);
+            backend.set_global_alpha(0.5);
+            backend.set_color_quantization(Some(&palette::CGA[..]));
+            backend.set_tabular_figures(true);
+            backend.set_scale(2.0, 1.5)?;
+            backend.set_offset((10, 5))?;
+            backend.set_flip_y(true)?;
+            backend.clear();
+            config = backend.config();
+            draw(backend)
+        })?;
+        let replicated = render_rgba_image(WIDTH, HEIGHT, |dc| {
+            draw(WxBackend::new_with_config(dc, &config))
+        })?;
+
+        anyhow::ensure!(config.dpi == 120.0, "dpi is {}", config.dpi);
+        anyhow::ensure!(
+            config.global_alpha == 0.5,
+            "global alpha is {}",
+            config.global_alpha
+        );
+        anyhow::ensure!(
+            config.scale == (2.0, 1.5),
+            "scale is {:?}",
+            config.scale
+        );
+        anyhow::ensure!(
+            config.offset == (10, 5),
+            "offset is {:?}",
+            config.offset
+        );
+        anyhow::ensure!(config.flip_y, "vertical axis not flipped");
+        anyhow::ensure!(
+            configured == replicated,
+            "backend created from the configuration draws a different image"
+        );
+        Ok(())
+    })
+}
+
+fn draw(mut backend: WxBackend<wx::MemoryDC>) -> Result<()> {
+    backend.draw_rect((10, 10), (90, 90), &RGBColor(200, 30, 40), true)?;
+    backend.draw_text(
+        "12.5",
+        &TextStyle::from(("sans-serif", 30).into_font()),
+        (100, 30),
+    )?;
+    backend.present()?;
+    Ok(())
+}
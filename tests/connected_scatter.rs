@@ -0,0 +1,63 @@
+//! Connected scatter plots
+//!
+//! This tests that `draw_connected_scatter` draws the same image as the two
+//! plotters passes it replaces: a path through the points, then a circle on
+//! each point.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::{MarkerShape, WxBackend};
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+const WIDTH: u32 = 200;
+const HEIGHT: u32 = 150;
+
+#[test]
+fn test_connected_scatter() -> Result<()> {
+    run_wx_test(|| {
+        let points = points();
+        let line_style = BLUE.stroke_width(2);
+        let marker_style = RED.filled();
+
+        let one_pass = render_rgba_image(WIDTH, HEIGHT, |dc| {
+            let mut backend = WxBackend::new(dc);
+            backend.draw_connected_scatter(
+                &points,
+                &line_style,
+                MarkerShape::Circle,
+                4,
+                &marker_style,
+            )?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        let two_passes = render_rgba_image(WIDTH, HEIGHT, |dc| {
+            let mut backend = WxBackend::new(dc);
+            backend.draw_path(points.iter().copied(), &line_style)?;
+            for &point in &points {
+                backend.draw_circle(point, 4, &marker_style, true)?;
+            }
+            backend.present()?;
+            Ok(())
+        })?;
+
+        anyhow::ensure!(
+            one_pass == two_passes,
+            "connected scatter differs from a path and circles"
+        );
+        let marker = one_pass.get_pixel(100, 75).0;
+        anyhow::ensure!(marker == [255, 0, 0, 255], "marker color: {marker:?}");
+        Ok(())
+    })
+}
+
+/// A zigzag through the center of the canvas.
+fn points() -> Vec<(i32, i32)> {
+    (0..=10)
+        .map(|i| (20 * i, if i % 2 == 0 { 75 } else { 25 + 10 * i }))
+        .collect()
+}
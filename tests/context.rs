@@ -0,0 +1,48 @@
+//! Direct access to the device context
+//!
+//! This tests `context`, by drawing on the device context between two
+//! primitives of the backend, and checking that the style of the backend does
+//! not leak into the direct drawing, nor the other way round.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+use wxdragon as wx;
+use wxdragon::DeviceContext;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_context() -> Result<()> {
+    run_wx_test(|| {
+        let image = render_rgba_image(90, 30, |dc| {
+            let mut backend = WxBackend::new(dc);
+            backend.draw_rect((0, 0), (29, 29), &RED, true)?;
+            backend.flush()?;
+            let context = backend.context();
+            let blue = wx::Colour::rgb(0, 0, 255);
+            context.set_pen(blue, 0, wx::PenStyle::Transparent);
+            context.set_brush(blue, wx::BrushStyle::Solid);
+            context.draw_rectangle(30, 0, 30, 30);
+            backend.draw_rect((60, 0), (89, 29), &RED, true)?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        let checks = [
+            (15, [255, 0, 0, 255]),
+            (45, [0, 0, 255, 255]),
+            (75, [255, 0, 0, 255]),
+        ];
+        for (x, expected) in checks {
+            let pixel = image.get_pixel(x, 15).0;
+            anyhow::ensure!(
+                pixel == expected,
+                "pixel at ({x}, 15) is {pixel:?} instead of {expected:?}"
+            );
+        }
+        Ok(())
+    })
+}
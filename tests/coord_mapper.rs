@@ -0,0 +1,57 @@
+//! Conversion between data coordinates and pixels
+//!
+//! These tests check that `CoordMapper` maps data coordinates to the pixels
+//! plotters draws them at, and pixels back to data coordinates.
+
+use plotters::prelude::*;
+use plotters_wxdragon::CoordMapper;
+
+const X_RANGE: std::ops::Range<f64> = -2.0..2.0;
+const Y_RANGE: std::ops::Range<f64> = 0.0..10.0;
+
+/// Builds a chart on a plotters `BitMapBackend`, and returns a mapper for its
+/// plotting area, with the pixels of `points` computed by plotters.
+fn chart_pixels(points: &[(f64, f64)]) -> (CoordMapper, Vec<(i32, i32)>) {
+    let mut buffer = vec![0; 300 * 200 * 3];
+    let root =
+        BitMapBackend::with_buffer(&mut buffer, (300, 200)).into_drawing_area();
+    let chart = ChartBuilder::on(&root)
+        .margin(10)
+        .x_label_area_size(30)
+        .y_label_area_size(40)
+        .build_cartesian_2d(X_RANGE, Y_RANGE)
+        .expect("build chart");
+    let mapper = CoordMapper::new(
+        chart.plotting_area().get_pixel_range(),
+        X_RANGE,
+        Y_RANGE,
+    );
+    let pixels = points.iter().map(|p| chart.backend_coord(p)).collect();
+    (mapper, pixels)
+}
+
+#[test]
+fn test_to_pixel() {
+    let points = [
+        (-2.0, 0.0),
+        (2.0, 0.0),
+        (-2.0, 10.0),
+        (2.0, 10.0),
+        (0.0, 5.0),
+        (0.7, 3.3),
+    ];
+    let (mapper, expected) = chart_pixels(&points);
+    for (point, expected) in points.into_iter().zip(expected) {
+        assert_eq!(mapper.to_pixel(point), expected, "{point:?}");
+    }
+}
+
+#[test]
+fn test_to_data() {
+    let (mapper, pixels) = chart_pixels(&[(-2.0, 0.0), (0.0, 5.0)]);
+    let (x, y) = mapper.to_data(pixels[0]);
+    assert!((x + 2.0).abs() < 1e-12 && y.abs() < 1e-12, "({x}, {y})");
+    let (x, y) = mapper.to_data(pixels[1]);
+    // within one pixel of the center
+    assert!(x.abs() < 0.02 && (y - 5.0).abs() < 0.1, "({x}, {y})");
+}
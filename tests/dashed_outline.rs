@@ -0,0 +1,71 @@
+//! Dashed outline of filled shapes
+//!
+//! This tests `set_shape_outline_style`, by drawing a blue rectangle and a
+//! blue rectangular polygon with a red dashed outline, then a line and a
+//! filled rectangle with the default style, and checking that the outlines
+//! have gaps, that the insides are solid, and that the pen is solid again
+//! afterwards.
+
+mod test_utils;
+
+use anyhow::Result;
+use image::RgbaImage;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+use wxdragon as wx;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+const RED_PIXEL: [u8; 4] = [255, 0, 0, 255];
+const BLUE_PIXEL: [u8; 4] = [0, 0, 255, 255];
+
+#[test]
+fn test_dashed_outline() -> Result<()> {
+    run_wx_test(|| {
+        let image = render_rgba_image(200, 200, |dc| {
+            let mut backend = WxBackend::new(dc);
+            let red = wx::Colour::rgb(255, 0, 0);
+            backend.set_shape_outline(Some((red, 1)));
+            backend.set_shape_outline_style(wx::PenStyle::ShortDash);
+            backend.draw_rect((20, 20), (180, 60), &BLUE, true)?;
+            backend.set_shape_outline(Some((red, 1)));
+            backend.set_shape_outline_style(wx::PenStyle::ShortDash);
+            backend.fill_polygon(
+                vec![(20, 80), (180, 80), (180, 120), (20, 120)],
+                &BLUE,
+            )?;
+            backend.draw_line((20, 140), (180, 140), &RED)?;
+            backend.draw_rect((20, 160), (180, 190), &RED, true)?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        for (shape, y) in [("rectangle", 20), ("polygon", 80)] {
+            let dashes = count(&image, y, RED_PIXEL);
+            anyhow::ensure!(
+                dashes > 20 && dashes < 130,
+                "{shape} outline has {dashes} of 150 pixels drawn"
+            );
+            let inside = count(&image, y + 20, BLUE_PIXEL);
+            anyhow::ensure!(
+                inside == 150,
+                "{shape} inside has {inside} of 150 pixels filled"
+            );
+        }
+        let line = count(&image, 140, RED_PIXEL);
+        anyhow::ensure!(line == 150, "line has {line} of 150 pixels drawn");
+        let outline = count(&image, 160, RED_PIXEL);
+        anyhow::ensure!(
+            outline == 150,
+            "default outline has {outline} of 150 pixels drawn"
+        );
+        Ok(())
+    })
+}
+
+/// Counts the pixels of row `y` of a color, between x = 25 and x = 175.
+fn count(image: &RgbaImage, y: u32, color: [u8; 4]) -> usize {
+    (25..175)
+        .filter(|&x| image.get_pixel(x, y).0 == color)
+        .count()
+}
@@ -0,0 +1,40 @@
+//! Degenerate paths and polygons
+//!
+//! This tests `draw_path` and `fill_polygon` with empty and single-point
+//! inputs: nothing is drawn, except a single point for a single-point path.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_degenerate_paths() -> Result<()> {
+    run_wx_test(|| {
+        let image = render_rgba_image(100, 100, |dc| {
+            let mut backend = WxBackend::new(dc);
+            backend.draw_path(vec![], &RED)?;
+            backend.fill_polygon(vec![], &RED)?;
+            backend.draw_path(vec![(50, 50)], &RED)?;
+            backend.fill_polygon(vec![(20, 20)], &RED)?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        for (x, y, pixel) in image.enumerate_pixels() {
+            let expected = match (x, y) {
+                (50, 50) => [255, 0, 0, 255],
+                _ => [255, 255, 255, 255],
+            };
+            anyhow::ensure!(
+                pixel.0 == expected,
+                "unexpected pixel {:?} at ({x}, {y})",
+                pixel.0
+            );
+        }
+        Ok(())
+    })
+}
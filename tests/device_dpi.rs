@@ -0,0 +1,29 @@
+//! Resolution of the device context
+//!
+//! This tests `WxBackend::dpi` on a `MemoryDC`, which reports the 96 dots per
+//! inch that font sizes are converted with by default.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters_wxdragon::WxBackend;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_device_dpi() -> Result<()> {
+    run_wx_test(|| {
+        render_rgba_image(100, 80, |dc| {
+            let backend = WxBackend::new(dc);
+            let dpi = backend.dpi();
+            anyhow::ensure!(dpi == (96, 96), "resolution is {dpi:?}");
+            let config_dpi = backend.config().dpi;
+            anyhow::ensure!(
+                config_dpi == 96.0,
+                "font resolution is {config_dpi}"
+            );
+            Ok(())
+        })?;
+        Ok(())
+    })
+}
@@ -0,0 +1,81 @@
+//! Explicit resolution
+//!
+//! This tests `WxBackend::new_with_dpi`: at 120 dpi, a font of a given pixel
+//! size is converted to fewer points than at 96 dpi, so that text rendered on
+//! the same 96 dpi `MemoryDC` is smaller by a factor 96/120. Text of 60 pixels
+//! at 120 dpi and text of 48 pixels at 96 dpi are both 36 points, and must be
+//! rendered identically. A resolution which is not a positive number falls
+//! back to 96 dpi.
+
+mod test_utils;
+
+use anyhow::Result;
+use image::RgbaImage;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+const TEXT: &str = "Hello, DPI";
+
+/// Render the text with a font of `size` pixels, at a resolution of `dpi`
+fn render_text(size: u32, dpi: f64) -> Result<RgbaImage> {
+    render_rgba_image(400, 100, |dc| {
+        let mut backend = WxBackend::new_with_dpi(dc, dpi);
+        let style = TextStyle::from(("sans-serif", size).into_font());
+        backend.draw_text(TEXT, &style, (10, 10))?;
+        backend.present()?;
+        Ok(())
+    })
+}
+
+#[test]
+fn test_dpi() -> Result<()> {
+    run_wx_test(|| {
+        let style = TextStyle::from(("sans-serif", 60).into_font());
+        let mut size_96 = (0, 0);
+        let mut size_120 = (0, 0);
+        let mut invalid_sizes = Vec::new();
+        render_rgba_image(400, 100, |dc| {
+            size_96 = WxBackend::new_with_dpi(dc, 96.0)
+                .estimate_text_size(TEXT, &style)?;
+            size_120 = WxBackend::new_with_dpi(dc, 120.0)
+                .estimate_text_size(TEXT, &style)?;
+            for dpi in [0.0, -96.0, f64::NAN, f64::INFINITY] {
+                let size = WxBackend::new_with_dpi(dc, dpi)
+                    .estimate_text_size(TEXT, &style)?;
+                invalid_sizes.push((dpi, size));
+            }
+            Ok(())
+        })?;
+
+        let expected = size_96.0 as f64 * 96.0 / 120.0;
+        anyhow::ensure!(
+            (size_120.0 as f64 - expected).abs() / expected < 0.1,
+            "text width at 120 dpi is {}, expected ~{expected:.0} from the \
+             width {} at 96 dpi",
+            size_120.0,
+            size_96.0
+        );
+        anyhow::ensure!(
+            size_120.1 < size_96.1,
+            "text height at 120 dpi ({}) is not smaller than at 96 dpi ({})",
+            size_120.1,
+            size_96.1
+        );
+        for (dpi, size) in invalid_sizes {
+            anyhow::ensure!(
+                size == size_96,
+                "text size at {dpi} dpi is {size:?} instead of {size_96:?}"
+            );
+        }
+
+        let expected = render_text(48, 96.0)?;
+        let image = render_text(60, 120.0)?;
+        anyhow::ensure!(
+            image == expected,
+            "36 point text differs between 96 dpi and 120 dpi"
+        );
+        Ok(())
+    })
+}
@@ -0,0 +1,63 @@
+//! Batch drawing of pixels
+//!
+//! This tests that `draw_pixels` produces the same output as calling
+//! `draw_pixel` for each pixel, for a translucent gradient field drawn over a
+//! line.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_backend::{BackendColor, BackendCoord};
+use plotters_wxdragon::WxBackend;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+const WIDTH: u32 = 200;
+const HEIGHT: u32 = 150;
+
+#[test]
+fn test_draw_pixels() -> Result<()> {
+    run_wx_test(|| {
+        let batched = render_rgba_image(WIDTH, HEIGHT, |dc| {
+            let mut backend = WxBackend::new(dc);
+            backend.set_global_alpha(0.8);
+            backend.draw_line((0, 0), (199, 149), &BLACK)?;
+            backend.draw_pixels(gradient())?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        let single = render_rgba_image(WIDTH, HEIGHT, |dc| {
+            let mut backend = WxBackend::new(dc);
+            backend.set_global_alpha(0.8);
+            backend.draw_line((0, 0), (199, 149), &BLACK)?;
+            for (point, color) in gradient() {
+                backend.draw_pixel(point, color)?;
+            }
+            backend.present()?;
+            Ok(())
+        })?;
+
+        anyhow::ensure!(
+            batched == single,
+            "draw_pixels differs from draw_pixel"
+        );
+        Ok(())
+    })
+}
+
+/// A gradient field covering the canvas, translucent on the right half,
+/// with a few pixels outside the canvas.
+fn gradient() -> impl Iterator<Item = (BackendCoord, BackendColor)> {
+    (-2..HEIGHT as i32 + 2).flat_map(|y| {
+        (-2..WIDTH as i32 + 2).map(move |x| {
+            let alpha = if x < WIDTH as i32 / 2 { 1.0 } else { 0.5 };
+            let color = BackendColor {
+                alpha,
+                rgb: (x as u8, y as u8, (x ^ y) as u8),
+            };
+            ((x, y), color)
+        })
+    })
+}
@@ -0,0 +1,90 @@
+//! Shapes with extreme coordinates
+//!
+//! This tests that drawing with coordinates near the limits of `i32`, as
+//! produced by data far out of the range of the axes, does not overflow, and
+//! that the visible part of the shapes is still drawn. This covers every
+//! primitive which takes coordinates, including the markers of connected
+//! scatter plots and check marks with corners in reverse order.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::{MarkerShape, WxBackend};
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_extreme_coords() -> Result<()> {
+    run_wx_test(|| {
+        let (min, max) = (i32::MIN, i32::MAX);
+        let image = render_rgba_image(100, 60, |dc| {
+            let mut backend = WxBackend::new(dc);
+            backend.set_clip_rect(Some(((min, min), (max, max))))?;
+            backend.draw_rect((min, min), (max, max), &WHITE, true)?;
+            backend.draw_rect((min, 10), (49, 49), &RED, true)?;
+            backend.draw_rect((50, 10), (max, 49), &BLUE, false)?;
+            backend.draw_band(min, max, (min, 5), GREEN.to_backend_color())?;
+            backend.draw_circle((max, max), u32::MAX, &BLACK, false)?;
+            backend
+                .fill_polygon(vec![(80, 15), (max, 15), (80, max)], &YELLOW)?;
+            backend.draw_path([(min, 52), (max, 52)], &CYAN)?;
+            backend.draw_line((min, 55), (max, 55), &BLACK)?;
+            backend.draw_connected_scatter(
+                &[(min, 57), (max, 57)],
+                &MAGENTA,
+                MarkerShape::Square,
+                2,
+                &MAGENTA,
+            )?;
+            backend.draw_connected_scatter(
+                &[(min, min)],
+                &MAGENTA,
+                MarkerShape::Circle,
+                u32::MAX,
+                &MAGENTA,
+            )?;
+            backend.draw_check_mark((max, 59), (min, 58), &BLACK)?;
+            backend.draw_cross((max, max), (max - 1, max - 1), &BLACK)?;
+            backend.draw_xor_line(
+                (min, max),
+                (min, min),
+                WHITE.to_backend_color(),
+            )?;
+            let src = [0, 0, 0, 255].repeat(4 * 4);
+            backend.blit_bitmap((max, min), (4, 4), &src)?;
+            backend.blit_bitmap_with_mask(
+                (min, max),
+                (4, 4),
+                &src,
+                &[1; 16],
+            )?;
+            backend.draw_text(
+                "far",
+                &TextStyle::from(("sans-serif", 12).into_font()),
+                (max, min),
+            )?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        let checks = [
+            ((2, 2), [0, 255, 0, 255]),
+            ((25, 30), [255, 0, 0, 255]),
+            ((0, 30), [255, 0, 0, 255]),
+            ((75, 30), [255, 255, 255, 255]),
+            ((85, 25), [255, 255, 0, 255]),
+            ((30, 52), [0, 255, 255, 255]),
+            ((30, 55), [0, 0, 0, 255]),
+            ((30, 57), [255, 0, 255, 255]),
+        ];
+        for ((x, y), expected) in checks {
+            let pixel = image.get_pixel(x, y).0;
+            anyhow::ensure!(
+                pixel == expected,
+                "pixel at ({x}, {y}) is {pixel:?} instead of {expected:?}"
+            );
+        }
+        Ok(())
+    })
+}
@@ -0,0 +1,122 @@
+//! Fidelity to the plotters `BitMapBackend`
+//!
+//! This draws a few standard plots with both the plotters `BitMapBackend` and
+//! `WxBackend`, and compares the images. The plots have no text, because the
+//! two backends use different font engines.
+//!
+//! The acceptable tolerance is a channel delta of 64, which absorbs the
+//! anti-aliasing of wxWidgets on edges, for at most 2% of pixels, which
+//! catches systematic offsets such as a shape drawn one pixel off.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+
+use test_utils::{
+    ImageTolerance, PlotFn, compare_with_bitmap_backend, run_wx_test,
+};
+
+const TOLERANCE: ImageTolerance = ImageTolerance {
+    max_channel_delta: 64,
+    max_differing_ratio: 0.02,
+};
+
+#[test]
+fn test_fidelity() -> Result<()> {
+    run_wx_test(|| {
+        compare_with_bitmap_backend(
+            400,
+            300,
+            "tests/fidelity_shapes",
+            &Shapes,
+            TOLERANCE,
+        )?;
+        compare_with_bitmap_backend(
+            400,
+            300,
+            "tests/fidelity_lines",
+            &Lines,
+            TOLERANCE,
+        )?;
+        compare_with_bitmap_backend(
+            400,
+            300,
+            "tests/fidelity_area",
+            &Area,
+            TOLERANCE,
+        )?;
+        Ok(())
+    })
+}
+
+/// Filled and outlined rectangles and circles
+struct Shapes;
+
+impl PlotFn for Shapes {
+    fn draw<DB>(&self, backend: DB) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        let root = backend.into_drawing_area();
+        root.fill(&WHITE)?;
+        root.draw(&Rectangle::new([(20, 20), (120, 100)], RED.filled()))?;
+        root.draw(&Rectangle::new([(150, 20), (250, 100)], BLUE))?;
+        root.draw(&Circle::new((80, 200), 50, GREEN.filled()))?;
+        root.draw(&Circle::new((200, 200), 50, BLACK))?;
+        root.present()?;
+        Ok(())
+    }
+}
+
+/// Line series in a chart without labels
+struct Lines;
+
+impl PlotFn for Lines {
+    fn draw<DB>(&self, backend: DB) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        let root = backend.into_drawing_area();
+        root.fill(&WHITE)?;
+        let mut chart = ChartBuilder::on(&root)
+            .margin(10)
+            .build_cartesian_2d(-3.2f64..3.2, -1.2f64..1.2)?;
+        let xs = (-320..=320).map(|x| x as f64 / 100.0);
+        chart.draw_series(LineSeries::new(
+            xs.clone().map(|x| (x, x.sin())),
+            &RED,
+        ))?;
+        chart.draw_series(LineSeries::new(xs.map(|x| (x, x.cos())), &BLUE))?;
+        root.present()?;
+        Ok(())
+    }
+}
+
+/// Area series, drawn as a filled polygon
+struct Area;
+
+impl PlotFn for Area {
+    fn draw<DB>(&self, backend: DB) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        let root = backend.into_drawing_area();
+        root.fill(&WHITE)?;
+        let mut chart = ChartBuilder::on(&root)
+            .margin(10)
+            .build_cartesian_2d(0f64..10.0, 0f64..10.0)?;
+        chart.draw_series(AreaSeries::new(
+            (0..=100)
+                .map(|x| x as f64 / 10.0)
+                .map(|x| (x, 5.0 + 4.0 * (x / 2.0).sin())),
+            0.0,
+            BLUE.mix(0.5),
+        ))?;
+        root.present()?;
+        Ok(())
+    }
+}
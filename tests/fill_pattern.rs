@@ -0,0 +1,90 @@
+//! Hatched fills
+//!
+//! This tests `set_fill_pattern`, by drawing bars with a hatch pattern and a
+//! solid bar, and checking that the hatched bars show both the fill color
+//! and the background, and that the pattern does not apply to outlines.
+//! The reference image is the same shapes drawn by wxWidgets with hatch
+//! brushes, rather than a PNG fixture, as the spacing and the phase of
+//! hatches are chosen by each platform.
+
+mod test_utils;
+
+use anyhow::Result;
+use image::RgbaImage;
+use plotters::prelude::*;
+use plotters_wxdragon::{FillPattern, WxBackend};
+use wxdragon::{self as wx, DeviceContext};
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+const RED_PIXEL: [u8; 4] = [255, 0, 0, 255];
+const WHITE_PIXEL: [u8; 4] = [255, 255, 255, 255];
+
+#[test]
+fn test_fill_pattern() -> Result<()> {
+    run_wx_test(|| {
+        let image = render_rgba_image(200, 100, |dc| {
+            let mut backend = WxBackend::new(dc);
+            backend.set_fill_pattern(FillPattern::ForwardDiagonal);
+            anyhow::ensure!(
+                backend.fill_pattern() == FillPattern::ForwardDiagonal,
+                "fill pattern is {:?}",
+                backend.fill_pattern()
+            );
+            backend.draw_rect((10, 20), (50, 90), &RED, true)?;
+            backend.fill_polygon([(60, 90), (100, 20), (100, 90)], &RED)?;
+            backend.set_fill_pattern(FillPattern::default());
+            backend.draw_rect((110, 20), (150, 90), &RED, true)?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        for (name, (x0, x1)) in [("bar", (15, 45)), ("polygon", (92, 98))] {
+            let inside = pixels(&image, (x0, x1), (60, 85));
+            anyhow::ensure!(
+                inside.contains(&RED_PIXEL) && inside.contains(&WHITE_PIXEL),
+                "hatched {name} is not hatched"
+            );
+        }
+        let solid = pixels(&image, (115, 145), (25, 85));
+        anyhow::ensure!(
+            solid.iter().all(|&p| p == RED_PIXEL),
+            "solid bar is not solid"
+        );
+        anyhow::ensure!(
+            image == render_reference()?,
+            "hatched shapes differ from shapes drawn with a hatch brush"
+        );
+        Ok(())
+    })
+}
+
+/// Draw the shapes directly on the device context, with a hatch brush.
+fn render_reference() -> Result<RgbaImage> {
+    render_rgba_image(200, 100, |dc| {
+        let backend = WxBackend::new(dc);
+        let dc = backend.context();
+        let red = wx::Colour::rgb(255, 0, 0);
+        dc.set_pen(red, 1, wx::PenStyle::Solid);
+        dc.set_brush(red, wx::BrushStyle::FDiagonalHatch);
+        dc.draw_rectangle(10, 20, 40, 70);
+        let triangle = [(60, 90), (100, 20), (100, 90)]
+            .map(|(x, y)| wx::dc::Point::new(x, y));
+        let fill_mode = wx::dc::PolygonFillMode::OddEven;
+        dc.draw_polygon(&triangle, 0, 0, fill_mode);
+        dc.set_brush(red, wx::BrushStyle::Solid);
+        dc.draw_rectangle(110, 20, 40, 70);
+        Ok(())
+    })
+}
+
+/// Pixels of the rectangle of columns `x0..x1` and rows `y0..y1`.
+fn pixels(
+    image: &RgbaImage,
+    (x0, x1): (u32, u32),
+    (y0, y1): (u32, u32),
+) -> Vec<[u8; 4]> {
+    (y0..y1)
+        .flat_map(|y| (x0..x1).map(move |x| image.get_pixel(x, y).0))
+        .collect()
+}
@@ -0,0 +1,65 @@
+//! Flipped vertical axis
+//!
+//! This tests `set_flip_y`, by drawing an arrow pointing up with y increasing
+//! upward, and a text anchored at its bottom, and checking that the arrow
+//! points to the top of the image and that the text is above its position.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters::style::text_anchor::{HPos, Pos, VPos};
+use plotters_wxdragon::WxBackend;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_flip_y() -> Result<()> {
+    run_wx_test(|| {
+        let image = render_rgba_image(100, 100, |dc| {
+            let mut backend = WxBackend::new(dc);
+            backend.set_flip_y(true)?;
+            anyhow::ensure!(backend.flip_y(), "vertical axis not flipped");
+            // shaft from y = 10 to y = 60, head from y = 60 to y = 90
+            backend.draw_rect((45, 10), (54, 59), &RED, true)?;
+            backend.fill_polygon(vec![(30, 60), (70, 60), (50, 90)], &RED)?;
+            let style = TextStyle::from(("sans-serif", 12).into_font())
+                .color(&BLUE)
+                .pos(Pos::new(HPos::Left, VPos::Bottom));
+            backend.draw_text("up", &style, (70, 10))?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        let red = [255, 0, 0, 255];
+        let white = [255, 255, 255, 255];
+        // y = 10 is row 89, y = 90 is row 9
+        let checks = [
+            ((50, 85), red),
+            ((50, 45), red),
+            ((50, 15), red),
+            ((35, 38), red),
+            ((35, 80), white),
+            ((50, 5), white),
+        ];
+        for ((x, y), expected) in checks {
+            let pixel = image.get_pixel(x, y).0;
+            anyhow::ensure!(
+                pixel == expected,
+                "pixel at ({x}, {y}) is {pixel:?} instead of {expected:?}"
+            );
+        }
+
+        let text_rows: Vec<u32> = image
+            .enumerate_pixels()
+            .filter(|(x, _, pixel)| *x >= 70 && pixel.0 != white)
+            .map(|(_, y, _)| y)
+            .collect();
+        anyhow::ensure!(!text_rows.is_empty(), "text not drawn");
+        anyhow::ensure!(
+            text_rows.iter().all(|&y| y <= 89),
+            "text below its position: rows {text_rows:?}"
+        );
+        Ok(())
+    })
+}
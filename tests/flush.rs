@@ -0,0 +1,31 @@
+//! Explicit flush
+//!
+//! This tests that `flush` commits buffered pixels to the device context
+//! before `present`, by reading them back from the device context.
+
+mod test_utils;
+
+use anyhow::{Context, Result};
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+use wxdragon::DeviceContext;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_flush() -> Result<()> {
+    run_wx_test(|| {
+        render_rgba_image(20, 20, |dc| {
+            let mut backend = WxBackend::new(dc);
+            backend.draw_pixel((5, 5), RED.to_backend_color())?;
+            backend.flush()?;
+            let pixel = dc.get_pixel(5, 5).context("failed to get pixel")?;
+            anyhow::ensure!(
+                (pixel.r, pixel.g, pixel.b) == (255, 0, 0),
+                "pixel not committed after flush: {pixel:?}"
+            );
+            Ok(())
+        })?;
+        Ok(())
+    })
+}
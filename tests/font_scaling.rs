@@ -0,0 +1,65 @@
+//! Font size conversion from pixels to points
+//!
+//! This tests that the text size estimated by the backend matches the glyphs
+//! it draws, at 96 dpi and at 192 dpi: the ink of the text fits in the
+//! estimated size, and fills most of its width. At 192 dpi, the same plotters
+//! font is half as large on the 96 dpi `MemoryDC`.
+
+mod test_utils;
+
+use anyhow::{Context, Result};
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+const TEXT: &str = "Hello, world";
+const ORIGIN: (i32, i32) = (10, 10);
+
+#[test]
+fn test_font_scaling() -> Result<()> {
+    run_wx_test(|| {
+        let mut widths = Vec::new();
+        for dpi in [96.0, 192.0] {
+            let mut estimated = (0, 0);
+            let image = render_rgba_image(500, 150, |dc| {
+                let mut backend = WxBackend::new_with_dpi(dc, dpi);
+                let style = TextStyle::from(("sans-serif", 60).into_font());
+                estimated = backend.estimate_text_size(TEXT, &style)?;
+                backend.draw_text(TEXT, &style, ORIGIN)?;
+                backend.present()?;
+                Ok(())
+            })?;
+
+            let ink: Vec<(u32, u32)> = image
+                .enumerate_pixels()
+                .filter(|(_, _, p)| p.0 != [255, 255, 255, 255])
+                .map(|(x, y, _)| (x, y))
+                .collect();
+            let x_min = ink.iter().map(|p| p.0).min().context("no ink")?;
+            let x_max = ink.iter().map(|p| p.0).max().context("no ink")?;
+            let y_max = ink.iter().map(|p| p.1).max().context("no ink")?;
+            let ink_width = x_max - x_min + 1;
+            let (width, height) = estimated;
+            anyhow::ensure!(
+                x_min >= ORIGIN.0 as u32
+                    && x_max < ORIGIN.0 as u32 + width + 2
+                    && y_max < ORIGIN.1 as u32 + height + 2,
+                "{dpi} dpi: ink exceeds the estimated size {estimated:?}"
+            );
+            anyhow::ensure!(
+                ink_width as f64 > 0.85 * width as f64,
+                "{dpi} dpi: ink is {ink_width} pixels wide, estimated \
+                 width is {width}"
+            );
+            widths.push(width as f64);
+        }
+
+        let ratio = widths[0] / widths[1];
+        anyhow::ensure!(
+            (ratio - 2.0).abs() < 0.2,
+            "text is {ratio:.2} times wider at 96 dpi than at 192 dpi"
+        );
+        Ok(())
+    })
+}
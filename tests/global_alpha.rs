@@ -0,0 +1,45 @@
+//! Global alpha factor
+//!
+//! This tests that `set_global_alpha` applies to opaque colors, by drawing an
+//! opaque red rectangle at half global alpha over a white background, and
+//! checking the effective alpha of the blended pixels.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_global_alpha() -> Result<()> {
+    run_wx_test(|| {
+        let image = render_rgba_image(100, 100, |dc| {
+            let mut backend = WxBackend::new(dc);
+            backend.set_global_alpha(0.5);
+            backend.draw_rect((10, 10), (90, 90), &RED, true)?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        // Over white, red at alpha `a` blends to (255, 255 - a, 255 - a).
+        let [r, g, b, _] = image.get_pixel(50, 50).0;
+        anyhow::ensure!(r == 255, "unexpected red channel {r}");
+        for channel in [g, b] {
+            let alpha = 255 - channel;
+            anyhow::ensure!(
+                alpha.abs_diff(128) <= 2,
+                "expected an effective alpha of ~128, got {alpha}"
+            );
+        }
+
+        // Outside of the rectangle, the background is untouched.
+        let outside = image.get_pixel(5, 5).0;
+        anyhow::ensure!(
+            outside == [255, 255, 255, 255],
+            "background was modified: {outside:?}"
+        );
+        Ok(())
+    })
+}
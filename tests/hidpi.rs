@@ -0,0 +1,48 @@
+//! High-DPI rendering
+//!
+//! This tests `WxBackendBuilder::scale`, by drawing at a scale of 2 on a bitmap
+//! twice as large as the plot, as for a high-DPI display, and checking that
+//! the backend reports the logical size and that shapes cover twice as many
+//! device pixels.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_hidpi() -> Result<()> {
+    run_wx_test(|| {
+        let image = render_rgba_image(200, 160, |dc| {
+            let mut backend = WxBackend::builder(dc).scale(2.0, 2.0).build();
+            let scale = backend.scale();
+            anyhow::ensure!(scale == (2.0, 2.0), "scale is {scale:?}");
+            let size = backend.get_size();
+            anyhow::ensure!(size == (100, 80), "logical size is {size:?}");
+            backend.draw_rect((10, 10), (20, 20), &RED, true)?;
+            backend.draw_pixel((50, 50), BLUE.to_backend_color())?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        let red = [255, 0, 0, 255];
+        let white = [255, 255, 255, 255];
+        let expected = [
+            ((20, 20), red),
+            ((38, 38), red),
+            ((18, 30), white),
+            ((42, 30), white),
+            ((100, 100), [0, 0, 255, 255]),
+            ((101, 101), [0, 0, 255, 255]),
+            ((102, 102), white),
+        ];
+        for ((x, y), color) in expected {
+            let pixel = image.get_pixel(x, y).0;
+            anyhow::ensure!(pixel == color, "pixel ({x}, {y}): {pixel:?}");
+        }
+        Ok(())
+    })
+}
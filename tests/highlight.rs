@@ -0,0 +1,47 @@
+//! Highlight of data points
+//!
+//! This tests `highlight_points`, which draws translucent circles without
+//! outline, and checks that later drawings are not affected by its style.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_highlight() -> Result<()> {
+    run_wx_test(|| {
+        let image = render_rgba_image(200, 100, |dc| {
+            let mut backend = WxBackend::new(dc);
+            let color = RED.mix(0.5).to_backend_color();
+            backend.highlight_points([(50, 50), (100, 50)], 10, color)?;
+            backend.draw_rect((130, 30), (170, 70), &BLUE, false)?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        // Over white, red at alpha 0.5 blends to (255, ~128, ~128).
+        for x in [50, 100] {
+            let [r, g, b, _] = image.get_pixel(x, 50).0;
+            anyhow::ensure!(
+                r == 255 && g.abs_diff(128) <= 2 && b.abs_diff(128) <= 2,
+                "unexpected highlight color {:?} at ({x}, 50)",
+                [r, g, b]
+            );
+        }
+        let outside = image.get_pixel(75, 50).0;
+        anyhow::ensure!(
+            outside == [255, 255, 255, 255],
+            "pixel outside highlights was modified: {outside:?}"
+        );
+        let inside_rect = image.get_pixel(150, 50).0;
+        anyhow::ensure!(
+            inside_rect == [255, 255, 255, 255],
+            "unfilled rectangle after highlights was filled: {inside_rect:?}"
+        );
+        Ok(())
+    })
+}
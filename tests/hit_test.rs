@@ -0,0 +1,46 @@
+//! Hit-testing of series
+//!
+//! These tests check the point-to-segment distance, and the series found by
+//! `nearest_series`.
+
+use plotters_wxdragon::{distance_to_segment, nearest_series};
+
+#[test]
+fn test_distance_to_segment() {
+    let segment = ((0, 0), (10, 0));
+    // projection inside the segment
+    assert_eq!(distance_to_segment((5, 3), segment), 3.0);
+    assert_eq!(distance_to_segment((5, -4), segment), 4.0);
+    // projection beyond either end
+    assert_eq!(distance_to_segment((-3, 4), segment), 5.0);
+    assert_eq!(distance_to_segment((13, -4), segment), 5.0);
+    // on the segment
+    assert_eq!(distance_to_segment((7, 0), segment), 0.0);
+}
+
+#[test]
+fn test_distance_to_diagonal_segment() {
+    let distance = distance_to_segment((0, 10), ((0, 0), (10, 10)));
+    assert!((distance - 50f64.sqrt()).abs() < 1e-12, "{distance}");
+}
+
+#[test]
+fn test_distance_to_degenerate_segment() {
+    assert_eq!(distance_to_segment((3, 4), ((0, 0), (0, 0))), 5.0);
+}
+
+#[test]
+fn test_nearest_series() {
+    let series = vec![
+        vec![(0, 0), (100, 0)],
+        vec![(0, 10), (50, 10), (100, 30)],
+        vec![(50, 50)],
+        vec![],
+    ];
+    assert_eq!(nearest_series(&series, (20, 2), 5.0), Some(0));
+    assert_eq!(nearest_series(&series, (20, 8), 5.0), Some(1));
+    assert_eq!(nearest_series(&series, (52, 50), 5.0), Some(2));
+    // too far from any series
+    assert_eq!(nearest_series(&series, (20, 40), 5.0), None);
+    assert_eq!(nearest_series::<Vec<_>>(&[], (0, 0), 5.0), None);
+}
@@ -0,0 +1,38 @@
+//! Nearest data point of a hover tooltip
+//!
+//! These tests check the data point found by `HoverTooltip::nearest_point`,
+//! with the points mapped to pixels as plotters draws them.
+
+use plotters_wxdragon::{CoordMapper, HoverTooltip};
+
+/// Tooltip over two series, on a plot of 100 x 50 pixels offset by label
+/// areas, showing `0..10` x `0..5`.
+fn tooltip() -> HoverTooltip {
+    let tooltip = HoverTooltip::new(5.0);
+    let mapper = CoordMapper::new((50..150, 10..60), 0.0..10.0, 0.0..5.0);
+    tooltip.set_data(
+        mapper,
+        vec![
+            vec![(1.0, 1.0), (2.0, 2.0), (3.0, 3.0)],
+            vec![(1.0, 4.0), (5.0, 4.0)],
+        ],
+    );
+    tooltip
+}
+
+#[test]
+fn test_nearest_point() {
+    let tooltip = tooltip();
+    // (2.0, 2.0) is drawn at (70, 39)
+    assert_eq!(tooltip.nearest_point((71, 38)), Some((0, (2.0, 2.0))));
+    // (5.0, 4.0) is drawn at (100, 19)
+    assert_eq!(tooltip.nearest_point((100, 22)), Some((1, (5.0, 4.0))));
+}
+
+#[test]
+fn test_no_point_in_reach() {
+    let tooltip = tooltip();
+    assert_eq!(tooltip.nearest_point((140, 55)), None);
+    // without data
+    assert_eq!(HoverTooltip::new(5.0).nearest_point((70, 39)), None);
+}
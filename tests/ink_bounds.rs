@@ -0,0 +1,60 @@
+//! Ink bounds
+//!
+//! This tests `ink_bounds`, by drawing a small mark in the middle of a large
+//! canvas, and checking that the bounds enclose it tightly.
+
+mod test_utils;
+
+use anyhow::{Context, Result};
+use plotters::prelude::*;
+use plotters_wxdragon::{WxBackend, ink_bounds};
+use wxdragon as wx;
+
+use test_utils::run_wx_test;
+
+#[test]
+fn test_ink_bounds() -> Result<()> {
+    run_wx_test(|| {
+        let mut bitmap =
+            wx::Bitmap::new(400, 300).context("failed to create bitmap")?;
+        let mut dc = wx::MemoryDC::new();
+        dc.select_object(&mut bitmap);
+        let white = wx::Colour::rgb(255, 255, 255);
+
+        // a new backend clears the canvas
+        drop(WxBackend::new(&dc));
+        anyhow::ensure!(
+            ink_bounds_of(&mut dc, &mut bitmap, white).is_none(),
+            "blank canvas has ink"
+        );
+
+        let mut backend = WxBackend::new(&dc);
+        backend.draw_rect((190, 140), (210, 160), &RED, true)?;
+        backend.present()?;
+        drop(backend);
+        let bounds = ink_bounds_of(&mut dc, &mut bitmap, white)
+            .context("no ink found")?;
+
+        // `draw_rect` covers the upper-left corner, and excludes the
+        // bottom-right corner
+        let expected = (190, 140, 20, 20);
+        let actual = (bounds.x, bounds.y, bounds.width, bounds.height);
+        anyhow::ensure!(
+            actual == expected,
+            "ink bounds are {actual:?}, expected {expected:?}"
+        );
+        Ok(())
+    })
+}
+
+/// Deselects the bitmap to compute its ink bounds, then selects it again.
+fn ink_bounds_of(
+    dc: &mut wx::MemoryDC,
+    bitmap: &mut wx::Bitmap,
+    background: wx::Colour,
+) -> Option<wx::Rect> {
+    dc.select_object(&mut wx::Bitmap::null_bitmap());
+    let bounds = ink_bounds(bitmap, background);
+    dc.select_object(bitmap);
+    bounds
+}
@@ -0,0 +1,43 @@
+//! Drawing after a failure
+//!
+//! This tests that once a call to wxWidgets fails, later drawing operations
+//! are no-ops and `present` returns an error. The failure is simulated by
+//! blitting an image whose buffer does not match its size, so that wxWidgets
+//! fails to create the bitmap.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_invalid() -> Result<()> {
+    run_wx_test(|| {
+        let image = render_rgba_image(100, 100, |dc| {
+            let mut backend = WxBackend::new(dc);
+            anyhow::ensure!(backend.is_valid(), "new backend is invalid");
+
+            let blit = backend.blit_bitmap((0, 0), (10, 10), &[0; 4]);
+            anyhow::ensure!(blit.is_err(), "blit of a bad buffer succeeded");
+            anyhow::ensure!(!backend.is_valid(), "backend is still valid");
+
+            backend.draw_pixel((5, 5), BLACK.to_backend_color())?;
+            backend.draw_rect((10, 10), (90, 90), &BLACK, true)?;
+            backend.draw_line((0, 99), (99, 0), &BLACK)?;
+            anyhow::ensure!(
+                backend.present().is_err(),
+                "present succeeded after a failure"
+            );
+            Ok(())
+        })?;
+
+        anyhow::ensure!(
+            image.pixels().all(|p| p.0 == [255, 255, 255, 255]),
+            "invalid backend drew on the device context"
+        );
+        Ok(())
+    })
+}
@@ -0,0 +1,39 @@
+//! Invisible strokes
+//!
+//! This tests that lines, paths and rectangle outlines with a zero stroke
+//! width or a fully transparent color draw nothing.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_invisible_stroke() -> Result<()> {
+    run_wx_test(|| {
+        let image = render_rgba_image(60, 60, |dc| {
+            let mut backend = WxBackend::new(dc);
+            let zero_width = BLACK.stroke_width(0);
+            let transparent = BLACK.mix(0.0).stroke_width(3);
+            for style in [zero_width, transparent] {
+                backend.draw_line((5, 5), (55, 55), &style)?;
+                backend.draw_path([(5, 55), (30, 5), (55, 55)], &style)?;
+                backend.draw_rect((10, 10), (50, 50), &style, false)?;
+            }
+            backend.present()?;
+            Ok(())
+        })?;
+
+        for (x, y, pixel) in image.enumerate_pixels() {
+            anyhow::ensure!(
+                pixel.0 == [255, 255, 255, 255],
+                "pixel at ({x}, {y}) changed to {:?}",
+                pixel.0
+            );
+        }
+        Ok(())
+    })
+}
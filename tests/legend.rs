@@ -0,0 +1,59 @@
+//! Legend outside of a chart
+//!
+//! This tests `draw_legend`, by drawing a vertical legend with two entries,
+//! and checking the swatch colors, that swatches are exactly `swatch_size`
+//! pixels wide and high, and that labels are drawn next to them.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::{LegendLayout, LegendOrientation, WxBackend};
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_legend() -> Result<()> {
+    run_wx_test(|| {
+        let layout = LegendLayout {
+            origin: (10, 10),
+            orientation: LegendOrientation::Vertical,
+            swatch_size: 20,
+            spacing: 10,
+        };
+        let image = render_rgba_image(200, 100, |dc| {
+            let mut backend = WxBackend::new(dc);
+            let text_style = TextStyle::from(("sans-serif", 20).into_font());
+            let entries = [("sine", RED.filled()), ("cosine", BLUE.filled())];
+            backend.draw_legend(&entries, &text_style, layout)?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        // first swatch at the origin, second swatch below it
+        let first = image.get_pixel(20, 20).0;
+        anyhow::ensure!(first == [255, 0, 0, 255], "first swatch: {first:?}");
+        let red = |(x, y)| image.get_pixel(x, y).0 == [255, 0, 0, 255];
+        let width = (0..40).filter(|&x| red((x, 20))).count();
+        let height = (0..40).filter(|&y| red((20, y))).count();
+        anyhow::ensure!(
+            (width, height) == (20, 20),
+            "first swatch is {width}x{height} pixels"
+        );
+        let second = (40..100)
+            .map(|y| image.get_pixel(20, y).0)
+            .find(|pixel| *pixel != [255, 255, 255, 255]);
+        anyhow::ensure!(
+            second == Some([0, 0, 255, 255]),
+            "second swatch: {second:?}"
+        );
+
+        // labels are drawn right of the swatches
+        let ink = image
+            .enumerate_pixels()
+            .filter(|(x, _, pixel)| *x > 40 && pixel.0 != [255, 255, 255, 255])
+            .count();
+        anyhow::ensure!(ink > 0, "labels were not drawn");
+        Ok(())
+    })
+}
@@ -0,0 +1,93 @@
+//! Keyboard focus of legend entries
+//!
+//! This tests that `LegendToggle::key_down` moves the focus between entries
+//! with Tab and toggles the focused series with Space or Enter, and that
+//! `LegendToggle::draw_focus` draws a dotted ring around the focused entry
+//! only.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::{
+    LegendKey, LegendLayout, LegendOrientation, LegendToggle, WxBackend,
+};
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+const TAB: i32 = 9;
+const RETURN: i32 = 13;
+const SPACE: i32 = 32;
+
+#[test]
+fn test_legend_focus() -> Result<()> {
+    run_wx_test(|| {
+        let layout = LegendLayout {
+            origin: (10, 10),
+            orientation: LegendOrientation::Horizontal,
+            swatch_size: 20,
+            spacing: 10,
+        };
+        let toggle = LegendToggle::new(2);
+        // keys are ignored until the legend is laid out
+        anyhow::ensure!(toggle.key_down(TAB) == LegendKey::Ignored);
+
+        let mut regions = Vec::new();
+        let image = render_rgba_image(300, 60, |dc| {
+            let mut backend = WxBackend::new(dc);
+            let text_style = TextStyle::from(("sans-serif", 20).into_font());
+            let entries = [("sine", RED.filled()), ("cosine", BLUE.filled())];
+            backend.draw_legend(&entries, &text_style, layout)?;
+            regions = backend.legend_regions(
+                &["sine", "cosine"],
+                &text_style,
+                layout,
+            )?;
+            toggle.set_regions(regions.clone());
+            anyhow::ensure!(toggle.key_down(TAB) == LegendKey::Focus(Some(0)));
+            toggle.draw_focus(&mut backend, &BLACK)?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        // the ring is dotted, along the top edge of the first entry only
+        let ink = |((x1, y1), (x2, _)): ((i32, i32), (i32, i32))| {
+            (x1..=x2)
+                .filter(|x| {
+                    image.get_pixel(*x as u32, (y1 - 2) as u32).0
+                        != [255, 255, 255, 255]
+                })
+                .count()
+        };
+        let (focused, other) = (ink(regions[0]), ink(regions[1]));
+        let width = (regions[0].1.0 - regions[0].0.0 + 1) as usize;
+        anyhow::ensure!(
+            focused > 0 && focused < width,
+            "{focused} of {width} pixels inked on the focus ring"
+        );
+        anyhow::ensure!(other == 0, "{other} pixels inked around entry 1");
+
+        // Space and Enter toggle the focused entry, Tab leaves the legend
+        // after the last entry
+        let keys = [
+            (SPACE, LegendKey::Toggle(0, false)),
+            (TAB, LegendKey::Focus(Some(1))),
+            (RETURN, LegendKey::Toggle(1, false)),
+            (SPACE, LegendKey::Toggle(1, true)),
+            ('a' as i32, LegendKey::Ignored),
+            (TAB, LegendKey::Focus(None)),
+            (SPACE, LegendKey::Ignored),
+            (TAB, LegendKey::Focus(Some(0))),
+        ];
+        for (key, expected) in keys {
+            let effect = toggle.key_down(key);
+            anyhow::ensure!(
+                effect == expected,
+                "key {key}: {effect:?} instead of {expected:?}"
+            );
+        }
+        anyhow::ensure!(toggle.focused() == Some(0));
+        anyhow::ensure!(!toggle.is_visible(0) && toggle.is_visible(1));
+        Ok(())
+    })
+}
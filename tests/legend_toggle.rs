@@ -0,0 +1,69 @@
+//! Legend click-to-toggle
+//!
+//! This tests that `legend_regions` covers the entries drawn by
+//! `draw_legend`, and that `LegendToggle` toggles the series whose entry is
+//! clicked.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::{
+    LegendLayout, LegendOrientation, LegendToggle, WxBackend,
+};
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_legend_toggle() -> Result<()> {
+    run_wx_test(|| {
+        let layout = LegendLayout {
+            origin: (10, 10),
+            orientation: LegendOrientation::Horizontal,
+            swatch_size: 20,
+            spacing: 10,
+        };
+        let mut regions = Vec::new();
+        let image = render_rgba_image(300, 60, |dc| {
+            let mut backend = WxBackend::new(dc);
+            let text_style = TextStyle::from(("sans-serif", 20).into_font());
+            let entries = [("sine", RED.filled()), ("cosine", BLUE.filled())];
+            backend.draw_legend(&entries, &text_style, layout)?;
+            regions = backend.legend_regions(
+                &["sine", "cosine"],
+                &text_style,
+                layout,
+            )?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        // each region starts with the swatch of its entry
+        anyhow::ensure!(regions.len() == 2, "regions: {regions:?}");
+        for (((x, y), _), color) in regions.iter().zip([RED, BLUE]) {
+            let pixel = image.get_pixel(*x as u32 + 5, *y as u32 + 10).0;
+            let expected = [color.0, color.1, color.2, 255];
+            anyhow::ensure!(
+                pixel == expected,
+                "swatch at ({x}, {y}): {pixel:?}"
+            );
+        }
+        let (_, (first_right, _)) = regions[0];
+        let ((second_left, _), _) = regions[1];
+        anyhow::ensure!(
+            first_right < second_left,
+            "regions overlap: {regions:?}"
+        );
+
+        // clicking an entry toggles its series, clicking elsewhere does not
+        let toggle = LegendToggle::new(2);
+        toggle.set_regions(regions.clone());
+        let ((x, y), _) = regions[1];
+        anyhow::ensure!(toggle.click((x + 30, y + 5)) == Some((1, false)));
+        anyhow::ensure!(toggle.is_visible(0) && !toggle.is_visible(1));
+        anyhow::ensure!(toggle.click((x + 30, y + 5)) == Some((1, true)));
+        anyhow::ensure!(toggle.click((295, 55)).is_none());
+        anyhow::ensure!(toggle.is_visible(0) && toggle.is_visible(1));
+        Ok(())
+    })
+}
@@ -0,0 +1,72 @@
+//! Letterboxed plot
+//!
+//! This tests `letterbox`, by filling the whole backend after letterboxing a
+//! square in a wide device context, and a wide rectangle in a tall device
+//! context with a flipped vertical axis, and checking that only the centered
+//! rectangle is filled and that the margins are cleared.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+use wxdragon as wx;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_letterbox() -> Result<()> {
+    run_wx_test(|| {
+        // a square of 100x100 with margins of 50 on the left and right
+        check(200, 100, 1.0, false, (100, 100), (50, 0))?;
+        // a rectangle of 100x50 with margins of 75 above and below
+        check(100, 200, 2.0, true, (100, 50), (0, 75))
+    })
+}
+
+/// Letterbox a rectangle of aspect ratio `ratio` in a device context of
+/// `width` x `height` filled with blue, and fill the whole backend with red.
+/// The red rectangle must have the size `size` and the upper-left corner
+/// `corner`, and the margins must be white.
+fn check(
+    width: u32,
+    height: u32,
+    ratio: f64,
+    flip_y: bool,
+    size: (u32, u32),
+    corner: (u32, u32),
+) -> Result<()> {
+    let image = render_rgba_image(width, height, |dc| {
+        let builder =
+            WxBackend::builder(dc).background_color(wx::Colour::rgb(0, 0, 255));
+        drop(builder.build());
+        let mut backend = WxBackend::new_no_clear(dc);
+        backend.set_flip_y(flip_y)?;
+        backend.letterbox(ratio)?;
+        anyhow::ensure!(
+            backend.get_size() == size,
+            "letterboxed size is {:?} instead of {size:?}",
+            backend.get_size()
+        );
+        backend.draw_rect((-10, -10), (1000, 1000), &RED, true)?;
+        backend.present()?;
+        Ok(())
+    })?;
+
+    let inside = |x: u32, y: u32| {
+        (corner.0..corner.0 + size.0).contains(&x)
+            && (corner.1..corner.1 + size.1).contains(&y)
+    };
+    for (x, y, pixel) in image.enumerate_pixels() {
+        let expected = match inside(x, y) {
+            true => [255, 0, 0, 255],
+            false => [255, 255, 255, 255],
+        };
+        anyhow::ensure!(
+            pixel.0 == expected,
+            "pixel at ({x}, {y}) is {:?} instead of {expected:?}",
+            pixel.0
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,46 @@
+//! Dashed and dotted lines
+//!
+//! This tests `set_line_style`: a dashed line has gaps, lines are solid again
+//! once the style is reset, and outlines of shapes stay solid.
+
+mod test_utils;
+
+use anyhow::Result;
+use image::RgbaImage;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+use wxdragon as wx;
+
+use test_utils::{is_ink, render_rgba_image, run_wx_test};
+
+#[test]
+fn test_line_style() -> Result<()> {
+    run_wx_test(|| {
+        let image = render_rgba_image(200, 100, |dc| {
+            let mut backend = WxBackend::new(dc);
+            backend.set_line_style(wx::PenStyle::LongDash);
+            backend.draw_line((10, 20), (190, 20), &BLACK)?;
+            backend.draw_rect((10, 60), (190, 90), &BLACK, false)?;
+            backend.set_line_style(wx::PenStyle::Solid);
+            backend.draw_line((10, 40), (190, 40), &BLACK)?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        let dashed = ink_count(&image, 20);
+        anyhow::ensure!(
+            dashed > 20 && dashed < 160,
+            "dashed line has {dashed} of 170 pixels drawn"
+        );
+        let solid = ink_count(&image, 40);
+        anyhow::ensure!(solid == 170, "solid line has {solid} of 170 pixels");
+        let outline = ink_count(&image, 60);
+        anyhow::ensure!(outline == 170, "outline has {outline} of 170 pixels");
+        Ok(())
+    })
+}
+
+/// Counts the non-white pixels of row `y`, between x = 15 and x = 185.
+fn ink_count(image: &RgbaImage, y: u32) -> usize {
+    (15..185).filter(|&x| is_ink(image.get_pixel(x, y))).count()
+}
@@ -0,0 +1,45 @@
+//! Text measurement without side effects
+//!
+//! This tests that `estimate_text_size` leaves the font and colors of the
+//! device context as they were, by drawing text directly on the device
+//! context after measuring text of another style, and comparing with the
+//! same drawing without the measurement.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::{DEFAULT_DPI, WxBackend};
+use wxdragon::DeviceContext;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_measure_state() -> Result<()> {
+    run_wx_test(|| {
+        let render = |measure: bool| {
+            render_rgba_image(200, 100, |dc| {
+                let mut backend = WxBackend::new_with_dpi(dc, DEFAULT_DPI);
+                let style = ("sans-serif", 20).into_font().color(&RED);
+                backend.draw_text("drawn", &style, (10, 10))?;
+                if measure {
+                    let other = ("serif", 40).into_font().color(&BLUE);
+                    backend.estimate_text_size("measured", &other)?;
+                }
+                backend.flush()?;
+                // drawn with the font and colors left by the backend
+                dc.draw_text("direct", 10, 50);
+                backend.draw_text("drawn again", &style, (100, 10))?;
+                backend.present()?;
+                Ok(())
+            })
+        };
+        let measured = render(true)?;
+        let unmeasured = render(false)?;
+        anyhow::ensure!(
+            measured == unmeasured,
+            "measuring text changed the state of the device context"
+        );
+        Ok(())
+    })
+}
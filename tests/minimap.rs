@@ -0,0 +1,66 @@
+//! Minimap of a zoomed plot
+//!
+//! This tests `draw_minimap`, by drawing a green plot of 200x200 pixels in a
+//! 100x100 inset, with the current ranges covering the middle half of the x
+//! range and the upper half of the y range, and checking the position of the
+//! rectangle drawn around them.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_minimap() -> Result<()> {
+    run_wx_test(|| {
+        let image = render_rgba_image(200, 200, |dc| {
+            let mut backend = WxBackend::new(dc);
+            backend.draw_minimap(
+                ((100, 100), (199, 199)),
+                (0.0..100.0, 0.0..100.0),
+                (25.0..75.0, 50.0..100.0),
+                &RED,
+                |mut backend, _, _| {
+                    let (width, height) = backend.get_size();
+                    anyhow::ensure!(
+                        (width, height) == (200, 200),
+                        "plot laid out on {width}x{height} pixels"
+                    );
+                    backend.draw_rect((0, 0), (200, 200), &GREEN, true)?;
+                    Ok((0..200, 0..200))
+                },
+            )?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        let red = [255, 0, 0, 255];
+        let green = [0, 255, 0, 255];
+        let white = [255, 255, 255, 255];
+        // x from 25 to 75 is columns 50 to 150 of the plot, 125 to 175 of the
+        // image, and y from 50 to 100 is rows 0 to 99 of the plot, 100 to 150
+        // of the image
+        let checks = [
+            ((125, 125), red),
+            ((174, 125), red),
+            ((150, 100), red),
+            ((150, 149), red),
+            ((150, 125), green),
+            ((110, 180), green),
+            ((190, 110), green),
+            ((50, 50), white),
+            ((150, 50), white),
+        ];
+        for ((x, y), expected) in checks {
+            let pixel = image.get_pixel(x, y).0;
+            anyhow::ensure!(
+                pixel == expected,
+                "pixel at ({x}, {y}) is {pixel:?} instead of {expected:?}"
+            );
+        }
+        Ok(())
+    })
+}
@@ -0,0 +1,45 @@
+//! Monospace font family
+//!
+//! This tests that the plotters monospace family selects a fixed-width face:
+//! strings of narrow and wide characters of the same length have the same
+//! width, unlike with the sans-serif family.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters::style::FontFamily;
+use plotters_wxdragon::WxBackend;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_monospace() -> Result<()> {
+    run_wx_test(|| {
+        let mut widths = Vec::new();
+        render_rgba_image(200, 100, |dc| {
+            let backend = WxBackend::new(dc);
+            for family in [FontFamily::Monospace, FontFamily::SansSerif] {
+                let style = TextStyle::from((family, 30).into_font());
+                let narrow = backend.estimate_text_size("iiiiii", &style)?.0;
+                let wide = backend.estimate_text_size("WWWWWW", &style)?.0;
+                widths.push((narrow, wide));
+            }
+            Ok(())
+        })?;
+
+        let (narrow, wide) = widths[0];
+        anyhow::ensure!(
+            narrow == wide,
+            "monospace: \"iiiiii\" is {narrow} pixels wide, \"WWWWWW\" is \
+             {wide} pixels wide"
+        );
+        let (narrow, wide) = widths[1];
+        anyhow::ensure!(
+            narrow < wide,
+            "sans-serif: \"iiiiii\" is {narrow} pixels wide, \"WWWWWW\" is \
+             {wide} pixels wide"
+        );
+        Ok(())
+    })
+}
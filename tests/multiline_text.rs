@@ -0,0 +1,69 @@
+//! Multiline text
+//!
+//! This tests that a text with a `\n` is drawn as lines stacked below each
+//! other, centered as a block on its anchor, by comparing a two-line caption
+//! to its lines drawn separately at the expected positions. This reference
+//! image is rendered at test time rather than stored as a PNG fixture, whose
+//! glyphs would depend on the fonts of the machine that generated it, while
+//! the layout of the lines is what is tested.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters::style::text_anchor::{HPos, Pos, VPos};
+use plotters_wxdragon::WxBackend;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+const WIDTH: u32 = 300;
+const HEIGHT: u32 = 200;
+
+#[test]
+fn test_multiline_text() -> Result<()> {
+    run_wx_test(|| {
+        let center = Pos::new(HPos::Center, VPos::Center);
+        let style = TextStyle::from(("sans-serif", 30).into_font()).pos(center);
+        let mut sizes = Vec::new();
+
+        let multiline = render_rgba_image(WIDTH, HEIGHT, |dc| {
+            let mut backend = WxBackend::new(dc);
+            for text in ["Caption\nsecond line", "Caption", "second line"] {
+                sizes.push(backend.estimate_text_size(text, &style)?);
+            }
+            backend.draw_text("Caption\nsecond line", &style, (150, 100))?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        let (block, first, second) = (sizes[0], sizes[1], sizes[2]);
+        anyhow::ensure!(
+            block == (first.0.max(second.0), first.1 + second.1),
+            "two-line text is {block:?}, lines are {first:?} and {second:?}"
+        );
+
+        // each line centered in the block, the block centered on the anchor
+        let (block_width, block_height) = (block.0 as i32, block.1 as i32);
+        let (left, top) = (150 - block_width / 2, 100 - block_height / 2);
+        let separate = render_rgba_image(WIDTH, HEIGHT, |dc| {
+            let mut backend = WxBackend::new(dc);
+            let style = style.pos(Pos::new(HPos::Left, VPos::Top));
+            let lines = [
+                ("Caption", first, top),
+                ("second line", second, top + first.1 as i32),
+            ];
+            for (text, (width, _), y) in lines {
+                let x = left + (block_width - width as i32) / 2;
+                backend.draw_text(text, &style, (x, y))?;
+            }
+            backend.present()?;
+            Ok(())
+        })?;
+
+        anyhow::ensure!(
+            multiline == separate,
+            "two-line text differs from its lines drawn separately"
+        );
+        Ok(())
+    })
+}
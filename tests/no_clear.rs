@@ -0,0 +1,44 @@
+//! Backend without clearing
+//!
+//! This tests `WxBackend::new_no_clear`, by pre-filling a `MemoryDC` with a
+//! gradient, then drawing on it with a backend, and checking that the
+//! gradient is preserved around the drawing.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+use wxdragon::{self as wx, DeviceContext};
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_no_clear() -> Result<()> {
+    run_wx_test(|| {
+        let image = render_rgba_image(100, 80, |dc| {
+            for x in 0..100 {
+                let level = (x * 2) as u8;
+                let color = wx::Colour::rgb(level, level, 255);
+                dc.set_pen(color, 1, wx::PenStyle::Solid);
+                dc.draw_line(x, 0, x, 80);
+            }
+            let mut backend = WxBackend::new_no_clear(dc);
+            backend.draw_rect((40, 30), (60, 50), &RED, true)?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        for x in [0, 20, 90] {
+            let pixel = image.get_pixel(x, 10).0;
+            let level = (x * 2) as u8;
+            anyhow::ensure!(
+                pixel == [level, level, 255, 255],
+                "gradient pixel at x = {x}: {pixel:?}"
+            );
+        }
+        let rect = image.get_pixel(50, 40).0;
+        anyhow::ensure!(rect == [255, 0, 0, 255], "rect pixel: {rect:?}");
+        Ok(())
+    })
+}
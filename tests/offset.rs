@@ -0,0 +1,42 @@
+//! Offset of drawing
+//!
+//! This tests `set_offset`, by drawing shapes and pixels with an offset, then
+//! with the offset reset, and checking where they land.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_offset() -> Result<()> {
+    run_wx_test(|| {
+        let image = render_rgba_image(60, 40, |dc| {
+            let mut backend = WxBackend::new(dc).with_offset(30, 20);
+            backend.draw_rect((0, 0), (9, 9), &RED, true)?;
+            backend.draw_pixel((15, 5), BLUE.to_backend_color())?;
+            backend.set_offset((0, 0))?;
+            backend.draw_rect((0, 0), (9, 9), &GREEN, true)?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        let checks = [
+            ((35, 25), [255, 0, 0, 255]),
+            ((45, 25), [0, 0, 255, 255]),
+            ((5, 5), [0, 255, 0, 255]),
+            ((35, 5), [255, 255, 255, 255]),
+        ];
+        for ((x, y), expected) in checks {
+            let pixel = image.get_pixel(x, y).0;
+            anyhow::ensure!(
+                pixel == expected,
+                "pixel at ({x}, {y}) is {pixel:?} instead of {expected:?}"
+            );
+        }
+        Ok(())
+    })
+}
@@ -0,0 +1,63 @@
+//! Overlay of transient lines
+//!
+//! This tests that `Overlay` draws lines over a plot, and that clearing it
+//! restores the plot exactly, as drawn without overlay.
+
+mod test_utils;
+
+use anyhow::{Context, Result};
+use plotters::prelude::*;
+use plotters_wxdragon::{Overlay, WxBackend};
+use wxdragon::{self as wx, DeviceContext};
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+const WIDTH: u32 = 120;
+const HEIGHT: u32 = 100;
+
+#[test]
+fn test_overlay() -> Result<()> {
+    run_wx_test(|| {
+        let plot = render_rgba_image(WIDTH, HEIGHT, |dc| {
+            let mut backend = WxBackend::new(dc);
+            draw_plot(&mut backend)?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        let cleared = render_rgba_image(WIDTH, HEIGHT, |dc| {
+            let mut backend = WxBackend::new(dc);
+            draw_plot(&mut backend)?;
+            let mut overlay = Overlay::new();
+            let white = WHITE.to_backend_color();
+            overlay.draw_line(&backend, (30, 0), (30, 99), white)?;
+            overlay.draw_line(&backend, (0, 30), (119, 30), white)?;
+            anyhow::ensure!(!overlay.is_empty(), "overlay is empty");
+
+            // white XOR red is cyan
+            let pixel = dc.get_pixel(30, 20).context("failed to get pixel")?;
+            anyhow::ensure!(
+                (pixel.r, pixel.g, pixel.b) == (0, 255, 255),
+                "crosshair over red is {pixel:?}"
+            );
+
+            overlay.clear(&backend)?;
+            anyhow::ensure!(overlay.is_empty(), "overlay is not empty");
+            backend.present()?;
+            Ok(())
+        })?;
+
+        anyhow::ensure!(
+            cleared == plot,
+            "clearing the overlay changed the plot"
+        );
+        Ok(())
+    })
+}
+
+/// Draws the plot below the overlay.
+fn draw_plot(backend: &mut WxBackend<'_, wx::MemoryDC>) -> Result<()> {
+    backend.draw_rect((10, 10), (60, 50), &RED, true)?;
+    backend.draw_line((0, 90), (119, 0), &BLUE)?;
+    Ok(())
+}
@@ -0,0 +1,80 @@
+//! Export of a plot panel
+//!
+//! This tests `PlotPanel::export`, by exporting the plot of a panel to files
+//! with a `.png` and a `.PNG` extension, and checking that they start with
+//! the PNG signature and have the size of the panel, then exporting to
+//! `.svg` and `.pdf` files, which are not supported, and checking that they
+//! fail with an unsupported format error without creating the file.
+//!
+//! This test requires the `export` feature.
+
+mod test_utils;
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use plotters::prelude::*;
+use plotters_wxdragon::{PlotPanel, WxBackend};
+use wxdragon::{self as wx, DeviceContext, WxWidget};
+
+use test_utils::run_wx_test;
+
+/// Signature at the start of every PNG file.
+const PNG_SIGNATURE: [u8; 8] =
+    [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+fn draw<C: DeviceContext>(mut backend: WxBackend<'_, C>) -> Result<()> {
+    backend.draw_rect((10, 10), (50, 40), &RED, true)?;
+    backend.present()?;
+    Ok(())
+}
+
+#[test]
+fn test_panel_export() -> Result<()> {
+    run_wx_test(|| {
+        let frame = wx::Frame::builder()
+            .with_size(wx::Size::new(300, 200))
+            .build();
+        let panel = PlotPanel::new(&frame, draw);
+        let size = panel.get_size();
+        let expected = (size.width.max(1) as u32, size.height.max(1) as u32);
+
+        for path in [
+            "tests/panel_export_actual.png",
+            "tests/panel_export_actual.PNG",
+        ] {
+            panel.export(path, draw)?;
+            let bytes = std::fs::read(path)
+                .with_context(|| format!("failed to read {path}"))?;
+            std::fs::remove_file(path)
+                .with_context(|| format!("failed to remove {path}"))?;
+            anyhow::ensure!(
+                bytes.starts_with(&PNG_SIGNATURE),
+                "{path} is not a PNG file"
+            );
+            let image = image::load_from_memory(&bytes)
+                .with_context(|| format!("failed to decode {path}"))?;
+            anyhow::ensure!(
+                (image.width(), image.height()) == expected,
+                "{path} is {}x{} instead of {expected:?}",
+                image.width(),
+                image.height()
+            );
+        }
+
+        for path in [
+            "tests/panel_export_actual.svg",
+            "tests/panel_export_actual.pdf",
+        ] {
+            let Err(error) = panel.export(path, draw) else {
+                anyhow::bail!("export to {path} did not fail");
+            };
+            anyhow::ensure!(
+                error.to_string().starts_with("unsupported image format"),
+                "export to {path} failed with: {error}"
+            );
+            anyhow::ensure!(!Path::new(path).exists(), "{path} was created");
+        }
+        Ok(())
+    })
+}
@@ -0,0 +1,96 @@
+//! Caching of the pen and brush of the device context
+//!
+//! This tests that primitives which skip setting an unchanged pen or brush
+//! produce the same output as setting them on the device context before each
+//! primitive, also when the device context is drawn on directly between two
+//! calls to the backend.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+use wxdragon::{self as wx, DeviceContext};
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+const WIDTH: u32 = 200;
+const HEIGHT: u32 = 150;
+
+#[test]
+fn test_pen_cache() -> Result<()> {
+    run_wx_test(|| {
+        let cached = render_rgba_image(WIDTH, HEIGHT, |dc| {
+            let mut backend = WxBackend::new(dc);
+            draw_series(&mut backend)?;
+            backend.flush()?;
+            draw_direct(dc);
+            draw_series(&mut backend)?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        let direct = render_rgba_image(WIDTH, HEIGHT, |dc| {
+            let mut backend = WxBackend::new(dc);
+            draw_series_direct(dc);
+            draw_direct(dc);
+            draw_series_direct(dc);
+            backend.present()?;
+            Ok(())
+        })?;
+
+        anyhow::ensure!(
+            cached == direct,
+            "output with cached pen and brush differs from direct output"
+        );
+        Ok(())
+    })
+}
+
+/// Draws a line series, then filled and outlined markers, through the
+/// backend.
+fn draw_series<DB: DrawingBackend>(backend: &mut DB) -> Result<()>
+where
+    DB::ErrorType: 'static,
+{
+    for x in (0..190).step_by(10) {
+        backend.draw_line((x, 20), (x + 10, 30), &BLUE)?;
+    }
+    for x in (10..190).step_by(20) {
+        backend.draw_circle((x, 60), 5, &RED, true)?;
+        backend.draw_circle((x, 90), 5, &RED, false)?;
+        backend.draw_rect((x - 5, 110), (x + 5, 120), &GREEN, true)?;
+    }
+    Ok(())
+}
+
+/// Draws the same as [`draw_series`] on the device context, setting the pen
+/// and brush before each primitive.
+fn draw_series_direct(dc: &wx::MemoryDC) {
+    let solid = wx::PenStyle::Solid;
+    for x in (0..190).step_by(10) {
+        dc.set_pen(wx::Colour::new(0, 0, 255, 255), 1, solid);
+        dc.draw_line(x, 20, x + 10, 30);
+    }
+    let red = wx::Colour::new(255, 0, 0, 255);
+    let green = wx::Colour::new(0, 255, 0, 255);
+    for x in (10..190).step_by(20) {
+        dc.set_pen(red, 1, solid);
+        dc.set_brush(red, wx::BrushStyle::Solid);
+        dc.draw_circle(x, 60, 5);
+        dc.set_pen(red, 1, solid);
+        dc.set_brush(red, wx::BrushStyle::Transparent);
+        dc.draw_circle(x, 90, 5);
+        dc.set_pen(green, 1, solid);
+        dc.set_brush(green, wx::BrushStyle::Solid);
+        dc.draw_rectangle(x - 5, 110, 10, 10);
+    }
+}
+
+/// Draws on the device context with another pen and brush.
+fn draw_direct(dc: &wx::MemoryDC) {
+    let black = wx::Colour::new(0, 0, 0, 255);
+    dc.set_pen(black, 3, wx::PenStyle::Dot);
+    dc.set_brush(black, wx::BrushStyle::CrossHatch);
+    dc.draw_rectangle(50, 130, 100, 15);
+}
@@ -0,0 +1,67 @@
+//! Dashed zig-zag path
+//!
+//! This is a regression test of the `pen_styles` example, in its initial
+//! configuration: a zig-zag path drawn with a long dash pen 3 pixels wide.
+//! The path must have gaps, and match the same path drawn by wxWidgets with
+//! the same pen. The reference is drawn at test time rather than stored as a
+//! PNG fixture, since dashes and anti-aliasing depend on the platform.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+use wxdragon::{self as wx, DeviceContext};
+
+use test_utils::{ink, render_rgba_image, run_wx_test};
+
+const WIDTH: u32 = 200;
+const HEIGHT: u32 = 100;
+
+#[test]
+fn test_pen_styles() -> Result<()> {
+    run_wx_test(|| {
+        let image = render_rgba_image(WIDTH, HEIGHT, |dc| {
+            let mut backend = WxBackend::new(dc);
+            backend.set_line_style(wx::PenStyle::LongDash);
+            backend.draw_path(zig_zag(), &BLUE.stroke_width(3))?;
+            backend.present()?;
+            Ok(())
+        })?;
+        let reference = render_rgba_image(WIDTH, HEIGHT, |dc| {
+            let backend = WxBackend::new(dc);
+            let dc = backend.context();
+            dc.set_pen(wx::Colour::rgb(0, 0, 255), 3, wx::PenStyle::LongDash);
+            let points: Vec<wx::dc::Point> = zig_zag()
+                .into_iter()
+                .map(|(x, y)| wx::dc::Point::new(x, y))
+                .collect();
+            dc.draw_lines(&points, 0, 0);
+            Ok(())
+        })?;
+
+        let solid = render_rgba_image(WIDTH, HEIGHT, |dc| {
+            let mut backend = WxBackend::new(dc);
+            backend.draw_path(zig_zag(), &BLUE.stroke_width(3))?;
+            backend.present()?;
+            Ok(())
+        })?;
+        anyhow::ensure!(
+            ink(&image) < ink(&solid),
+            "dashed path has as much ink ({}) as a solid path",
+            ink(&image)
+        );
+        anyhow::ensure!(
+            image == reference,
+            "dashed path differs from the path drawn by wxWidgets"
+        );
+        Ok(())
+    })
+}
+
+/// Vertices of a zig-zag across the image.
+fn zig_zag() -> Vec<(i32, i32)> {
+    (0..10)
+        .map(|i| (10 + 20 * i, if i % 2 == 0 { 80 } else { 20 }))
+        .collect()
+}
@@ -0,0 +1,77 @@
+//! Batching of `draw_pixel` calls
+//!
+//! This tests that pixels accumulated in the backend's pixel buffer produce
+//! the same output as drawing each pixel directly on the device context, also
+//! when mixed with other drawing primitives.
+
+mod test_utils;
+
+use std::convert::Infallible;
+use std::ops::Range;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+use wxdragon::{self as wx, DeviceContext};
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+const WIDTH: u32 = 200;
+const HEIGHT: u32 = 150;
+
+#[test]
+fn test_pixel_buffer() -> Result<()> {
+    run_wx_test(|| {
+        let batched = render_rgba_image(WIDTH, HEIGHT, |dc| {
+            let mut backend = WxBackend::new(dc);
+            draw_gradient(0..100, |x, y, c| {
+                backend.draw_pixel((x, y), c.to_backend_color())
+            })?;
+            backend.draw_line((0, 0), (199, 149), &BLACK)?;
+            draw_gradient(50..150, |x, y, c| {
+                backend.draw_pixel((x, y), c.to_backend_color())
+            })?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        let direct = render_rgba_image(WIDTH, HEIGHT, |dc| {
+            let mut backend = WxBackend::new(dc);
+            let mut draw_point = |x, y, c: RGBColor| {
+                dc.set_pen(
+                    wx::Colour::rgb(c.0, c.1, c.2),
+                    1,
+                    wx::PenStyle::Solid,
+                );
+                dc.draw_point(x, y);
+                Ok::<_, Infallible>(())
+            };
+            draw_gradient(0..100, &mut draw_point)?;
+            backend.draw_line((0, 0), (199, 149), &BLACK)?;
+            draw_gradient(50..150, &mut draw_point)?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        anyhow::ensure!(
+            batched == direct,
+            "batched pixels differ from pixels drawn directly"
+        );
+        Ok(())
+    })
+}
+
+/// Draws a gradient on the rows `rows` of the whole canvas width.
+fn draw_gradient<F, E>(rows: Range<i32>, mut draw_fn: F) -> Result<()>
+where
+    F: FnMut(i32, i32, RGBColor) -> Result<(), E>,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    for y in rows {
+        for x in 0..WIDTH as i32 {
+            let color = RGBColor(x as u8, y as u8, 128);
+            draw_fn(x, y, color)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,77 @@
+//! Rectangles snapped to device pixels
+//!
+//! This tests `set_pixel_snap`, by drawing adjacent bars 3 pixels wide at a
+//! scale of 1.5, where their edges land between device pixels, each bar
+//! starting at the excluded bottom-right corner of the previous one. Snapped
+//! bars must abut without gap or blended edge, and be 4 or 5 device pixels
+//! wide. At a scale of 1, snapping changes nothing.
+
+mod test_utils;
+
+use anyhow::Result;
+use image::RgbaImage;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+/// Number of bars, covering 99 logical pixels.
+const BARS: i32 = 33;
+
+/// Draw the bars at a scale, with or without snapping
+fn draw(scale: f64, snap: bool) -> Result<RgbaImage> {
+    let width = (100.0 * scale) as u32;
+    let height = (40.0 * scale) as u32;
+    render_rgba_image(width, height, |dc| {
+        let mut backend = WxBackend::new(dc);
+        backend.set_scale(scale, scale)?;
+        backend.set_pixel_snap(snap);
+        anyhow::ensure!(backend.pixel_snap() == snap, "pixel snap not set");
+        for bar in 0..BARS {
+            let color = if bar % 2 == 0 { RED } else { BLUE };
+            let x = 3 * bar;
+            backend.draw_rect((x, 5), (x + 3, 35), &color, true)?;
+        }
+        backend.present()?;
+        Ok(())
+    })
+}
+
+#[test]
+fn test_pixel_snap() -> Result<()> {
+    run_wx_test(|| {
+        anyhow::ensure!(
+            draw(1.0, true)? == draw(1.0, false)?,
+            "snapping changed the bars at a scale of 1"
+        );
+
+        let image = draw(1.5, true)?;
+        let red = [255, 0, 0, 255];
+        let blue = [0, 0, 255, 255];
+        let mut runs: Vec<([u8; 4], u32)> = Vec::new();
+        // 99 logical pixels are 148.5 device pixels, rounded to 149
+        for x in 0..149 {
+            let pixel = image.get_pixel(x, 30).0;
+            anyhow::ensure!(
+                pixel == red || pixel == blue,
+                "pixel ({x}, 30) between bars is {pixel:?}"
+            );
+            match runs.last_mut() {
+                Some((color, length)) if *color == pixel => *length += 1,
+                _ => runs.push((pixel, 1)),
+            }
+        }
+        anyhow::ensure!(
+            runs.len() == BARS as usize,
+            "{} bars instead of {BARS}",
+            runs.len()
+        );
+        for (index, (_, length)) in runs.iter().enumerate() {
+            anyhow::ensure!(
+                *length == 4 || *length == 5,
+                "bar {index} is {length} device pixels wide"
+            );
+        }
+        Ok(())
+    })
+}
@@ -0,0 +1,61 @@
+//! PNG export with a description
+//!
+//! This tests `save_png_with_metadata`, by exporting a plot with a
+//! description and reading the description back from the PNG file.
+//!
+//! This test requires the `export` feature.
+
+mod test_utils;
+
+use std::fs::File;
+use std::io::BufReader;
+
+use anyhow::{Context, Result};
+use plotters::prelude::*;
+use plotters_wxdragon::save_png_with_metadata;
+
+use test_utils::run_wx_test;
+
+const PNG_PATH: &str = "tests/png_metadata_actual.png";
+const DESCRIPTION: &str = "Bar chart of monthly rainfall, peaking in März";
+
+#[test]
+fn test_png_metadata() -> Result<()> {
+    run_wx_test(|| {
+        save_png_with_metadata(
+            PNG_PATH,
+            100,
+            80,
+            DESCRIPTION,
+            |mut backend| {
+                backend.draw_rect((10, 40), (30, 80), &BLUE, true)?;
+                backend.draw_rect((40, 20), (60, 80), &BLUE, true)?;
+                backend.present()?;
+                anyhow::Ok(())
+            },
+        )?;
+
+        let file = File::open(PNG_PATH)
+            .with_context(|| format!("failed to open {PNG_PATH}"))?;
+        let reader = png::Decoder::new(BufReader::new(file))
+            .read_info()
+            .context("failed to decode PNG header")?;
+        let descriptions = reader
+            .info()
+            .utf8_text
+            .iter()
+            .filter(|chunk| chunk.keyword == "Description")
+            .map(|chunk| chunk.get_text())
+            .collect::<Result<Vec<_>, _>>()
+            .context("failed to decode description")?;
+        drop(reader);
+        std::fs::remove_file(PNG_PATH)
+            .with_context(|| format!("failed to remove {PNG_PATH}"))?;
+
+        anyhow::ensure!(
+            descriptions == [DESCRIPTION],
+            "descriptions read back: {descriptions:?}"
+        );
+        Ok(())
+    })
+}
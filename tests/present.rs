@@ -0,0 +1,52 @@
+//! Presenting off-screen drawing
+//!
+//! This tests that after `present`, everything drawn on a `MemoryDC` is on
+//! the device context, and that the present hook is called with it.
+
+mod test_utils;
+
+use std::cell::Cell;
+
+use anyhow::{Context, Result};
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+use wxdragon::DeviceContext;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_present() -> Result<()> {
+    run_wx_test(|| {
+        let presented = Cell::new(0);
+        let image = render_rgba_image(40, 40, |dc| {
+            let mut backend = WxBackend::new(dc);
+            backend.set_present_hook(|dc: &wxdragon::MemoryDC| {
+                // pixels were flushed before the hook
+                let complete = dc
+                    .get_pixel(5, 5)
+                    .is_some_and(|p| (p.r, p.g, p.b) == (255, 0, 0));
+                if complete {
+                    presented.set(presented.get() + 1);
+                }
+            });
+            backend.draw_pixel((5, 5), RED.to_backend_color())?;
+            backend.draw_rect((20, 20), (30, 30), &BLUE, true)?;
+            backend.present()?;
+            let pixel = dc.get_pixel(5, 5).context("failed to get pixel")?;
+            anyhow::ensure!(
+                (pixel.r, pixel.g, pixel.b) == (255, 0, 0),
+                "pixel not committed after present: {pixel:?}"
+            );
+            Ok(())
+        })?;
+
+        anyhow::ensure!(
+            presented.get() == 1,
+            "hook called {} times with complete drawing",
+            presented.get()
+        );
+        let pixel = image.get_pixel(25, 25).0;
+        anyhow::ensure!(pixel == [0, 0, 255, 255], "rectangle: {pixel:?}");
+        Ok(())
+    })
+}
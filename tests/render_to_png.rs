@@ -0,0 +1,46 @@
+//! Export to a PNG file
+//!
+//! This tests `render_to_png`, by exporting a red rectangle on a white
+//! background, and checking the pixels of the saved image.
+//!
+//! This test requires the `export` feature.
+
+mod test_utils;
+
+use anyhow::{Context, Result};
+use plotters::prelude::*;
+use plotters_wxdragon::render_to_png;
+
+use test_utils::run_wx_test;
+
+const PNG_PATH: &str = "tests/render_to_png_actual.png";
+
+#[test]
+fn test_render_to_png() -> Result<()> {
+    run_wx_test(|| {
+        render_to_png(PNG_PATH, 120, 80, |mut backend| {
+            backend.draw_rect((20, 20), (60, 50), &RED, true)?;
+            backend.present()?;
+            anyhow::Ok(())
+        })?;
+        let image = image::open(PNG_PATH)
+            .with_context(|| format!("failed to open {PNG_PATH}"))?
+            .to_rgba8();
+        std::fs::remove_file(PNG_PATH)
+            .with_context(|| format!("failed to remove {PNG_PATH}"))?;
+
+        anyhow::ensure!(
+            image.dimensions() == (120, 80),
+            "image size is {:?}",
+            image.dimensions()
+        );
+        let inside = image.get_pixel(40, 35).0;
+        anyhow::ensure!(inside == [255, 0, 0, 255], "inside: {inside:?}");
+        let outside = image.get_pixel(100, 70).0;
+        anyhow::ensure!(
+            outside == [255, 255, 255, 255],
+            "outside: {outside:?}"
+        );
+        Ok(())
+    })
+}
@@ -0,0 +1,39 @@
+//! Rendering to an in-memory image
+//!
+//! This tests `render_to_rgba_image`, by drawing a blue rectangle on a white
+//! background, and checking the pixels of the returned image.
+//!
+//! This test requires the `export` feature.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::render_to_rgba_image;
+
+use test_utils::run_wx_test;
+
+#[test]
+fn test_render_to_rgba_image() -> Result<()> {
+    run_wx_test(|| {
+        let image = render_to_rgba_image(120, 80, |mut backend| {
+            backend.draw_rect((20, 20), (60, 50), &BLUE, true)?;
+            backend.present()?;
+            anyhow::Ok(())
+        })?;
+
+        anyhow::ensure!(
+            image.dimensions() == (120, 80),
+            "image size is {:?}",
+            image.dimensions()
+        );
+        let inside = image.get_pixel(40, 35).0;
+        anyhow::ensure!(inside == [0, 0, 255, 255], "inside: {inside:?}");
+        let outside = image.get_pixel(100, 70).0;
+        anyhow::ensure!(
+            outside == [255, 255, 255, 255],
+            "outside: {outside:?}"
+        );
+        Ok(())
+    })
+}
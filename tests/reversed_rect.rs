@@ -0,0 +1,44 @@
+//! Rectangles with reversed corners
+//!
+//! This tests that `draw_rect` draws the same rectangle whatever the order of
+//! its corners, filled or not.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_reversed_rect() -> Result<()> {
+    run_wx_test(|| {
+        let draw = |corners: [((i32, i32), (i32, i32)); 2]| {
+            render_rgba_image(100, 60, |dc| {
+                let mut backend = WxBackend::new(dc);
+                let [(a, b), (c, d)] = corners;
+                backend.draw_rect(a, b, &RED, true)?;
+                backend.draw_rect(c, d, &BLUE, false)?;
+                backend.present()?;
+                Ok(())
+            })
+        };
+
+        let expected = draw([((10, 10), (40, 50)), ((60, 10), (90, 50))])?;
+        let pixel = expected.get_pixel(25, 30).0;
+        anyhow::ensure!(pixel == [255, 0, 0, 255], "filled rect: {pixel:?}");
+
+        for corners in [
+            [((40, 50), (10, 10)), ((90, 50), (60, 10))],
+            [((40, 10), (10, 50)), ((60, 50), (90, 10))],
+        ] {
+            let image = draw(corners)?;
+            anyhow::ensure!(
+                image == expected,
+                "rectangles with corners {corners:?} differ"
+            );
+        }
+        Ok(())
+    })
+}
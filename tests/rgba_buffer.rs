@@ -0,0 +1,45 @@
+//! Rendering into a caller-provided buffer
+//!
+//! This tests `render_to_rgba_buffer`, by drawing a rectangle into a buffer
+//! owned by the test, and checking its pixels.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::render_to_rgba_buffer;
+
+use test_utils::run_wx_test;
+
+const WIDTH: u32 = 60;
+const HEIGHT: u32 = 40;
+
+#[test]
+fn test_rgba_buffer() -> Result<()> {
+    run_wx_test(|| {
+        let mut buffer = vec![0; (4 * WIDTH * HEIGHT) as usize];
+        render_to_rgba_buffer(&mut buffer, WIDTH, HEIGHT, |mut backend| {
+            backend.draw_rect((10, 10), (30, 20), &GREEN, true)?;
+            backend.present()?;
+            anyhow::Ok(())
+        })?;
+
+        let pixel = |x: u32, y: u32| {
+            let offset = (4 * (y * WIDTH + x)) as usize;
+            &buffer[offset..offset + 4]
+        };
+        anyhow::ensure!(pixel(15, 15) == [0, 255, 0, 255], "inside");
+        anyhow::ensure!(pixel(5, 5) == [255, 255, 255, 255], "outside");
+
+        let mut short = vec![0; 4];
+        let result =
+            render_to_rgba_buffer(
+                &mut short,
+                WIDTH,
+                HEIGHT,
+                |_| anyhow::Ok(()),
+            );
+        anyhow::ensure!(result.is_err(), "buffer of the wrong size accepted");
+        Ok(())
+    })
+}
@@ -0,0 +1,44 @@
+//! Size of rotated text
+//!
+//! This tests that `estimate_text_size` returns the bounding box of the
+//! rotated text: the width and height are swapped for a quarter turn, and
+//! unchanged for a half turn.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters::style::FontTransform;
+use plotters_wxdragon::{DEFAULT_DPI, WxBackend};
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_rotated_text_size() -> Result<()> {
+    run_wx_test(|| {
+        render_rgba_image(100, 100, |dc| {
+            let backend = WxBackend::new_with_dpi(dc, DEFAULT_DPI);
+            let font = ("sans-serif", 20).into_font();
+            let size = |transform: FontTransform| {
+                let style = TextStyle::from(font.clone().transform(transform));
+                backend.estimate_text_size("y axis label", &style)
+            };
+            let (width, height) = size(FontTransform::None)?;
+            anyhow::ensure!(width > height, "text size is {width}x{height}");
+            for (transform, expected) in [
+                (FontTransform::Rotate90, (height, width)),
+                (FontTransform::Rotate180, (width, height)),
+                (FontTransform::Rotate270, (height, width)),
+            ] {
+                let actual = size(transform.clone())?;
+                anyhow::ensure!(
+                    actual == expected,
+                    "{transform:?}: text size is {actual:?}, expected \
+                     {expected:?}"
+                );
+            }
+            Ok(())
+        })?;
+        Ok(())
+    })
+}
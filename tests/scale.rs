@@ -0,0 +1,50 @@
+//! Magnified drawing
+//!
+//! This tests `set_scale`, by drawing the same shapes at scale 1 and at a
+//! scale of 2, or 2 horizontally only, on bitmaps magnified as much, and
+//! checking that the backend reports the same size and that the magnified
+//! images have the same proportions.
+
+mod test_utils;
+
+use anyhow::Result;
+use image::RgbaImage;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+/// Draw the shapes at a scale, on a bitmap magnified as much
+fn draw((scale_x, scale_y): (u32, u32)) -> Result<RgbaImage> {
+    let (width, height) = (60, 40);
+    render_rgba_image(width * scale_x, height * scale_y, |dc| {
+        let backend = WxBackend::new(dc);
+        backend.set_scale(scale_x as f64, scale_y as f64)?;
+        let size = backend.get_size();
+        anyhow::ensure!(size == (width, height), "logical size is {size:?}");
+        let root = backend.into_drawing_area();
+        root.draw(&Rectangle::new([(5, 5), (24, 34)], RED.filled()))?;
+        root.draw(&Rectangle::new([(30, 10), (54, 19)], BLUE.filled()))?;
+        root.present()?;
+        Ok(())
+    })
+}
+
+#[test]
+fn test_scale() -> Result<()> {
+    run_wx_test(|| {
+        let expected = draw((1, 1))?;
+        for scale in [(2, 2), (2, 1)] {
+            let image = draw(scale)?;
+            for (x, y, pixel) in expected.enumerate_pixels() {
+                let magnified = image.get_pixel(x * scale.0, y * scale.1);
+                anyhow::ensure!(
+                    magnified == pixel,
+                    "pixel ({x}, {y}) at scale {scale:?}: {magnified:?} \
+                     instead of {pixel:?}"
+                );
+            }
+        }
+        Ok(())
+    })
+}
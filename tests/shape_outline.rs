@@ -0,0 +1,46 @@
+//! Outline of filled shapes
+//!
+//! This tests `set_shape_outline`, by drawing a filled red rectangle with a
+//! black outline, then a filled red rectangle with the default outline, and
+//! checking the colors of their borders and insides.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+use wxdragon as wx;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_shape_outline() -> Result<()> {
+    run_wx_test(|| {
+        let image = render_rgba_image(200, 100, |dc| {
+            let mut backend = WxBackend::new(dc);
+            backend.set_shape_outline(Some((wx::Colour::rgb(0, 0, 0), 1)));
+            backend.draw_rect((20, 20), (80, 80), &RED, true)?;
+            backend.draw_rect((120, 20), (180, 80), &RED, true)?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        let black = [0, 0, 0, 255];
+        let red = [255, 0, 0, 255];
+        let expected = [
+            // outlined rectangle
+            ((20, 50), black),
+            ((50, 20), black),
+            ((50, 50), red),
+            // the outline is reset after one shape
+            ((120, 50), red),
+            ((150, 20), red),
+            ((150, 50), red),
+        ];
+        for ((x, y), color) in expected {
+            let pixel = image.get_pixel(x, y).0;
+            anyhow::ensure!(pixel == color, "pixel ({x}, {y}): {pixel:?}");
+        }
+        Ok(())
+    })
+}
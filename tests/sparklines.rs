@@ -0,0 +1,32 @@
+//! Sparklines in an image list
+//!
+//! This tests `sparklines_to_image_list`, which renders one bitmap per series.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::sparklines_to_image_list;
+
+use test_utils::run_wx_test;
+
+#[test]
+fn test_sparklines() -> Result<()> {
+    run_wx_test(|| {
+        let series: Vec<Vec<f64>> = (1..=5)
+            .map(|k| (0..50).map(|i| (i as f64 / k as f64).sin()).collect())
+            .collect();
+        let image_list = sparklines_to_image_list((64, 16), &series, &BLUE)?;
+
+        let count = image_list.get_image_count();
+        anyhow::ensure!(count == 5, "expected 5 images, got {count}");
+        for index in 0..count {
+            let size = image_list.get_size(index);
+            anyhow::ensure!(
+                size == Some((64, 16)),
+                "unexpected size {size:?} for image {index}"
+            );
+        }
+        Ok(())
+    })
+}
@@ -0,0 +1,56 @@
+//! Stipple brush
+//!
+//! This tests `set_stipple_brush`, by filling a rectangle with a 4x4
+//! checkerboard, then checking that the next filled rectangle is solid again.
+
+mod test_utils;
+
+use anyhow::{Context, Result};
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+use wxdragon as wx;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_stipple() -> Result<()> {
+    run_wx_test(|| {
+        let checkerboard: Vec<u8> = (0..16)
+            .flat_map(|i| match (i % 4 + i / 4) % 2 {
+                0 => [0, 0, 0, 255],
+                _ => [255, 255, 255, 255],
+            })
+            .collect();
+        let bitmap = wx::Bitmap::from_rgba(&checkerboard, 4, 4)
+            .context("failed to create bitmap")?;
+
+        let image = render_rgba_image(100, 60, |dc| {
+            let mut backend = WxBackend::new(dc);
+            backend.set_stipple_brush(bitmap);
+            backend.draw_rect((10, 10), (49, 49), &RED, true)?;
+            backend.draw_rect((60, 10), (89, 49), &RED, true)?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        for y in 10..50 {
+            for x in 10..50 {
+                let expected = match (x + y) % 2 {
+                    0 => [0, 0, 0, 255],
+                    _ => [255, 255, 255, 255],
+                };
+                let actual = image.get_pixel(x, y).0;
+                anyhow::ensure!(
+                    actual == expected,
+                    "unexpected stipple pixel {actual:?} at ({x}, {y})"
+                );
+            }
+        }
+        let solid = image.get_pixel(75, 30).0;
+        anyhow::ensure!(
+            solid == [255, 0, 0, 255],
+            "second rectangle is not solid: {solid:?}"
+        );
+        Ok(())
+    })
+}
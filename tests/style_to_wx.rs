@@ -0,0 +1,48 @@
+//! Conversion of plotters styles to wxWidgets pens and brushes
+//!
+//! These tests check the color, width, pen style and brush style returned by
+//! `style_to_wx` for a few plotters styles, and the rounding of alpha.
+
+use plotters::prelude::*;
+use plotters_wxdragon::style_to_wx;
+use wxdragon as wx;
+
+/// Color channels of a wx color.
+fn rgba(color: wx::Colour) -> (u8, u8, u8, u8) {
+    (color.r, color.g, color.b, color.a)
+}
+
+#[test]
+fn test_stroke() {
+    let style = BLUE.stroke_width(3);
+    let (color, width, pen_style, brush_style) =
+        style_to_wx(&style, style.filled);
+    assert_eq!(rgba(color), (0, 0, 255, 255));
+    assert_eq!(width, 3);
+    assert_eq!(pen_style, wx::PenStyle::Solid);
+    assert_eq!(brush_style, wx::BrushStyle::Transparent);
+}
+
+#[test]
+fn test_filled() {
+    let style = RED.filled();
+    let (color, _, _, brush_style) = style_to_wx(&style, style.filled);
+    assert_eq!(rgba(color), (255, 0, 0, 255));
+    assert_eq!(brush_style, wx::BrushStyle::Solid);
+}
+
+#[test]
+fn test_translucent() {
+    let style = GREEN.mix(0.5).stroke_width(2);
+    let (color, width, _, _) = style_to_wx(&style, style.filled);
+    assert_eq!(rgba(color), (0, 255, 0, 128));
+    assert_eq!(width, 2);
+}
+
+#[test]
+fn test_alpha_rounding() {
+    for (alpha, expected) in [(0.0, 0), (0.5, 128), (0.999, 255), (1.0, 255)] {
+        let (color, _, _, _) = style_to_wx(&BLACK.mix(alpha), false);
+        assert_eq!(color.a, expected, "alpha {alpha}");
+    }
+}
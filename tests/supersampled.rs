@@ -0,0 +1,59 @@
+//! Supersampled export
+//!
+//! This tests `save_png_supersampled`, by exporting a diagonal black line on
+//! a white background, and checking that the pixels along its edges have
+//! intermediate gray levels, i.e. that the line is anti-aliased.
+//!
+//! This test requires the `export` feature.
+
+mod test_utils;
+
+use anyhow::{Context, Result};
+use plotters::prelude::*;
+use plotters_wxdragon::save_png_supersampled;
+
+use test_utils::run_wx_test;
+
+const PNG_PATH: &str = "tests/supersampled_actual.png";
+
+#[test]
+fn test_supersampled() -> Result<()> {
+    run_wx_test(|| {
+        save_png_supersampled(PNG_PATH, 100, 100, 4, |mut backend| {
+            let (width, height) = backend.get_size();
+            anyhow::ensure!(
+                (width, height) == (100, 100),
+                "backend size is {width}x{height}"
+            );
+            backend.draw_line((10, 20), (90, 60), &BLACK)?;
+            backend.present()?;
+            Ok(())
+        })?;
+        let image = image::open(PNG_PATH)
+            .with_context(|| format!("failed to open {PNG_PATH}"))?
+            .to_rgba8();
+        std::fs::remove_file(PNG_PATH)
+            .with_context(|| format!("failed to remove {PNG_PATH}"))?;
+
+        anyhow::ensure!(
+            image.dimensions() == (100, 100),
+            "image size is {:?}",
+            image.dimensions()
+        );
+        let intermediate = image
+            .pixels()
+            .filter(|p| p.0[0] > 20 && p.0[0] < 235)
+            .count();
+        anyhow::ensure!(
+            intermediate >= 40,
+            "only {intermediate} pixels with an intermediate gray level"
+        );
+        // background is untouched away from the line
+        let corner = image.get_pixel(95, 5).0;
+        anyhow::ensure!(
+            corner == [255, 255, 255, 255],
+            "corner pixel: {corner:?}"
+        );
+        Ok(())
+    })
+}
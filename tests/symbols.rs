@@ -0,0 +1,58 @@
+//! Stock symbols
+//!
+//! This tests `draw_check_mark` and `draw_cross`, by drawing each symbol in a
+//! box and checking that it is drawn inside the box only.
+
+mod test_utils;
+
+use anyhow::Result;
+use image::RgbaImage;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_symbols() -> Result<()> {
+    run_wx_test(|| {
+        let image = render_rgba_image(200, 100, |dc| {
+            let mut backend = WxBackend::new(dc);
+            backend.draw_rect((20, 20), (80, 80), &BLACK, false)?;
+            backend.draw_check_mark((25, 25), (75, 75), &GREEN)?;
+            backend.draw_rect((120, 20), (180, 80), &BLACK, false)?;
+            backend.draw_cross((125, 25), (175, 75), &RED)?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        let green =
+            count_pixels(&image, |[r, g, b, _]| g > 100 && r < 50 && b < 50);
+        let red =
+            count_pixels(&image, |[r, g, b, _]| r > 200 && g < 50 && b < 50);
+        anyhow::ensure!(green > 0, "check mark was not drawn");
+        anyhow::ensure!(red > 0, "cross was not drawn");
+
+        for (x, y, pixel) in image.enumerate_pixels() {
+            let [r, g, b, _] = pixel.0;
+            // gray pixels are the background and the box borders
+            let is_gray = r == g && g == b;
+            // symbols may be anti-aliased one pixel beyond their box
+            let in_check_box = (24..=76).contains(&x) && (24..=76).contains(&y);
+            let in_cross_box =
+                (124..=176).contains(&x) && (24..=76).contains(&y);
+            anyhow::ensure!(
+                is_gray || in_check_box || in_cross_box,
+                "symbol drawn outside of its box at ({x}, {y})"
+            );
+        }
+        Ok(())
+    })
+}
+
+/// Count the pixels of `image` that match `predicate`.
+fn count_pixels<P>(image: &RgbaImage, predicate: P) -> usize
+where
+    P: Fn([u8; 4]) -> bool,
+{
+    image.pixels().filter(|pixel| predicate(pixel.0)).count()
+}
@@ -0,0 +1,43 @@
+//! Tabular figures
+//!
+//! This tests `WxBackend::set_tabular_figures`: numbers with the same count of
+//! digits have the same width, whatever the digits, so that a live value does
+//! not jitter when it changes.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_tabular_figures() -> Result<()> {
+    run_wx_test(|| {
+        let style = TextStyle::from(("sans-serif", 30).into_font());
+        let mut sizes = Vec::new();
+        render_rgba_image(200, 100, |dc| {
+            let backend = WxBackend::new(dc);
+            backend.set_tabular_figures(true);
+            for text in ["111", "000", "-1.17", "-8.08"] {
+                sizes.push(backend.estimate_text_size(text, &style)?);
+            }
+            Ok(())
+        })?;
+
+        anyhow::ensure!(
+            sizes[0].0 == sizes[1].0,
+            "\"111\" is {} pixels wide, \"000\" is {} pixels wide",
+            sizes[0].0,
+            sizes[1].0
+        );
+        anyhow::ensure!(
+            sizes[2].0 == sizes[3].0,
+            "\"-1.17\" is {} pixels wide, \"-8.08\" is {} pixels wide",
+            sizes[2].0,
+            sizes[3].0
+        );
+        Ok(())
+    })
+}
@@ -1,12 +1,30 @@
 //! Testing utilities for non-regression tests
+//!
+//! # Adding a non-regression test
+//!
+//! 1. Create a test file `tests/{name}.rs` with a drawing function taking a
+//!    `WxBackend`, and a test that calls [`generate_fixture`] with the path
+//!    root `"tests/{name}"`.
+//! 2. Run the test once with `cargo test --test {name}`. This writes the
+//!    reference image `tests/{name}.png`.
+//! 3. Check the reference image manually, e.g. against the output of the
+//!    plotters `BitMapBackend` for the same drawing function.
+//! 4. Replace the call to [`generate_fixture`] with a call to
+//!    [`run_plotters_image_test`] with the same arguments, and commit both the
+//!    test file and the reference image.
+
+// Each test file includes this module, but does not use all of it.
+#![allow(dead_code)]
 
 use std::fs;
 use std::io;
+use std::ops::Range;
 use std::process;
 
 use anyhow::{Context, Result};
-use image::RgbaImage;
-use plotters_wxdragon::WxBackend;
+use image::{Rgba, RgbaImage};
+use plotters::prelude::*;
+use plotters_wxdragon::{DEFAULT_DPI, WxBackend};
 use wxdragon::{self as wx};
 
 /// Executes a plotter drawing function and compares the output to an expected
@@ -17,6 +35,9 @@ use wxdragon::{self as wx};
 /// image loaded from `{path_root}.png`. If the images do not match, the test
 /// will fail.
 ///
+/// The comparison is exact, see [`run_plotters_image_test_with_tolerance`]
+/// to allow minor rendering differences.
+///
 /// # Arguments
 ///
 /// * `width`: width of the drawing area.
@@ -36,63 +57,273 @@ pub fn run_plotters_image_test<F>(
     path_root: &str,
     draw_fn: F,
 ) -> Result<()>
+where
+    F: FnOnce(WxBackend<wx::MemoryDC>) -> Result<()> + Send + 'static,
+{
+    run_plotters_image_test_with_tolerance(
+        width,
+        height,
+        path_root,
+        ImageTolerance::STRICT,
+        draw_fn,
+    )
+}
+
+/// Executes a plotter drawing function and compares the output to an expected
+/// image file, within a tolerance.
+///
+/// This is [`run_plotters_image_test`], for drawings whose rendering differs
+/// slightly across platforms or font rendering versions, e.g. by a few
+/// anti-aliased pixels. Choose the tolerance so that real regressions, such
+/// as a missing or shifted element, still fail the test.
+pub fn run_plotters_image_test_with_tolerance<F>(
+    width: u32,
+    height: u32,
+    path_root: &str,
+    tolerance: ImageTolerance,
+    draw_fn: F,
+) -> Result<()>
 where
     F: FnOnce(WxBackend<wx::MemoryDC>) -> Result<()> + Send + 'static,
 {
     let reference_png = format!("{path_root}.png");
     let actual_png = format!("{path_root}_actual.png"); // saved if mismatch
-    let _ = wx::main(move |_| {
-        let result = (|| -> Result<()> {
-            // setup the backend with an empty bitmap
-            let mut bitmap = wx::Bitmap::new(width as i32, height as i32)
-                .context("failed to create bitmap")?;
-            let mut dc = wx::MemoryDC::new();
-            dc.select_object(&mut bitmap);
-            let backend = WxBackend::new(&dc);
-
-            // draw with user-provided closure
-            draw_fn(backend).context("error while drawing")?;
-
-            // convert to an image for comparison
-            dc.select_object(&mut wx::Bitmap::null_bitmap());
-            let rgba_data = bitmap
-                .get_rgba_data()
-                .context("failed to obtain image rgba data")?;
-            anyhow::ensure!(
-                rgba_data.len() == (width * height * 4) as usize,
-                "RGBA data length mismatch"
-            );
-            let image = RgbaImage::from_raw(width, height, rgba_data)
-                .context("failed to create RgbaImage from bitmap")?;
-
-            // non-regression comparison
-            let expected = image::load(
-                io::BufReader::new(
-                    fs::File::open(&reference_png).with_context(|| {
-                        format!("failed to open {reference_png}")
-                    })?,
-                ),
-                image::ImageFormat::Png,
-            )
-            .with_context(|| "failed to load {reference_png}")?;
-            if expected == image::DynamicImage::ImageRgba8(image.clone()) {
-                Ok(())
-            } else {
-                image
-                    .save(&actual_png)
-                    .context("failed to save {actual_png}")?;
-                let message = format!(
-                    "ERROR: image mismatch.
+    let diff_png = format!("{path_root}_diff.png"); // saved if mismatch
+    run_wx_test(move || {
+        // draw with user-provided closure on a new backend, with an explicit
+        // dpi so that the output does not depend on the machine
+        let image = render_rgba_image(width, height, |dc| {
+            let backend = WxBackend::new_with_dpi(dc, DEFAULT_DPI);
+            draw_fn(backend)
+        })?;
+
+        // non-regression comparison
+        let expected = image::load(
+            io::BufReader::new(
+                fs::File::open(&reference_png).with_context(|| {
+                    format!("failed to open {reference_png}")
+                })?,
+            ),
+            image::ImageFormat::Png,
+        )
+        .with_context(|| "failed to load {reference_png}")?
+        .to_rgba8();
+        tolerance.check(&expected, &image).or_else(|e| {
+            image
+                .save(&actual_png)
+                .context("failed to save {actual_png}")?;
+            tolerance
+                .diff_image(&expected, &image)
+                .save(&diff_png)
+                .with_context(|| format!("failed to save {diff_png}"))?;
+            Err(e.context(format!(
+                "ERROR: image mismatch.
 Compare the following two files manually, then \
 update the reference image if needed.
   reference image: {reference_png}
   actual image   : {actual_png}
+  differences    : {diff_png}
 "
-                );
-                anyhow::bail!(message)
+            )))
+        })
+    })
+}
+
+/// Executes a plotter drawing function and saves the output as the reference
+/// image of a non-regression test.
+///
+/// The drawing is done exactly as in [`run_plotters_image_test`], and the
+/// image is written to `{path_root}.png`, overwriting any existing file. See
+/// the module documentation for the workflow to add a new test.
+pub fn generate_fixture<F>(
+    width: u32,
+    height: u32,
+    path_root: &str,
+    draw_fn: F,
+) -> Result<()>
+where
+    F: FnOnce(WxBackend<wx::MemoryDC>) -> Result<()> + Send + 'static,
+{
+    let reference_png = format!("{path_root}.png");
+    run_wx_test(move || {
+        let image = render_rgba_image(width, height, |dc| {
+            let backend = WxBackend::new_with_dpi(dc, DEFAULT_DPI);
+            draw_fn(backend)
+        })?;
+        image
+            .save(&reference_png)
+            .with_context(|| format!("failed to save {reference_png}"))?;
+        println!("reference image written to {reference_png}");
+        Ok(())
+    })
+}
+
+/// A drawing function that can run on any plotters backend.
+///
+/// This is a trait rather than a closure, because the drawing function must
+/// be generic over the backend.
+pub trait PlotFn {
+    /// Draws the plot on `backend`.
+    fn draw<DB>(&self, backend: DB) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static;
+}
+
+/// Tolerance when comparing two images.
+///
+/// The default tolerance is [`ImageTolerance::STRICT`].
+#[derive(Debug, Clone, Copy)]
+pub struct ImageTolerance {
+    /// Maximum difference of any RGBA channel for two pixels to be considered
+    /// equal.
+    pub max_channel_delta: u8,
+    /// Maximum ratio of pixels that are not equal.
+    pub max_differing_ratio: f64,
+}
+
+impl ImageTolerance {
+    /// Exact comparison.
+    pub const STRICT: ImageTolerance = ImageTolerance {
+        max_channel_delta: 0,
+        max_differing_ratio: 0.0,
+    };
+
+    /// Compares two images of the same size, and fails if the ratio of
+    /// differing pixels exceeds the tolerance.
+    pub fn check(
+        &self,
+        expected: &RgbaImage,
+        actual: &RgbaImage,
+    ) -> Result<()> {
+        anyhow::ensure!(
+            expected.dimensions() == actual.dimensions(),
+            "image size mismatch: expected {:?}, got {:?}",
+            expected.dimensions(),
+            actual.dimensions()
+        );
+        let differing = expected
+            .pixels()
+            .zip(actual.pixels())
+            .filter(|(e, a)| {
+                e.0.iter()
+                    .zip(a.0.iter())
+                    .any(|(e, a)| e.abs_diff(*a) > self.max_channel_delta)
+            })
+            .count();
+        let ratio = differing as f64 / expected.pixels().len() as f64;
+        anyhow::ensure!(
+            ratio <= self.max_differing_ratio,
+            "{:.2}% of pixels differ by more than {}, tolerance is {:.2}%",
+            100.0 * ratio,
+            self.max_channel_delta,
+            100.0 * self.max_differing_ratio
+        );
+        Ok(())
+    }
+
+    /// Builds an image highlighting the differences between two images of
+    /// the same size, to review a failed comparison.
+    ///
+    /// Pixels that differ by more than the tolerated channel delta are red,
+    /// other pixels are those of `expected`, faded to a light gray.
+    pub fn diff_image(
+        &self,
+        expected: &RgbaImage,
+        actual: &RgbaImage,
+    ) -> RgbaImage {
+        let (width, height) = expected.dimensions();
+        RgbaImage::from_fn(width, height, |x, y| {
+            let e = expected.get_pixel(x, y);
+            let differs = actual.get_pixel_checked(x, y).is_none_or(|a| {
+                e.0.iter()
+                    .zip(a.0.iter())
+                    .any(|(e, a)| e.abs_diff(*a) > self.max_channel_delta)
+            });
+            if differs {
+                image::Rgba([255, 0, 0, 255])
+            } else {
+                let [r, g, b, _] = e.0;
+                let luma = (r as u32 * 3 + g as u32 * 6 + b as u32) / 10;
+                let faded = 255 - (255 - luma as u8) / 4;
+                image::Rgba([faded, faded, faded, 255])
             }
-        })();
-        if let Err(e) = result {
+        })
+    }
+}
+
+impl Default for ImageTolerance {
+    fn default() -> Self {
+        ImageTolerance::STRICT
+    }
+}
+
+/// Draws the same plot with the plotters `BitMapBackend` and with a
+/// `WxBackend` on an in-memory device context, and compares the two images.
+///
+/// This pins the fidelity of `WxBackend` to the reference plotters backend,
+/// and catches systematic offsets. The two backends do not rasterize shapes
+/// and fonts in the same way, so a tolerance is needed.
+///
+/// This function must be called from within the wxWidgets main loop, see
+/// [`run_wx_test`]. If the images differ, both are saved as
+/// `{path_root}_bitmap.png` and `{path_root}_wx.png`, with an image of their
+/// differences as `{path_root}_diff.png`.
+pub fn compare_with_bitmap_backend<P: PlotFn>(
+    width: u32,
+    height: u32,
+    path_root: &str,
+    plot: &P,
+    tolerance: ImageTolerance,
+) -> Result<()> {
+    let mut buffer = vec![0; (width * height * 3) as usize];
+    plot.draw(BitMapBackend::with_buffer(&mut buffer, (width, height)))
+        .context("error while drawing with BitMapBackend")?;
+    let expected = image::DynamicImage::ImageRgb8(
+        image::RgbImage::from_raw(width, height, buffer)
+            .context("failed to create RgbImage from buffer")?,
+    )
+    .to_rgba8();
+
+    let actual = render_rgba_image(width, height, |dc| {
+        plot.draw(WxBackend::new_with_dpi(dc, DEFAULT_DPI))
+    })?;
+
+    tolerance.check(&expected, &actual).or_else(|e| {
+        let bitmap_png = format!("{path_root}_bitmap.png");
+        let wx_png = format!("{path_root}_wx.png");
+        let diff_png = format!("{path_root}_diff.png");
+        expected
+            .save(&bitmap_png)
+            .with_context(|| format!("failed to save {bitmap_png}"))?;
+        actual
+            .save(&wx_png)
+            .with_context(|| format!("failed to save {wx_png}"))?;
+        tolerance
+            .diff_image(&expected, &actual)
+            .save(&diff_png)
+            .with_context(|| format!("failed to save {diff_png}"))?;
+        Err(e.context(format!(
+            "WxBackend output differs from BitMapBackend output.
+  BitMapBackend image: {bitmap_png}
+  WxBackend image    : {wx_png}
+  differences        : {diff_png}"
+        )))
+    })
+}
+
+/// Runs a test function inside the wxWidgets main loop.
+///
+/// wxWidgets can only be initialized once per process, so this function exits
+/// the process when `test_fn` succeeds, and panics with the full error chain
+/// when it fails. As a consequence, each test file can contain only one test
+/// that calls this function.
+pub fn run_wx_test<F>(test_fn: F) -> Result<()>
+where
+    F: FnOnce() -> Result<()> + Send + 'static,
+{
+    let _ = wx::main(move |_| {
+        if let Err(e) = test_fn() {
             panic!("{}", error_chain_string(&*e.into_boxed_dyn_error()));
         }
         process::exit(0);
@@ -100,6 +331,75 @@ update the reference image if needed.
     Ok(())
 }
 
+/// Draws on an in-memory device context and returns the resulting image.
+///
+/// This function must be called from within the wxWidgets main loop, see
+/// [`run_wx_test`]. The device context is backed by an empty bitmap of size
+/// `width` x `height`, and `draw_fn` is free to create any backend on it.
+pub fn render_rgba_image<F>(
+    width: u32,
+    height: u32,
+    draw_fn: F,
+) -> Result<RgbaImage>
+where
+    F: FnOnce(&wx::MemoryDC) -> Result<()>,
+{
+    // setup the device context with an empty bitmap
+    let mut bitmap = wx::Bitmap::new(width as i32, height as i32)
+        .context("failed to create bitmap")?;
+    let mut dc = wx::MemoryDC::new();
+    dc.select_object(&mut bitmap);
+
+    // draw with user-provided closure
+    draw_fn(&dc).context("error while drawing")?;
+
+    // convert to an image for comparison
+    dc.select_object(&mut wx::Bitmap::null_bitmap());
+    let rgba_data = bitmap
+        .get_rgba_data()
+        .context("failed to obtain image rgba data")?;
+    anyhow::ensure!(
+        rgba_data.len() == (width * height * 4) as usize,
+        "RGBA data length mismatch"
+    );
+    RgbaImage::from_raw(width, height, rgba_data)
+        .context("failed to create RgbaImage from bitmap")
+}
+
+/// Whether a pixel is ink, i.e. not white.
+pub fn is_ink(pixel: &Rgba<u8>) -> bool {
+    pixel.0[..3] != [255, 255, 255]
+}
+
+/// Counts the non-white pixels of an image.
+pub fn ink(image: &RgbaImage) -> usize {
+    image.pixels().filter(|pixel| is_ink(pixel)).count()
+}
+
+/// Counts the non-white pixels in the rows `rows` of an image.
+pub fn ink_in_rows(image: &RgbaImage, rows: Range<u32>) -> usize {
+    image
+        .enumerate_pixels()
+        .filter(|(_, y, pixel)| rows.contains(y) && is_ink(pixel))
+        .count()
+}
+
+/// Bounding box of the non-white pixels of an image, bounds included.
+pub fn ink_bounds(image: &RgbaImage) -> Option<(i32, i32, i32, i32)> {
+    let pixels = image
+        .enumerate_pixels()
+        .filter(|(_, _, pixel)| is_ink(pixel))
+        .map(|(x, y, _)| (x as i32, y as i32));
+    pixels.fold(None, |bounds, (x, y)| {
+        Some(match bounds {
+            None => (x, y, x, y),
+            Some((x0, y0, x1, y1)) => {
+                (x0.min(x), y0.min(y), x1.max(x), y1.max(y))
+            }
+        })
+    })
+}
+
 // Helper to get the full error chain string
 fn error_chain_string(err: &dyn std::error::Error) -> String {
     let mut messages = Vec::new();
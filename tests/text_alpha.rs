@@ -0,0 +1,37 @@
+//! Translucent text
+//!
+//! This tests that the alpha of the text color is honored, by drawing a
+//! black caption at 50% opacity over a red filled region, and checking that
+//! the glyphs blend with the red instead of covering it.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::{DEFAULT_DPI, WxBackend};
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_text_alpha() -> Result<()> {
+    run_wx_test(|| {
+        let image = render_rgba_image(200, 100, |dc| {
+            let mut backend = WxBackend::new_with_dpi(dc, DEFAULT_DPI);
+            backend.draw_rect((0, 0), (200, 100), &RED, true)?;
+            let style = ("sans-serif", 40).into_font().color(&BLACK.mix(0.5));
+            backend.draw_text("WATERMARK", &style, (10, 30))?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        let darkest = image.pixels().map(|p| p.0[0]).min().unwrap_or(255);
+        anyhow::ensure!(darkest < 200, "caption is not drawn");
+        anyhow::ensure!(
+            darkest.abs_diff(128) <= 3,
+            "darkest red level is {darkest}, expected 128 for a 50% caption"
+        );
+        let blue_or_green = image.pixels().any(|p| p.0[1] != 0 || p.0[2] != 0);
+        anyhow::ensure!(!blue_or_green, "caption is not blended over red");
+        Ok(())
+    })
+}
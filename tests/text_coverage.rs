@@ -0,0 +1,61 @@
+//! Text coverage blending
+//!
+//! This tests that the anti-aliased edges of an opaque label blend with the
+//! pixels under it, by drawing a white caption over a gradient from blue to
+//! red with `set_text_coverage_blend`, and checking that each pixel is a
+//! mix of white and the gradient color of its column.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::{DEFAULT_DPI, WxBackend};
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+const WIDTH: u32 = 256;
+
+/// Color of the gradient in column `x`.
+fn gradient(x: u32) -> (u8, u8, u8) {
+    (x as u8, 0, 255 - x as u8)
+}
+
+#[test]
+fn test_text_coverage() -> Result<()> {
+    run_wx_test(|| {
+        let image = render_rgba_image(WIDTH, 100, |dc| {
+            let mut backend = WxBackend::new_with_dpi(dc, DEFAULT_DPI);
+            for x in 0..WIDTH {
+                let (r, g, b) = gradient(x);
+                let color = RGBColor(r, g, b);
+                backend.draw_line((x as i32, 0), (x as i32, 99), &color)?;
+            }
+            backend.set_text_coverage_blend(true);
+            let style = ("sans-serif", 40).into_font().color(&WHITE);
+            backend.draw_text("Label", &style, (20, 30))?;
+            backend.present()?;
+            Ok(())
+        })?;
+
+        let (mut ink, mut edges) = (0, 0);
+        for (x, y, pixel) in image.enumerate_pixels() {
+            let [r, g, b, _] = pixel.0;
+            let (bg_r, _, bg_b) = gradient(x);
+            // green is only brought by the white caption
+            let coverage = g as f64 / 255.0;
+            let blend = |bg: u8| bg as f64 + (255.0 - bg as f64) * coverage;
+            anyhow::ensure!(
+                (r as f64 - blend(bg_r)).abs() <= 4.0
+                    && (b as f64 - blend(bg_b)).abs() <= 4.0,
+                "pixel ({x}, {y}) is {:?}, not a blend of white and the \
+                 gradient at {coverage:.2}",
+                (r, g, b)
+            );
+            ink += (g > 0) as usize;
+            edges += (g > 0 && g < 255) as usize;
+        }
+        anyhow::ensure!(ink > 0, "caption is not drawn");
+        anyhow::ensure!(edges > 0, "caption edges are not anti-aliased");
+        Ok(())
+    })
+}
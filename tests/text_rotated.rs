@@ -0,0 +1,88 @@
+//! Text rotated by an arbitrary angle
+//!
+//! This tests `draw_text_rotated`, by drawing a centered label rotated by
+//! 45°, and checking that the bounding box of its pixels is centered on the
+//! anchor, and about as wide as it is high. The reference image is the same
+//! label drawn by wxWidgets, rotated around its anchor by hand, rather than a
+//! PNG fixture, whose glyphs would depend on the fonts of the machine that
+//! generated it.
+
+mod test_utils;
+
+use anyhow::{Context, Result};
+use image::RgbaImage;
+use plotters::prelude::*;
+use plotters::style::text_anchor::{HPos, Pos, VPos};
+use plotters_wxdragon::{DEFAULT_DPI, WxBackend};
+use wxdragon::{self as wx, DeviceContext};
+
+use test_utils::{ink_bounds, is_ink, render_rgba_image, run_wx_test};
+
+const SIZE: u32 = 200;
+const ANCHOR: (i32, i32) = (100, 100);
+const TEXT: &str = "HHHHHHHH";
+
+#[test]
+fn test_text_rotated() -> Result<()> {
+    run_wx_test(|| {
+        let image = render_rgba_image(SIZE, SIZE, |dc| {
+            let backend = WxBackend::new_with_dpi(dc, DEFAULT_DPI);
+            let style = TextStyle::from(("sans-serif", 30).into_font())
+                .pos(Pos::new(HPos::Center, VPos::Center));
+            backend.draw_text_rotated(TEXT, &style, ANCHOR, 45.0)?;
+            Ok(())
+        })?;
+
+        let (x0, y0, x1, y1) = ink_bounds(&image).context("no text drawn")?;
+        let (width, height) = (x1 - x0, y1 - y0);
+        let center = ((x0 + x1) / 2, (y0 + y1) / 2);
+        anyhow::ensure!(
+            (center.0 - ANCHOR.0).abs() <= 3
+                && (center.1 - ANCHOR.1).abs() <= 3,
+            "text centered at {center:?}, expected {ANCHOR:?}"
+        );
+        anyhow::ensure!(
+            width > 80 && (width - height).abs() <= 6,
+            "text bounding box is {width}x{height}"
+        );
+        // rotated clockwise, the text goes down from left to right, so that
+        // its top is on the left
+        let top_x = (x0..=x1)
+            .find(|&x| is_ink(image.get_pixel(x as u32, y0 as u32)))
+            .context("empty first row")?;
+        anyhow::ensure!(
+            top_x < ANCHOR.0,
+            "top of the text at x = {top_x}, expected on the left"
+        );
+        anyhow::ensure!(
+            image == render_reference()?,
+            "rotated text differs from the text rotated by wxWidgets"
+        );
+        Ok(())
+    })
+}
+
+/// Draw the label directly on the device context, rotated around its center.
+fn render_reference() -> Result<RgbaImage> {
+    render_rgba_image(SIZE, SIZE, |dc| {
+        let backend = WxBackend::new_with_dpi(dc, DEFAULT_DPI);
+        // 30 pixels at 96 dpi, as converted by the backend
+        let font = wx::Font::builder()
+            .with_point_size(18)
+            .with_family(wx::FontFamily::Swiss)
+            .build()
+            .context("failed to create the reference font")?;
+        let dc = backend.context();
+        dc.set_font(&font);
+        dc.set_text_foreground(wx::Colour::rgb(0, 0, 0));
+        // the upper-left corner of the text, rotated around the center
+        let (width, height) = dc.get_text_extent(TEXT);
+        let (dx, dy) = ((-width / 2) as f64, (-height / 2) as f64);
+        let (sin, cos) = 45f64.to_radians().sin_cos();
+        let x = ANCHOR.0 + (dx * cos - dy * sin).round() as i32;
+        let y = ANCHOR.1 + (dx * sin + dy * cos).round() as i32;
+        // wxWidgets rotates counterclockwise
+        dc.draw_rotated_text(TEXT, x, y, -45.0);
+        Ok(())
+    })
+}
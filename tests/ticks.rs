@@ -0,0 +1,56 @@
+//! Pixel-aligned ticks
+//!
+//! These tests check the tick values computed by `pixel_aligned_ticks`, and
+//! that their pixel positions match the plotters mapping of a `f64` axis.
+
+use plotters::coord::ranged1d::Ranged;
+use plotters::coord::types::RangedCoordf64;
+use plotters_wxdragon::{Tick, pixel_aligned_ticks};
+
+/// Checks that tick pixels match the plotters mapping.
+fn assert_aligned_with_plotters(
+    ticks: &[Tick],
+    data_range: std::ops::Range<f64>,
+    pixel_range: (i32, i32),
+) {
+    let coord: RangedCoordf64 = data_range.into();
+    for tick in ticks {
+        assert_eq!(tick.pixel, coord.map(&tick.value, pixel_range), "{tick:?}");
+    }
+}
+
+#[test]
+fn test_unit_steps() {
+    let ticks = pixel_aligned_ticks(0.0..10.0, (0, 100), 11);
+    let values: Vec<f64> = ticks.iter().map(|t| t.value).collect();
+    let pixels: Vec<i32> = ticks.iter().map(|t| t.pixel).collect();
+    assert_eq!(values, (0..=10).map(f64::from).collect::<Vec<_>>());
+    assert_eq!(pixels, (0..=10).map(|i| 10 * i).collect::<Vec<_>>());
+    assert_aligned_with_plotters(&ticks, 0.0..10.0, (0, 100));
+}
+
+#[test]
+fn test_vertical_axis() {
+    let ticks = pixel_aligned_ticks(0.0..1.0, (500, 0), 6);
+    let pixels: Vec<i32> = ticks.iter().map(|t| t.pixel).collect();
+    assert_eq!(pixels, vec![500, 400, 300, 200, 100, 0]);
+    assert_aligned_with_plotters(&ticks, 0.0..1.0, (500, 0));
+}
+
+#[test]
+fn test_symmetric_range() {
+    let ticks = pixel_aligned_ticks(-3.4..3.4, (0, 680), 10);
+    let values: Vec<f64> = ticks.iter().map(|t| t.value).collect();
+    assert_eq!(values, vec![-3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0]);
+    assert_eq!(ticks[0].pixel, 40);
+    assert_eq!(ticks[3].pixel, 340);
+    assert_aligned_with_plotters(&ticks, -3.4..3.4, (0, 680));
+}
+
+#[test]
+fn test_degenerate_inputs() {
+    assert!(pixel_aligned_ticks(1.0..1.0, (0, 100), 10).is_empty());
+    assert!(pixel_aligned_ticks(2.0..1.0, (0, 100), 10).is_empty());
+    assert!(pixel_aligned_ticks(0.0..f64::NAN, (0, 100), 10).is_empty());
+    assert!(pixel_aligned_ticks(0.0..1.0, (0, 100), 0).is_empty());
+}
@@ -0,0 +1,54 @@
+//! Export to a transparent PNG file
+//!
+//! This tests `render_to_transparent_png`, by exporting a half-transparent
+//! red rectangle and an opaque blue one, and checking that the saved image
+//! is transparent around them, and has straight alpha: the red keeps its
+//! full intensity instead of being premultiplied by its alpha.
+//!
+//! This test requires the `export` feature.
+
+mod test_utils;
+
+use anyhow::{Context, Result};
+use plotters::prelude::*;
+use plotters_wxdragon::render_to_transparent_png;
+
+use test_utils::run_wx_test;
+
+const PNG_PATH: &str = "tests/transparent_png_actual.png";
+
+#[test]
+fn test_transparent_png() -> Result<()> {
+    run_wx_test(|| {
+        render_to_transparent_png(PNG_PATH, 120, 80, |mut backend| {
+            backend.draw_rect((10, 10), (50, 70), &RED.mix(0.5), true)?;
+            backend.draw_rect((70, 10), (110, 70), &BLUE, true)?;
+            backend.present()?;
+            anyhow::Ok(())
+        })?;
+        let image = image::open(PNG_PATH)
+            .with_context(|| format!("failed to open {PNG_PATH}"))?
+            .to_rgba8();
+        std::fs::remove_file(PNG_PATH)
+            .with_context(|| format!("failed to remove {PNG_PATH}"))?;
+
+        let [r, g, b, a] = image.get_pixel(30, 40).0;
+        anyhow::ensure!(
+            a.abs_diff(128) <= 2,
+            "half-transparent red has alpha {a}, expected 128"
+        );
+        anyhow::ensure!(
+            r >= 252 && g <= 3 && b <= 3,
+            "half-transparent red is {:?}, expected straight [255, 0, 0]",
+            (r, g, b)
+        );
+        let opaque = image.get_pixel(90, 40).0;
+        anyhow::ensure!(opaque == [0, 0, 255, 255], "opaque: {opaque:?}");
+        let background = image.get_pixel(60, 75).0;
+        anyhow::ensure!(
+            background[3] == 0,
+            "background is not transparent: {background:?}"
+        );
+        Ok(())
+    })
+}
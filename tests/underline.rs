@@ -0,0 +1,49 @@
+//! Underlined text
+//!
+//! This tests `set_underline`, by drawing the same text twice, first with
+//! the underline setting, and comparing with the text drawn without it: the
+//! first text has more ink, and the second text is not underlined because the
+//! setting applies to a single text.
+
+mod test_utils;
+
+use anyhow::Result;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+
+use test_utils::{ink_in_rows, render_rgba_image, run_wx_test};
+
+const TEXT: &str = "plotters-wxdragon";
+const WIDTH: u32 = 300;
+const HEIGHT: u32 = 100;
+
+#[test]
+fn test_underline() -> Result<()> {
+    run_wx_test(|| {
+        let render = |underline: bool| {
+            render_rgba_image(WIDTH, HEIGHT, |dc| {
+                let mut backend = WxBackend::new(dc);
+                let style = TextStyle::from(("sans-serif", 30).into_font());
+                backend.set_underline(underline);
+                backend.draw_text(TEXT, &style, (10, 5))?;
+                backend.draw_text(TEXT, &style, (10, 55))?;
+                backend.present()?;
+                Ok(())
+            })
+        };
+        let underlined = render(true)?;
+        let plain = render(false)?;
+
+        let (top, bottom) = (0..HEIGHT / 2, HEIGHT / 2..HEIGHT);
+        anyhow::ensure!(
+            ink_in_rows(&underlined, top.clone()) > ink_in_rows(&plain, top),
+            "underlined text has no more ink than plain text"
+        );
+        anyhow::ensure!(
+            ink_in_rows(&underlined, bottom.clone())
+                == ink_in_rows(&plain, bottom),
+            "underline was not reset after the first text"
+        );
+        Ok(())
+    })
+}
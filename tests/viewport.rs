@@ -0,0 +1,87 @@
+//! Zooming and panning of a viewport
+//!
+//! These tests check that zooming keeps the data point under the cursor in
+//! place, that panning follows the mouse, that zooming to a rectangle shows
+//! the data in that rectangle, and that zooming is clamped.
+
+use std::ops::Range;
+
+use plotters_wxdragon::Viewport;
+
+/// Pixel area of a plot of 100 x 50 pixels, offset by label areas.
+fn pixel_area() -> (Range<i32>, Range<i32>) {
+    (50..150, 10..60)
+}
+
+fn assert_range_eq(actual: Range<f64>, expected: Range<f64>) {
+    assert!(
+        (actual.start - expected.start).abs() < 1e-9
+            && (actual.end - expected.end).abs() < 1e-9,
+        "{actual:?} != {expected:?}"
+    );
+}
+
+#[test]
+fn test_zoom_around_cursor() {
+    let mut viewport = Viewport::new(0.0..10.0, 0.0..5.0);
+    // cursor at data point (2.0, 4.0)
+    viewport.zoom((70, 20), &pixel_area(), 2.0);
+    assert_range_eq(viewport.x_range(), 1.0..6.0);
+    assert_range_eq(viewport.y_range(), 2.0..4.5);
+    viewport.zoom((70, 20), &pixel_area(), 0.5);
+    assert_range_eq(viewport.x_range(), 0.0..10.0);
+    assert_range_eq(viewport.y_range(), 0.0..5.0);
+}
+
+#[test]
+fn test_pan() {
+    let mut viewport = Viewport::new(0.0..10.0, 0.0..5.0);
+    // drag right by a tenth and down by a fifth of the plot
+    viewport.pan((10, 10), &pixel_area());
+    assert_range_eq(viewport.x_range(), -1.0..9.0);
+    assert_range_eq(viewport.y_range(), 1.0..6.0);
+    viewport.reset();
+    assert_eq!(viewport, Viewport::new(0.0..10.0, 0.0..5.0));
+}
+
+#[test]
+fn test_zoom_to_rect() {
+    let mut viewport = Viewport::new(0.0..10.0, 0.0..5.0);
+    // from the bottom-right to the top-left corner, in any drag direction
+    assert!(viewport.zoom_to_rect((100, 40), (60, 20), &pixel_area()));
+    assert_range_eq(viewport.x_range(), 1.0..5.0);
+    assert_range_eq(viewport.y_range(), 1.9..3.9);
+
+    // a click is not a selection
+    let zoomed = viewport.clone();
+    assert!(!viewport.zoom_to_rect((60, 20), (61, 40), &pixel_area()));
+    assert!(!viewport.zoom_to_rect((60, 20), (100, 20), &pixel_area()));
+    assert_eq!(viewport, zoomed);
+}
+
+#[test]
+fn test_zoom_is_clamped() {
+    let mut viewport = Viewport::new(0.0..10.0, 0.0..5.0);
+    for _ in 0..1000 {
+        viewport.zoom((100, 35), &pixel_area(), 10.0);
+    }
+    let x = viewport.x_range();
+    assert!(x.end > x.start, "{x:?}");
+    assert!((x.end - x.start - 1e-5).abs() < 1e-12, "{x:?}");
+    for _ in 0..2000 {
+        viewport.zoom((100, 35), &pixel_area(), 0.1);
+    }
+    let y = viewport.y_range();
+    assert!((y.end - y.start - 5e6).abs() < 1e-3, "{y:?}");
+}
+
+#[test]
+fn test_degenerate_inputs() {
+    let initial = Viewport::new(0.0..10.0, 0.0..5.0);
+    let mut viewport = initial.clone();
+    viewport.zoom((70, 20), &(0..0, 10..60), 2.0);
+    viewport.zoom((70, 20), &pixel_area(), f64::NAN);
+    viewport.zoom((70, 20), &pixel_area(), 0.0);
+    viewport.pan((10, 10), &(50..150, 10..10));
+    assert_eq!(viewport, initial);
+}
@@ -0,0 +1,93 @@
+//! Plot split across device contexts
+//!
+//! This tests `set_virtual_size`, by drawing a chart laid out on 100x50
+//! pixels on two `MemoryDC`s of 50x50 pixels, each shifted to show one half,
+//! and checking that the halves put side by side are the chart drawn on a
+//! single `MemoryDC`. A rectangle across a horizontal seam checks the offsets
+//! of tiles with a flipped vertical axis.
+
+mod test_utils;
+
+use anyhow::Result;
+use image::RgbaImage;
+use plotters::prelude::*;
+use plotters_wxdragon::WxBackend;
+use wxdragon as wx;
+
+use test_utils::{render_rgba_image, run_wx_test};
+
+#[test]
+fn test_virtual_size() -> Result<()> {
+    run_wx_test(|| {
+        let full = render_rgba_image(100, 50, |dc| draw(WxBackend::new(dc)))?;
+        let tile = |dx: i32| {
+            render_rgba_image(50, 50, move |dc| {
+                let backend = WxBackend::new(dc);
+                backend.set_virtual_size(Some((100, 50)))?;
+                backend.set_offset((dx, 0))?;
+                anyhow::ensure!(
+                    backend.config().virtual_size == Some((100, 50)),
+                    "virtual size not set"
+                );
+                draw(backend)
+            })
+        };
+        let tiles = [tile(0)?, tile(-50)?];
+        compare(&full, &tiles, |x, y| ((x / 50) as usize, x % 50, y))?;
+
+        let full = render_rgba_image(50, 100, |dc| {
+            let backend = WxBackend::new(dc);
+            backend.set_flip_y(true)?;
+            draw_bar(backend)
+        })?;
+        let tile = |dy: i32| {
+            render_rgba_image(50, 50, move |dc| {
+                let backend = WxBackend::new(dc);
+                backend.set_flip_y(true)?;
+                backend.set_virtual_size(Some((50, 100)))?;
+                backend.set_offset((0, dy))?;
+                draw_bar(backend)
+            })
+        };
+        // the lower tile is shifted upward
+        let tiles = [tile(0)?, tile(50)?];
+        compare(&full, &tiles, |x, y| ((y / 50) as usize, x, y % 50))
+    })
+}
+
+/// Check that each pixel of `full` is the pixel of a tile, given by `locate`
+/// as the index of the tile and the position in the tile.
+fn compare(
+    full: &RgbaImage,
+    tiles: &[RgbaImage],
+    locate: impl Fn(u32, u32) -> (usize, u32, u32),
+) -> Result<()> {
+    for (x, y, pixel) in full.enumerate_pixels() {
+        let (index, tile_x, tile_y) = locate(x, y);
+        let tiled = tiles[index].get_pixel(tile_x, tile_y);
+        anyhow::ensure!(
+            tiled == pixel,
+            "pixel ({x}, {y}) is {tiled:?} in tile {index} instead of {pixel:?}"
+        );
+    }
+    Ok(())
+}
+
+/// Draw a chart with a caption and a line across the seam.
+fn draw(backend: WxBackend<wx::MemoryDC>) -> Result<()> {
+    let root = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&root)
+        .caption("seam", ("sans-serif", 14))
+        .build_cartesian_2d(0f64..1f64, 0f64..1f64)?;
+    let points = (0..=20).map(|i| i as f64 / 20.0).map(|x| (x, x * x));
+    chart.draw_series(LineSeries::new(points, BLUE.stroke_width(2)))?;
+    root.present()?;
+    Ok(())
+}
+
+/// Draw a bar from y = 20 to y = 79, across the seam at y = 50.
+fn draw_bar(mut backend: WxBackend<wx::MemoryDC>) -> Result<()> {
+    backend.draw_rect((10, 20), (40, 80), &RED, true)?;
+    backend.present()?;
+    Ok(())
+}